@@ -0,0 +1,744 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::contact::{tire_hub_wrench, ContactAggregate, HubWrench};
+use crate::conventions::{lateral_force, slip_angle, SlipConvention};
+use crate::force_model::{tire_forces, BrushParams, ForceModel};
+use crate::pacejka::{pacejka_fx, PacejkaCoeffs};
+use crate::suspension::{travel_stop_force, TravelStopParams};
+use crate::tire_state::{TireSimParameters, TireState};
+use crate::wear::{tire_wear_thermal_step, LapSample};
+use crate::{Vec2, Vec3};
+
+/// Side order for every two-element array in this module.
+pub const AXLE_LEFT: usize = 0;
+pub const AXLE_RIGHT: usize = 1;
+
+/// Longitudinal speed below which the slip ratio denominator is held, so a
+/// wheel spinning up from rest stays bounded.
+pub const SLIP_SPEED_FLOOR_M_PER_S: f32 = 0.5;
+
+/// Hub velocity (vehicle plane, `x` forward, `y` right) in the wheel frame of a
+/// wheel steered by `steer_rad` (positive to the right): `x` along the wheel
+/// heading, `y` to its right.
+pub fn steer_kinematics(hub_velocity: Vec2, steer_rad: f32) -> Vec2 {
+    let (sin, cos) = steer_rad.sin_cos();
+    Vec2 {
+        x: hub_velocity.x * cos + hub_velocity.y * sin,
+        y: -hub_velocity.x * sin + hub_velocity.y * cos,
+    }
+}
+
+/// Tyre force from the wheel frame (`x` along the wheel heading, `y` along the
+/// cambered wheel's vertical, `z` lateral to the right) into the chassis frame
+/// (`x` forward, `y` up, `z` right). Camber (positive tilts the top of the
+/// wheel to the right) is undone first, then the steer angle (positive to the
+/// right, as in `steer_kinematics`). Non-finite angles read as zero and a
+/// non-finite result as no force.
+pub fn tire_to_chassis_force(
+    tire_force: Vec3,
+    wheel_steer_angle_rad: f32,
+    camber_rad: f32,
+) -> Vec3 {
+    let finite = |a: f32| if a.is_finite() { a } else { 0.0 };
+    let (sin_c, cos_c) = finite(camber_rad).sin_cos();
+    let (sin_s, cos_s) = finite(wheel_steer_angle_rad).sin_cos();
+    let y = tire_force.y * cos_c - tire_force.z * sin_c;
+    let lateral = tire_force.y * sin_c + tire_force.z * cos_c;
+    let chassis = Vec3 {
+        x: tire_force.x * cos_s - lateral * sin_s,
+        y,
+        z: tire_force.x * sin_s + lateral * cos_s,
+    };
+    if chassis.x.is_finite() && chassis.y.is_finite() && chassis.z.is_finite() {
+        chassis
+    } else {
+        Vec3::default()
+    }
+}
+
+/// Steer angle over which `caster_self_centering_torque` fades to zero, so
+/// a released wheel settles on centre instead of chattering across it.
+pub const CASTER_CENTERING_BLEND_RAD: f32 = 0.02;
+
+/// Net steering torque about the kingpin returning a steered wheel to centre
+/// (positive steers right, as `steer_rad`): the lateral force acting
+/// `mechanical_trail_m` behind the steering axis, less the Coulomb friction
+/// of the steering system, and zero when friction holds the wheel. It opposes
+/// `current_steer_angle_rad` and fades out within
+/// `CASTER_CENTERING_BLEND_RAD` of centre. Non-finite input gives zero.
+pub fn caster_self_centering_torque(
+    lateral_force_n: f32,
+    mechanical_trail_m: f32,
+    steering_friction_nm: f32,
+    current_steer_angle_rad: f32,
+) -> f32 {
+    let moment = lateral_force_n.abs() * mechanical_trail_m.max(0.0);
+    let net = (moment - steering_friction_nm.max(0.0)).max(0.0);
+    let fade = (current_steer_angle_rad.abs() / CASTER_CENTERING_BLEND_RAD).min(1.0);
+    let torque = -current_steer_angle_rad.signum() * net * fade;
+    if torque.is_finite() {
+        torque
+    } else {
+        0.0
+    }
+}
+
+/// Anti-roll bar wheel loads `[left, right]`: the bar pushes the more compressed
+/// side down and lifts the other by the same amount.
+pub fn anti_roll_bar_force(travel_left_m: f32, travel_right_m: f32, bar_rate: f32) -> [f32; 2] {
+    let force = bar_rate.max(0.0) * (travel_left_m - travel_right_m);
+    if force.is_finite() {
+        [force, -force]
+    } else {
+        [0.0; 2]
+    }
+}
+
+/// Open differential: the axle torque splits evenly `[left, right]`.
+pub fn open_differential_split(axle_torque: f32) -> [f32; 2] {
+    let half = if axle_torque.is_finite() {
+        0.5 * axle_torque
+    } else {
+        0.0
+    };
+    [half, half]
+}
+
+/// `(omega * r - v) / max(|v|, SLIP_SPEED_FLOOR_M_PER_S)`.
+///
+/// Sign conventions, relied on by every force model, the wear step and the
+/// effects: `omega` and `v` are both positive forwards. The numerator is the
+/// tread speed relative to the road (`longitudinal_slip_velocity`) and the
+/// denominator is a magnitude, so the slip ratio has the sign of the force
+/// the road exerts on the tyre in all four sign combinations: positive
+/// (driving) when the tread runs forward over the road, negative (braking)
+/// when it runs backwards. Reversing on a braked wheel, spinning backwards
+/// while rolling forward and a forward burnout while reversing therefore need
+/// no special cases; `|slip| > 1` simply means the wheel turns against the
+/// direction of travel. The sliding speed `|omega * r - v|` equals
+/// `|slip| * max(|v|, SLIP_SPEED_FLOOR_M_PER_S)`, which is the speed the wear
+/// sample must carry.
+pub fn longitudinal_slip_ratio(wheel_omega: f32, radius_m: f32, velocity_longitudinal: f32) -> f32 {
+    let slip = (wheel_omega * radius_m - velocity_longitudinal)
+        / velocity_longitudinal.abs().max(SLIP_SPEED_FLOOR_M_PER_S);
+    if slip.is_finite() {
+        slip
+    } else {
+        0.0
+    }
+}
+
+/// Tread speed relative to the road along the wheel heading, `omega * r - v`
+/// (positive when the tread runs forward over the road). Its magnitude is the
+/// longitudinal sliding speed fed to the effects.
+pub fn longitudinal_slip_velocity(
+    wheel_omega: f32,
+    radius_m: f32,
+    velocity_longitudinal: f32,
+) -> f32 {
+    let slip = wheel_omega * radius_m - velocity_longitudinal;
+    if slip.is_finite() {
+        slip
+    } else {
+        0.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AxleInput {
+    pub travel_m: [f32; 2],
+    pub travel_rate: [f32; 2],
+    pub steer_rad: [f32; 2],
+    /// Hub velocities in the vehicle plane (`x` forward, `y` right).
+    pub hub_velocity: [Vec2; 2],
+    pub wheel_omega: [f32; 2],
+    pub drive_torque: f32,
+    pub surface_mu: f32,
+    pub delta: f32,
+    pub fidelity: FidelityLevel,
+}
+
+/// Per-axle cost/accuracy trade-off for `axle_step`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FidelityLevel {
+    /// Pure-slip Magic Formula longitudinally, saturating linear laterally.
+    #[default]
+    Full,
+    /// One analytic contact: the closed-form combined-slip brush model with
+    /// the Magic Formula's slip stiffness at the current load. Below the
+    /// friction limit it stays within 10% of the wheel load of `Full`.
+    Reduced,
+    /// `Reduced` forces with wear and temperature integrated at
+    /// `MINIMAL_FIDELITY_UPDATE_S` instead of every step.
+    Minimal,
+}
+
+/// Wear and thermal cadence under `FidelityLevel::Minimal` (10 Hz).
+pub const MINIMAL_FIDELITY_UPDATE_S: f32 = 0.1;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AxleParams {
+    /// Per-wheel load at zero travel.
+    pub static_load_n: f32,
+    pub spring_rate: f32,
+    pub damping: f32,
+    pub anti_roll_rate: f32,
+    pub stops: TravelStopParams,
+    pub tire_radius_m: f32,
+    pub cornering_stiffness: f32,
+    pub pacejka: PacejkaCoeffs,
+    pub wear: TireSimParameters,
+    /// Hub centres relative to the body centre of mass, SAE axes (`x` forward,
+    /// `y` right, `z` down).
+    pub hub_positions: [Vec3; 2],
+    /// Time over which forces blend between the Full and Reduced models after
+    /// a fidelity change; zero switches at once.
+    pub fidelity_transition_s: f32,
+}
+
+impl Default for AxleParams {
+    fn default() -> Self {
+        Self {
+            static_load_n: 3500.0,
+            spring_rate: 30000.0,
+            damping: 2500.0,
+            anti_roll_rate: 15000.0,
+            stops: TravelStopParams::default(),
+            tire_radius_m: 0.33,
+            cornering_stiffness: 60000.0,
+            pacejka: PacejkaCoeffs::default(),
+            wear: TireSimParameters::default(),
+            hub_positions: [
+                Vec3 {
+                    x: 1.15,
+                    y: -0.775,
+                    z: 0.2,
+                },
+                Vec3 {
+                    x: 1.15,
+                    y: 0.775,
+                    z: 0.2,
+                },
+            ],
+            fidelity_transition_s: 0.25,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AxleState {
+    pub tires: [TireState; 2],
+    /// Share of the Reduced force model in the output, moving towards the
+    /// requested fidelity at `1 / fidelity_transition_s` per second.
+    pub reduced_blend: f32,
+    /// Wear and thermal time not yet integrated under `FidelityLevel::Minimal`.
+    pub pending_wear_s: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AxleOutput {
+    /// Wrenches in SAE body axes; convert with `convert_vec3` for Godot.
+    pub wrenches: [HubWrench; 2],
+    pub tire_states: [TireState; 2],
+    pub diff_torques: [f32; 2],
+    pub normal_loads: [f32; 2],
+}
+
+/// One axle in the documented order: steer kinematics, wheel loads (spring,
+/// damper, stops, anti-roll bar), differential split, tyre forces, wear, hub
+/// wrenches. Identical to calling those pieces by hand; `state.tires` is
+/// advanced in place and copied to the output. The differential torques are
+/// returned, not applied: the crate does not integrate wheel speed, so the
+/// caller adds `diff_torques[side] - force.x * tire_radius_m` over the wheel
+/// inertia to its own `wheel_omega` (see `tests/full_vehicle_step.rs`).
+///
+/// `input.fidelity` picks the force model and wear cadence (see
+/// `FidelityLevel`). A change of force model blends over
+/// `params.fidelity_transition_s`, so switching at runtime never steps the
+/// forces; wear time deferred under `Minimal` is integrated on the next
+/// update, whatever the level by then.
+pub fn axle_step(input: &AxleInput, params: &AxleParams, state: &mut AxleState) -> AxleOutput {
+    let arb = anti_roll_bar_force(input.travel_m[0], input.travel_m[1], params.anti_roll_rate);
+    let diff_torques = open_differential_split(input.drive_torque);
+    let mu = if input.surface_mu.is_finite() {
+        input.surface_mu.max(0.0)
+    } else {
+        0.0
+    };
+    let coeffs = PacejkaCoeffs {
+        mu: params.pacejka.mu * mu,
+        ..params.pacejka
+    };
+
+    let delta = if input.delta.is_finite() {
+        input.delta.max(0.0)
+    } else {
+        0.0
+    };
+    let target = match input.fidelity {
+        FidelityLevel::Full => 0.0,
+        FidelityLevel::Reduced | FidelityLevel::Minimal => 1.0,
+    };
+    let blend = finite_or(state.reduced_blend, target).clamp(0.0, 1.0);
+    state.reduced_blend = if params.fidelity_transition_s > 0.0 {
+        let step = delta / params.fidelity_transition_s;
+        blend + (target - blend).clamp(-step, step)
+    } else {
+        target
+    };
+    let wear_duration = finite_or(state.pending_wear_s, 0.0).max(0.0) + delta;
+    let update_wear =
+        input.fidelity != FidelityLevel::Minimal || wear_duration >= MINIMAL_FIDELITY_UPDATE_S;
+    state.pending_wear_s = if update_wear { 0.0 } else { wear_duration };
+
+    let mut out = AxleOutput {
+        diff_torques,
+        ..AxleOutput::default()
+    };
+    for side in [AXLE_LEFT, AXLE_RIGHT] {
+        let velocity = steer_kinematics(input.hub_velocity[side], input.steer_rad[side]);
+        let travel = input.travel_m[side];
+        let rate = input.travel_rate[side];
+        let load = (params.static_load_n
+            + params.spring_rate * travel
+            + params.damping * rate
+            + travel_stop_force(travel, rate, params.stops)
+            + arb[side])
+            .max(0.0);
+
+        let slip_ratio =
+            longitudinal_slip_ratio(input.wheel_omega[side], params.tire_radius_m, velocity.x);
+        let alpha = slip_angle(velocity.x, velocity.y, SlipConvention::SaeJ670);
+        let (mut fx, mut fy) = (0.0, 0.0);
+        let reduced = state.reduced_blend;
+        if reduced < 1.0 {
+            let full_fx = pacejka_fx(&coeffs, slip_ratio, load);
+            let full_fy = lateral_force(
+                alpha,
+                params.cornering_stiffness,
+                load,
+                coeffs.mu,
+                SlipConvention::SaeJ670,
+            );
+            fx += (1.0 - reduced) * full_fx;
+            fy += (1.0 - reduced) * full_fy;
+        }
+        if reduced > 0.0 {
+            let brush = BrushParams {
+                // Slope of the Magic Formula at zero slip, `b c mu fz`.
+                longitudinal_stiffness: coeffs.b * coeffs.c * coeffs.mu * load,
+                cornering_stiffness: params.cornering_stiffness,
+                mu: coeffs.mu,
+            };
+            let (brush_fx, brush_fy) =
+                tire_forces(&ForceModel::Brush(brush), slip_ratio, alpha, load);
+            // The brush model is positive for positive slip; SAE J670 lateral
+            // force opposes the slip angle, as in `lateral_force`.
+            fx += reduced * brush_fx;
+            fy -= reduced * brush_fy;
+        }
+
+        let sample = LapSample {
+            load,
+            slip_ratio,
+            slip_angle: alpha,
+            // Sliding speed over slip ratio, so a burnout from rest still
+            // heats and wears the tyre.
+            speed: velocity.x.abs().max(SLIP_SPEED_FLOOR_M_PER_S),
+            duration_s: wear_duration,
+        };
+        if update_wear {
+            state.tires[side] = tire_wear_thermal_step(
+                state.tires[side],
+                &sample,
+                Some(&params.wear),
+                wear_duration,
+            );
+        }
+
+        let (sin, cos) = input.steer_rad[side].sin_cos();
+        let aggregate = ContactAggregate {
+            count: 1,
+            total_force: Vec3 {
+                x: fx * cos - fy * sin,
+                y: fx * sin + fy * cos,
+                z: -load,
+            },
+            ..ContactAggregate::default()
+        };
+        out.wrenches[side] = tire_hub_wrench(
+            &aggregate,
+            Vec3::default(),
+            params.hub_positions[side],
+            Vec3::default(),
+        );
+        out.normal_loads[side] = load;
+    }
+    out.tire_states = state.tires;
+    out
+}
+
+fn finite_or(value: f32, fallback: f32) -> f32 {
+    if value.is_finite() {
+        value
+    } else {
+        fallback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cornering_input() -> AxleInput {
+        AxleInput {
+            travel_m: [-0.01, 0.03],
+            travel_rate: [-0.05, 0.1],
+            steer_rad: [0.06, 0.05],
+            hub_velocity: [Vec2 { x: 30.0, y: 0.4 }, Vec2 { x: 30.0, y: 0.4 }],
+            wheel_omega: [92.0, 93.0],
+            drive_torque: 400.0,
+            surface_mu: 1.0,
+            delta: 1.0 / 60.0,
+            fidelity: FidelityLevel::Full,
+        }
+    }
+
+    #[test]
+    fn axle_step_matches_the_pieces_called_by_hand() {
+        let input = cornering_input();
+        let params = AxleParams::default();
+        let mut state = AxleState::default();
+        let out = axle_step(&input, &params, &mut state);
+
+        let arb = anti_roll_bar_force(input.travel_m[0], input.travel_m[1], params.anti_roll_rate);
+        assert_eq!(
+            out.diff_torques,
+            open_differential_split(input.drive_torque)
+        );
+        for side in [AXLE_LEFT, AXLE_RIGHT] {
+            let v = steer_kinematics(input.hub_velocity[side], input.steer_rad[side]);
+            let t = input.travel_m[side];
+            let r = input.travel_rate[side];
+            let load = (params.static_load_n
+                + params.spring_rate * t
+                + params.damping * r
+                + travel_stop_force(t, r, params.stops)
+                + arb[side])
+                .max(0.0);
+            assert_eq!(out.normal_loads[side], load);
+
+            let slip_ratio =
+                longitudinal_slip_ratio(input.wheel_omega[side], params.tire_radius_m, v.x);
+            let alpha = slip_angle(v.x, v.y, SlipConvention::SaeJ670);
+            let sample = LapSample {
+                load,
+                slip_ratio,
+                slip_angle: alpha,
+                speed: v.x.abs().max(SLIP_SPEED_FLOOR_M_PER_S),
+                duration_s: input.delta,
+            };
+            let tire = tire_wear_thermal_step(
+                TireState::default(),
+                &sample,
+                Some(&params.wear),
+                input.delta,
+            );
+            assert_eq!(out.tire_states[side], tire);
+            assert_eq!(state.tires[side], tire);
+
+            let fx = pacejka_fx(&params.pacejka, slip_ratio, load);
+            let fy = lateral_force(
+                alpha,
+                params.cornering_stiffness,
+                load,
+                params.pacejka.mu,
+                SlipConvention::SaeJ670,
+            );
+            let (sin, cos) = input.steer_rad[side].sin_cos();
+            let force = Vec3 {
+                x: fx * cos - fy * sin,
+                y: fx * sin + fy * cos,
+                z: -load,
+            };
+            assert_eq!(out.wrenches[side].force, force);
+            assert_eq!(
+                out.wrenches[side].torque_about_com,
+                params.hub_positions[side].cross(force)
+            );
+        }
+    }
+
+    #[test]
+    fn right_hand_turn_loads_the_outside_wheel_and_pulls_right() {
+        let input = AxleInput {
+            travel_m: [0.03, -0.01],
+            travel_rate: [0.0; 2],
+            steer_rad: [0.05; 2],
+            hub_velocity: [Vec2 { x: 25.0, y: 0.0 }; 2],
+            wheel_omega: [25.0 / 0.33; 2],
+            drive_torque: 0.0,
+            surface_mu: 1.0,
+            delta: 1.0 / 60.0,
+            fidelity: FidelityLevel::Full,
+        };
+        let out = axle_step(&input, &AxleParams::default(), &mut AxleState::default());
+        assert!(out.normal_loads[AXLE_LEFT] > out.normal_loads[AXLE_RIGHT]);
+        assert!(out.wrenches.iter().all(|w| w.force.y > 0.0));
+    }
+
+    #[test]
+    fn every_omega_velocity_quadrant_opposes_sliding_and_wears() {
+        let params = AxleParams::default();
+        let r = params.tire_radius_m;
+        // (v, omega * r): forward wheelspin, spinning backwards while rolling
+        // forward, reverse wheelspin, spinning forwards while reversing, and
+        // a burnout from rest.
+        for (v, tread) in [
+            (10.0, 12.0),
+            (10.0, -4.0),
+            (-10.0, -12.0),
+            (-10.0, 4.0),
+            (0.0, 6.0),
+            (0.0, -6.0),
+        ] {
+            let omega = tread / r;
+            let input = AxleInput {
+                hub_velocity: [Vec2 { x: v, y: 0.0 }; 2],
+                wheel_omega: [omega; 2],
+                surface_mu: 1.0,
+                delta: 0.1,
+                ..AxleInput::default()
+            };
+            let mut state = AxleState::default();
+            let out = axle_step(&input, &params, &mut state);
+            // The road slides backwards under a tread running forward.
+            let sliding = -longitudinal_slip_velocity(omega, r, v);
+            assert!(sliding.abs() > 1.0);
+            let slip = longitudinal_slip_ratio(omega, r, v);
+            for model in [
+                ForceModel::Pacejka(params.pacejka),
+                ForceModel::Brush(BrushParams::default()),
+            ] {
+                let (fx, _) = tire_forces(&model, slip, 0.0, params.static_load_n);
+                assert!(fx * sliding < 0.0, "{v} {tread}: {fx}");
+            }
+            let fx = out.wrenches[AXLE_LEFT].force.x;
+            assert!(fx * sliding < 0.0, "{v} {tread}: {fx}");
+            let sliding_from_sample = slip.abs() * v.abs().max(SLIP_SPEED_FLOOR_M_PER_S);
+            assert!((sliding_from_sample - sliding.abs()).abs() < 1.0e-4);
+            let tire = out.tire_states[AXLE_LEFT];
+            assert!(tire.wear > 0.0, "{v} {tread}");
+            assert!(tire.surface_temp_c > TireState::default().surface_temp_c);
+        }
+    }
+
+    #[test]
+    fn chassis_force_undoes_steer_and_camber() {
+        let force = Vec3 {
+            x: 1200.0,
+            y: 4000.0,
+            z: -900.0,
+        };
+        assert_eq!(tire_to_chassis_force(force, 0.0, 0.0), force);
+        let chassis = tire_to_chassis_force(force, 0.3, -0.05);
+        assert!((chassis.length() - force.length()).abs() < 1.0e-2);
+
+        // Steer alone is the inverse of `steer_kinematics` in the road plane.
+        let steered = tire_to_chassis_force(force, 0.3, 0.0);
+        let back = steer_kinematics(
+            Vec2 {
+                x: steered.x,
+                y: steered.z,
+            },
+            0.3,
+        );
+        assert!((back.x - force.x).abs() < 1.0e-2 && (back.y - force.z).abs() < 1.0e-2);
+        assert_eq!(steered.y, force.y);
+
+        // Driving force on a wheel steered 90 degrees right pushes the chassis right.
+        let drive = Vec3 {
+            x: 1000.0,
+            ..Vec3::default()
+        };
+        let right = tire_to_chassis_force(drive, std::f32::consts::FRAC_PI_2, 0.0);
+        assert!(right.x.abs() < 1.0e-3 && (right.z - 1000.0).abs() < 1.0e-3);
+
+        // Camber tilts the wheel's vertical load towards the side the top leans to.
+        let load = Vec3 {
+            y: 4000.0,
+            ..Vec3::default()
+        };
+        let tilted = tire_to_chassis_force(load, 0.0, 0.1);
+        assert!(tilted.z > 0.0 && tilted.y < 4000.0);
+
+        assert_eq!(tire_to_chassis_force(force, f32::NAN, f32::INFINITY), force);
+        let bad = Vec3 {
+            x: f32::NAN,
+            ..force
+        };
+        assert_eq!(tire_to_chassis_force(bad, 0.1, 0.0), Vec3::default());
+    }
+
+    #[test]
+    fn reduced_fidelity_tracks_full_in_a_steady_corner() {
+        // Below the friction limit, where both models are meant to agree; at
+        // the limit the saturating linear lateral force and the brush curve
+        // differ by design.
+        let params = AxleParams::default();
+        let corner = AxleInput {
+            steer_rad: [0.02; 2],
+            hub_velocity: [Vec2 { x: 30.0, y: 0.0 }; 2],
+            ..cornering_input()
+        };
+        let full = axle_step(&corner, &params, &mut AxleState::default());
+        let input = AxleInput {
+            fidelity: FidelityLevel::Reduced,
+            ..corner
+        };
+        let mut state = AxleState {
+            reduced_blend: 1.0,
+            ..AxleState::default()
+        };
+        let reduced = axle_step(&input, &params, &mut state);
+        // Stated tolerance: 10% of the wheel load on each planar force.
+        for side in [AXLE_LEFT, AXLE_RIGHT] {
+            let (a, b) = (full.wrenches[side].force, reduced.wrenches[side].force);
+            let tolerance = 0.1 * full.normal_loads[side];
+            assert!((a.x - b.x).abs() < tolerance, "{a:?} vs {b:?}");
+            assert!((a.y - b.y).abs() < tolerance, "{a:?} vs {b:?}");
+            assert!(a.x.abs() > 100.0 && a.y.abs() > 100.0);
+        }
+        assert_eq!(full.tire_states, reduced.tire_states);
+    }
+
+    #[test]
+    fn fidelity_switch_blends_without_a_force_step() {
+        let params = AxleParams::default();
+        let dt = 1.0 / 240.0;
+        let mut state = AxleState::default();
+        let mut previous = None;
+        let mut largest_jump = 0.0_f32;
+        let mut mid_blend = None;
+        for step in 0..240 {
+            let fidelity = if step < 60 {
+                FidelityLevel::Full
+            } else {
+                FidelityLevel::Reduced
+            };
+            let input = AxleInput {
+                fidelity,
+                delta: dt,
+                ..cornering_input()
+            };
+            let out = axle_step(&input, &params, &mut state);
+            let force = out.wrenches[AXLE_LEFT].force;
+            if let Some(prev) = previous {
+                largest_jump = largest_jump.max((force - prev).length());
+            }
+            if step == 90 {
+                mid_blend = Some(state.reduced_blend);
+            }
+            previous = Some(force);
+        }
+        let mid = mid_blend.unwrap();
+        assert!(mid > 0.0 && mid < 1.0, "{mid}");
+        assert_eq!(state.reduced_blend, 1.0);
+
+        // The whole Full-to-Reduced gap, spread over the transition.
+        let full = axle_step(&cornering_input(), &params, &mut AxleState::default());
+        let settled = previous.unwrap();
+        let gap = (full.wrenches[AXLE_LEFT].force - settled).length();
+        let steps = params.fidelity_transition_s / dt;
+        assert!(
+            largest_jump < 2.0 * gap / steps + 1.0,
+            "{largest_jump} vs {gap}"
+        );
+
+        let instant = AxleParams {
+            fidelity_transition_s: 0.0,
+            ..params
+        };
+        let mut state = AxleState::default();
+        let input = AxleInput {
+            fidelity: FidelityLevel::Minimal,
+            ..cornering_input()
+        };
+        axle_step(&input, &instant, &mut state);
+        assert_eq!(state.reduced_blend, 1.0);
+    }
+
+    #[test]
+    fn minimal_fidelity_integrates_wear_at_ten_hertz() {
+        let params = AxleParams::default();
+        let input = AxleInput {
+            fidelity: FidelityLevel::Minimal,
+            delta: 0.025,
+            ..cornering_input()
+        };
+        let mut state = AxleState::default();
+        for _ in 0..3 {
+            let out = axle_step(&input, &params, &mut state);
+            assert_eq!(out.tire_states, [TireState::default(); 2]);
+        }
+        assert!((state.pending_wear_s - 0.075).abs() < 1.0e-6);
+        let out = axle_step(&input, &params, &mut state);
+        assert_eq!(state.pending_wear_s, 0.0);
+        assert!(out.tire_states[AXLE_LEFT].wear > 0.0);
+
+        // Deferred time is not lost when the level changes between updates.
+        let mut minimal = AxleState::default();
+        axle_step(&input, &params, &mut minimal);
+        let full_input = AxleInput {
+            fidelity: FidelityLevel::Full,
+            ..input
+        };
+        let out = axle_step(&full_input, &params, &mut minimal);
+        let mut full = AxleState::default();
+        axle_step(&full_input, &params, &mut full);
+        axle_step(&full_input, &params, &mut full);
+        let combined = out.tire_states[AXLE_LEFT].wear;
+        let stepped = full.tires[AXLE_LEFT].wear;
+        assert!(
+            (combined - stepped).abs() <= 0.05 * stepped,
+            "{combined} vs {stepped}"
+        );
+    }
+
+    #[test]
+    fn caster_torque_returns_the_wheel_to_centre() {
+        let (trail, friction) = (0.03, 5.0);
+        // Lateral force builds with steer as the tyres take up slip.
+        let torque =
+            |steer: f32| caster_self_centering_torque(40000.0 * steer, trail, friction, steer);
+        assert_eq!(
+            caster_self_centering_torque(2000.0, trail, friction, 0.0),
+            0.0
+        );
+        let mut previous = 0.0;
+        for steer in [0.005, 0.01, 0.02, 0.05, 0.1, 0.2] {
+            let t = torque(steer);
+            assert!(t < previous, "{steer}: {t}");
+            assert_eq!(torque(-steer), -t);
+            previous = t;
+        }
+        assert!((torque(0.1) + (4000.0 * trail - friction)).abs() < 1.0e-3);
+
+        assert_eq!(caster_self_centering_torque(0.0, trail, friction, 0.1), 0.0);
+        // Friction holds a lightly loaded wheel where it is.
+        assert_eq!(
+            caster_self_centering_torque(100.0, trail, friction, 0.1),
+            0.0
+        );
+        assert_eq!(
+            caster_self_centering_torque(f32::NAN, trail, friction, 0.1),
+            0.0
+        );
+    }
+}
@@ -0,0 +1,106 @@
+//! Build identification for loaders that must check they got the right
+//! library build (e.g. matching feature sets between networked peers).
+
+/// Bumped whenever a struct layout or function signature that bindings rely
+/// on changes.
+pub const TIRE_CORE_ABI_VERSION: u32 = 1;
+
+/// Bits of `BuildInfo::features`, one per cargo feature.
+pub const FEATURE_SERDE: u32 = 1 << 0;
+pub const FEATURE_VALIDATOR: u32 = 1 << 1;
+pub const FEATURE_DEBUG_VERBOSE: u32 = 1 << 2;
+
+/// Capacity of `BuildInfo::git_hash`, including the terminating NUL.
+pub const GIT_HASH_LEN: usize = 41;
+
+/// Fixed-size, allocation-free build description. `git_hash` is the
+/// NUL-terminated `TIRE_CORE_GIT_HASH` set at compile time, or `unknown`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuildInfo {
+    pub version_major: u32,
+    pub version_minor: u32,
+    pub version_patch: u32,
+    pub abi_version: u32,
+    pub features: u32,
+    pub git_hash: [u8; GIT_HASH_LEN],
+}
+
+const fn parse_u32(s: &str) -> u32 {
+    let bytes = s.as_bytes();
+    let mut value = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        value = value * 10 + (bytes[i] - b'0') as u32;
+        i += 1;
+    }
+    value
+}
+
+const fn enabled_features() -> u32 {
+    let mut bits = 0;
+    if cfg!(feature = "serde") {
+        bits |= FEATURE_SERDE;
+    }
+    if cfg!(feature = "validator") {
+        bits |= FEATURE_VALIDATOR;
+    }
+    if cfg!(feature = "debug_verbose") {
+        bits |= FEATURE_DEBUG_VERBOSE;
+    }
+    bits
+}
+
+pub fn tire_core_build_info() -> BuildInfo {
+    let hash = option_env!("TIRE_CORE_GIT_HASH").unwrap_or("unknown");
+    let mut git_hash = [0_u8; GIT_HASH_LEN];
+    let len = hash.len().min(GIT_HASH_LEN - 1);
+    git_hash[..len].copy_from_slice(&hash.as_bytes()[..len]);
+    BuildInfo {
+        version_major: parse_u32(env!("CARGO_PKG_VERSION_MAJOR")),
+        version_minor: parse_u32(env!("CARGO_PKG_VERSION_MINOR")),
+        version_patch: parse_u32(env!("CARGO_PKG_VERSION_PATCH")),
+        abi_version: TIRE_CORE_ABI_VERSION,
+        features: enabled_features(),
+        git_hash,
+    }
+}
+
+/// True when every bit of `feature_id` (one of the `FEATURE_*` constants) was
+/// compiled in. Unknown or zero ids return false.
+pub fn tire_core_has_feature(feature_id: u32) -> bool {
+    feature_id != 0 && enabled_features() & feature_id == feature_id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_info_reports_version_and_features() {
+        let info = tire_core_build_info();
+        let version = format!(
+            "{}.{}.{}",
+            info.version_major, info.version_minor, info.version_patch
+        );
+        assert_eq!(version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(info.abi_version, TIRE_CORE_ABI_VERSION);
+        assert_eq!(info.git_hash[GIT_HASH_LEN - 1], 0);
+        assert!(info.git_hash[0] != 0);
+
+        assert_eq!(
+            tire_core_has_feature(FEATURE_SERDE),
+            cfg!(feature = "serde")
+        );
+        assert_eq!(
+            tire_core_has_feature(FEATURE_DEBUG_VERBOSE),
+            cfg!(feature = "debug_verbose")
+        );
+        assert!(!tire_core_has_feature(0));
+        assert!(!tire_core_has_feature(1 << 31));
+        assert_eq!(
+            info.features & FEATURE_VALIDATOR != 0,
+            cfg!(feature = "validator")
+        );
+    }
+}
@@ -0,0 +1,1577 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::report::{tire_convert, Unit};
+use crate::{Mat3, Vec3};
+
+/// One resolved contact: world-space position, surface normal, force applied to
+/// the tyre, the patch area it represents and the surface grip (friction
+/// coefficient) under it.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ContactPoint {
+    pub position: Vec3,
+    pub normal: Vec3,
+    pub force: Vec3,
+    pub area: f32,
+    pub grip: f32,
+}
+
+/// Sum of a wheel's contacts. `torque` is `sum((p_i - origin) x F_i)` about the
+/// `global_origin` passed to `aggregate_contacts`; `spread_m` is the largest
+/// distance of a contact from `average_position`; `grip` is the mean contact grip.
+/// `normal_force` sums each point's force projected onto its own normal and
+/// `tangential_force` the remainder; `aggregate_contacts` builds `total_force`
+/// as their sum. `max_pressure` is the largest per-point normal force over
+/// area (Pa), skipping points without area. `normal_variance` is
+/// `patch::contact_normal_variance` over the points, taken about
+/// `average_normal`, with a point without a normal counting as a dot product
+/// of 0; above `patch::NORMAL_VARIANCE_UNRELIABLE`, weight the aggregate
+/// less in force integration.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ContactAggregate {
+    pub count: u32,
+    pub total_force: Vec3,
+    pub torque: Vec3,
+    pub average_position: Vec3,
+    pub average_normal: Vec3,
+    pub contact_area: f32,
+    pub spread_m: f32,
+    pub grip: f32,
+    pub normal_force: Vec3,
+    pub tangential_force: Vec3,
+    pub max_pressure: f32,
+    pub normal_variance: f32,
+}
+
+/// Running sums behind `aggregate_contacts`, so one pass over a shared point
+/// array can feed several aggregates.
+#[derive(Default)]
+struct AggregateSums {
+    agg: ContactAggregate,
+    position_sum: Vec3,
+    normal_sum: Vec3,
+    unit_normal_sum: Vec3,
+    grip_sum: f32,
+}
+
+impl AggregateSums {
+    fn add(&mut self, p: &ContactPoint, global_origin: Vec3) {
+        let agg = &mut self.agg;
+        agg.count += 1;
+        let n = p.normal.normalized();
+        let normal_part = n * p.force.dot(n);
+        if p.area > 0.0 {
+            let pressure = p.force.dot(n).abs() / p.area;
+            if pressure.is_finite() {
+                agg.max_pressure = agg.max_pressure.max(pressure);
+            }
+        }
+        agg.normal_force = agg.normal_force + normal_part;
+        agg.tangential_force = agg.tangential_force + (p.force - normal_part);
+        agg.torque = agg.torque + (p.position - global_origin).cross(p.force);
+        agg.contact_area += p.area.max(0.0);
+        self.position_sum = self.position_sum + p.position;
+        self.normal_sum = self.normal_sum + p.normal;
+        self.unit_normal_sum = self.unit_normal_sum + n;
+        self.grip_sum += p.grip.max(0.0);
+    }
+
+    /// Everything but `spread_m` and `normal_variance`, which need the finished
+    /// average position and normal, plus the mean of `n_i . average_normal`
+    /// the variance is taken about.
+    fn finish(self) -> (ContactAggregate, f32) {
+        let mut agg = self.agg;
+        if agg.count == 0 {
+            return (ContactAggregate::default(), 0.0);
+        }
+        agg.total_force = agg.normal_force + agg.tangential_force;
+        agg.average_position = self.position_sum * (1.0 / agg.count as f32);
+        agg.grip = self.grip_sum / agg.count as f32;
+        agg.average_normal = self.normal_sum.normalized();
+        let mean_dot = self.unit_normal_sum.dot(agg.average_normal) / agg.count as f32;
+        (agg, mean_dot)
+    }
+}
+
+/// Second-pass accumulation of `spread_m` and `normal_variance`.
+fn spread_about(p: &ContactPoint, agg: &mut ContactAggregate, mean_dot: f32) {
+    agg.spread_m = agg
+        .spread_m
+        .max((p.position - agg.average_position).length());
+    let deviation = p.normal.normalized().dot(agg.average_normal) - mean_dot;
+    agg.normal_variance += deviation * deviation / agg.count as f32;
+}
+
+pub fn aggregate_contacts(points: &[ContactPoint], global_origin: Vec3) -> ContactAggregate {
+    let mut sums = AggregateSums::default();
+    for p in points {
+        sums.add(p, global_origin);
+    }
+    let (mut agg, mean_dot) = sums.finish();
+    for p in points {
+        spread_about(p, &mut agg, mean_dot);
+    }
+    agg
+}
+
+/// Result of `tire_aggregate_shared_patch`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SharedPatchAggregate {
+    /// Every assigned point, torque about the track origin.
+    pub track: ContactAggregate,
+    /// Points left out because their wheel index was out of range or missing.
+    pub skipped: u32,
+}
+
+/// Aggregates for several wheels pressing on one shared contact array (road
+/// wheels on a track). `wheel_indices[i]` names the wheel carrying
+/// `points[i]`; wheel `w` is aggregated into `out[w]` with its torque about
+/// `origins[w]`, for `w` below `min(out.len(), origins.len())`. Points with an
+/// index outside that range, or beyond `wheel_indices`, are skipped and
+/// counted. The arrays are read in place, once for the sums and once for the
+/// spreads.
+pub fn tire_aggregate_shared_patch(
+    points: &[ContactPoint],
+    wheel_indices: &[u32],
+    origins: &[Vec3],
+    track_origin: Vec3,
+    out: &mut [ContactAggregate],
+) -> SharedPatchAggregate {
+    let wheel_count = out.len().min(origins.len());
+    let wheel_of = |i: usize| {
+        wheel_indices
+            .get(i)
+            .map(|&w| w as usize)
+            .filter(|&w| w < wheel_count)
+    };
+    let mut wheels: Vec<AggregateSums> =
+        (0..wheel_count).map(|_| AggregateSums::default()).collect();
+    let mut track = AggregateSums::default();
+    let mut skipped = 0;
+    for (i, p) in points.iter().enumerate() {
+        match wheel_of(i) {
+            Some(w) => {
+                wheels[w].add(p, origins[w]);
+                track.add(p, track_origin);
+            }
+            None => skipped += 1,
+        }
+    }
+
+    out.fill(ContactAggregate::default());
+    let mut mean_dots = vec![0.0; wheel_count];
+    for ((slot, mean_dot), sums) in out.iter_mut().zip(mean_dots.iter_mut()).zip(wheels) {
+        (*slot, *mean_dot) = sums.finish();
+    }
+    let (mut track, track_mean_dot) = track.finish();
+    for (i, p) in points.iter().enumerate() {
+        if let Some(w) = wheel_of(i) {
+            spread_about(p, &mut out[w], mean_dots[w]);
+            spread_about(p, &mut track, track_mean_dot);
+        }
+    }
+    SharedPatchAggregate { track, skipped }
+}
+
+/// Part of the tyre a contact point touches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ContactRegion {
+    #[default]
+    Tread,
+    /// Sidewall scrubbing a wall or kerb face.
+    Sidewall,
+    /// Bare rim on the ground: metallic contact.
+    Rim,
+}
+
+/// Friction coefficient of sidewall rubber against a wall.
+pub const SIDEWALL_FRICTION: f32 = 0.6;
+/// Friction coefficient of a rim on the ground.
+pub const RIM_FRICTION: f32 = 0.15;
+
+impl ContactRegion {
+    /// Grip for a point of this region: the surface grip on the tread, the
+    /// fixed region coefficient otherwise.
+    pub fn friction(self, surface_grip: f32) -> f32 {
+        match self {
+            ContactRegion::Tread => surface_grip,
+            ContactRegion::Sidewall => SIDEWALL_FRICTION,
+            ContactRegion::Rim => RIM_FRICTION,
+        }
+    }
+}
+
+/// Contacts aggregated per region. Only `tread` feeds the grip, wear and heat
+/// models; `sidewall` feeds `failure::sidewall_damage_step`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RegionAggregate {
+    pub tread: ContactAggregate,
+    pub sidewall: ContactAggregate,
+    pub rim: ContactAggregate,
+}
+
+impl RegionAggregate {
+    /// Force on the wheel from every region.
+    pub fn total_force(&self) -> Vec3 {
+        self.tread.total_force + self.sidewall.total_force + self.rim.total_force
+    }
+
+    /// Torque about the aggregation origin from every region.
+    pub fn total_torque(&self) -> Vec3 {
+        self.tread.torque + self.sidewall.torque + self.rim.torque
+    }
+
+    /// Metal on the ground this step: worth a spark effect.
+    pub fn sparks(&self) -> bool {
+        self.rim.count > 0
+    }
+}
+
+/// `aggregate_contacts` split by `regions[i]` (points without an entry are
+/// tread). Sidewall and rim points take their region friction as grip.
+pub fn aggregate_contacts_by_region(
+    points: &[ContactPoint],
+    regions: &[ContactRegion],
+    global_origin: Vec3,
+) -> RegionAggregate {
+    let mut split: [Vec<ContactPoint>; 3] = Default::default();
+    for (i, point) in points.iter().enumerate() {
+        let region = regions.get(i).copied().unwrap_or_default();
+        split[region as usize].push(ContactPoint {
+            grip: region.friction(point.grip),
+            ..*point
+        });
+    }
+    let [tread, sidewall, rim] = split.map(|p| aggregate_contacts(&p, global_origin));
+    RegionAggregate {
+        tread,
+        sidewall,
+        rim,
+    }
+}
+
+/// Extra work for `aggregate_contacts_ex`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AggregateOptions<'a> {
+    /// Sum the aligning moment from the per-point forces instead of leaving it
+    /// to a parametric trail.
+    pub compute_mz_from_patch: bool,
+    /// Per-point position along the patch from its centre (m, positive towards
+    /// the leading edge), paired with `points`.
+    pub longitudinal_m: &'a [f32],
+    /// Wheel lateral axis (world space) used to read each point's lateral force.
+    pub lateral_axis: Vec3,
+}
+
+/// `aggregate_contacts` plus, when `compute_mz_from_patch` is set, the aligning
+/// moment about the vertical axis through the patch centre,
+/// `sum(x_i * (F_i . lateral_axis))` (0 otherwise). With brush-distributed forces
+/// (`patch::brush_lateral_forces`) it peaks and then decays with slip angle on
+/// its own, without a pneumatic-trail curve.
+pub fn aggregate_contacts_ex(
+    points: &[ContactPoint],
+    global_origin: Vec3,
+    options: &AggregateOptions,
+) -> (ContactAggregate, f32) {
+    let aggregate = aggregate_contacts(points, global_origin);
+    if !options.compute_mz_from_patch {
+        return (aggregate, 0.0);
+    }
+    let axis = options.lateral_axis.normalized();
+    let mz: f32 = points
+        .iter()
+        .zip(options.longitudinal_m)
+        .map(|(p, &x)| x * p.force.dot(axis))
+        .sum();
+    (aggregate, if mz.is_finite() { mz } else { 0.0 })
+}
+
+fn is_duplicate_point(points: &[Vec3], kept: &[usize], p: Vec3, tolerance_m: f32) -> bool {
+    kept.iter()
+        .any(|&k| (points[k] - p).length() <= tolerance_m)
+}
+
+fn dedup_tolerance(tolerance_m: f32) -> f32 {
+    if tolerance_m.is_finite() {
+        tolerance_m.max(0.0)
+    } else {
+        0.0
+    }
+}
+
+/// Indices of the points to keep, in order: a point is dropped when it lies
+/// within `tolerance_m` of a point already kept (a negative or non-finite
+/// tolerance only merges exact repeats). Use the indices to gather the point
+/// arrays before aggregating, so a wheel touching two overlapping shapes is
+/// not counted twice.
+pub fn dedup_contact_points(points: &[Vec3], tolerance_m: f32) -> Vec<usize> {
+    let tolerance = dedup_tolerance(tolerance_m);
+    let mut kept = Vec::with_capacity(points.len());
+    for (i, &p) in points.iter().enumerate() {
+        if !is_duplicate_point(points, &kept, p, tolerance) {
+            kept.push(i);
+        }
+    }
+    kept
+}
+
+/// `dedup_contact_points` into `out`, returning the indices written. Stops
+/// once `out` is full, so size it to `points.len()` to keep every unique
+/// point.
+pub fn dedup_contact_indices_into(points: &[Vec3], tolerance_m: f32, out: &mut [usize]) -> usize {
+    let tolerance = dedup_tolerance(tolerance_m);
+    let mut count = 0;
+    for (i, &p) in points.iter().enumerate() {
+        if count == out.len() {
+            break;
+        }
+        if !is_duplicate_point(points, &out[..count], p, tolerance) {
+            out[count] = i;
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Per-point `forces` (e.g. normal loads) summed by patch quadrant,
+/// `[front-left, front-right, rear-left, rear-right]`. Front is along `heading`
+/// from `centre` and right along `heading x normal`, with `normal` the patch
+/// normal (e.g. `average_normal`; `heading` need not be perpendicular to it).
+/// A point on a dividing axis is split evenly between the two sides, so a
+/// symmetric patch reads symmetric. A load bias to one side points to camber,
+/// a force bias to one end to toe scrub. Pairs beyond the shorter slice and
+/// non-finite forces are skipped.
+pub fn quadrant_forces(
+    points: &[Vec3],
+    forces: &[f32],
+    centre: Vec3,
+    heading: Vec3,
+    normal: Vec3,
+) -> [f32; 4] {
+    let normal = normal.normalized();
+    let forward = (heading - normal * heading.dot(normal)).normalized();
+    let right = forward.cross(normal);
+    let share = |d: f32| {
+        if d > 1.0e-6 {
+            1.0
+        } else if d < -1.0e-6 {
+            0.0
+        } else {
+            0.5
+        }
+    };
+    let mut quadrants = [0.0; 4];
+    for (&p, &f) in points.iter().zip(forces) {
+        if !f.is_finite() {
+            continue;
+        }
+        let offset = p - centre;
+        let front = share(offset.dot(forward));
+        let to_right = share(offset.dot(right));
+        quadrants[0] += f * front * (1.0 - to_right);
+        quadrants[1] += f * front * to_right;
+        quadrants[2] += f * (1.0 - front) * (1.0 - to_right);
+        quadrants[3] += f * (1.0 - front) * to_right;
+    }
+    quadrants
+}
+
+/// Version stored in slot 0 of the flat aggregate layout. Bump it whenever the
+/// layout below changes.
+pub const CONTACT_AGGREGATE_FLAT_VERSION: f32 = 4.0;
+/// Floats written by `tire_aggregate_contacts_flat`.
+pub const CONTACT_AGGREGATE_FLAT_LEN: usize = 25;
+
+/// Flat layout for bulk copies into a `PackedFloat32Array`:
+///
+/// | slot  | field                  |
+/// |-------|------------------------|
+/// | 0     | layout version         |
+/// | 1     | `count`                |
+/// | 2..5  | `total_force` x, y, z  |
+/// | 5..8  | `torque` x, y, z       |
+/// | 8..11 | `average_position`     |
+/// | 11..14| `average_normal`       |
+/// | 14    | `contact_area`         |
+/// | 15    | `spread_m`             |
+/// | 16    | `grip`                 |
+/// | 17..20| `normal_force`         |
+/// | 20..23| `tangential_force`     |
+/// | 23    | `max_pressure`         |
+/// | 24    | `normal_variance`      |
+///
+/// Returns `Ok(floats_written)`, or `Err(required_len)` without touching `out`
+/// when it is too short.
+pub fn tire_aggregate_contacts_flat(
+    points: &[ContactPoint],
+    global_origin: Vec3,
+    out: &mut [f32],
+) -> Result<usize, usize> {
+    if out.len() < CONTACT_AGGREGATE_FLAT_LEN {
+        return Err(CONTACT_AGGREGATE_FLAT_LEN);
+    }
+    let agg = aggregate_contacts(points, global_origin);
+    let v = |v: Vec3| [v.x, v.y, v.z];
+    let mut slots = [0.0; CONTACT_AGGREGATE_FLAT_LEN];
+    slots[0] = CONTACT_AGGREGATE_FLAT_VERSION;
+    slots[1] = agg.count as f32;
+    slots[2..5].copy_from_slice(&v(agg.total_force));
+    slots[5..8].copy_from_slice(&v(agg.torque));
+    slots[8..11].copy_from_slice(&v(agg.average_position));
+    slots[11..14].copy_from_slice(&v(agg.average_normal));
+    slots[14] = agg.contact_area;
+    slots[15] = agg.spread_m;
+    slots[16] = agg.grip;
+    slots[17..20].copy_from_slice(&v(agg.normal_force));
+    slots[20..23].copy_from_slice(&v(agg.tangential_force));
+    slots[23] = agg.max_pressure;
+    slots[24] = agg.normal_variance;
+    out[..CONTACT_AGGREGATE_FLAT_LEN].copy_from_slice(&slots);
+    Ok(CONTACT_AGGREGATE_FLAT_LEN)
+}
+
+/// Floats in the network-sync form of `contact_aggregate_compress`.
+pub const CONTACT_AGGREGATE_COMPACT_LEN: usize = 8;
+
+/// Eight-float form of an aggregate for network sync: `total_force` (0..3),
+/// `torque` (3..6), `max_pressure` (6) and `grip` (7). Positions, normal,
+/// area, spread and count are dropped.
+pub fn contact_aggregate_compress(agg: ContactAggregate) -> [f32; CONTACT_AGGREGATE_COMPACT_LEN] {
+    let f = agg.total_force;
+    let t = agg.torque;
+    [f.x, f.y, f.z, t.x, t.y, t.z, agg.max_pressure, agg.grip]
+}
+
+/// Inverse of `contact_aggregate_compress`. `contact_area` is recovered as
+/// `|total_force| / max_pressure`: exact for an evenly loaded patch with no
+/// tangential force, otherwise an estimate (the peak pressure exceeds the mean).
+/// Position, normal, spread, the normal / tangential split and
+/// `normal_variance` come back zeroed and `count` is 1 for a loaded aggregate,
+/// 0 otherwise.
+pub fn contact_aggregate_decompress(
+    compact: [f32; CONTACT_AGGREGATE_COMPACT_LEN],
+) -> ContactAggregate {
+    let total_force = Vec3 {
+        x: compact[0],
+        y: compact[1],
+        z: compact[2],
+    };
+    let force = total_force.length();
+    let contact_area = if compact[6] > 0.0 {
+        force / compact[6]
+    } else {
+        0.0
+    };
+    ContactAggregate {
+        count: u32::from(force > 0.0),
+        total_force,
+        torque: Vec3 {
+            x: compact[3],
+            y: compact[4],
+            z: compact[5],
+        },
+        contact_area: if contact_area.is_finite() {
+            contact_area
+        } else {
+            0.0
+        },
+        grip: compact[7],
+        max_pressure: compact[6].max(0.0),
+        ..ContactAggregate::default()
+    }
+}
+
+/// Sub-step blend of two aggregates, `t` clamped to `[0, 1]`. Force, torque,
+/// area, spread, peak pressure and normal variance are lerped;
+/// `average_position` and `grip` are averaged with weights `(1 - t)|F_a|` and
+/// `t|F_b|` so an unloaded aggregate does not drag the patch centre (plain
+/// lerp when both are unloaded). `count` switches at `t = 0.5`.
+pub fn interpolate_contact_aggregates(
+    a: ContactAggregate,
+    b: ContactAggregate,
+    t: f32,
+) -> ContactAggregate {
+    let t = if t.is_finite() {
+        t.clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let lerp = |x: f32, y: f32| x + (y - x) * t;
+    let lerp3 = |x: Vec3, y: Vec3| x + (y - x) * t;
+
+    let wa = (1.0 - t) * a.total_force.length();
+    let wb = t * b.total_force.length();
+    let (average_position, grip) = if wa + wb > 1.0e-6 {
+        let k = wb / (wa + wb);
+        (
+            a.average_position + (b.average_position - a.average_position) * k,
+            a.grip + (b.grip - a.grip) * k,
+        )
+    } else {
+        (
+            lerp3(a.average_position, b.average_position),
+            lerp(a.grip, b.grip),
+        )
+    };
+
+    ContactAggregate {
+        count: if t < 0.5 { a.count } else { b.count },
+        total_force: lerp3(a.total_force, b.total_force),
+        torque: lerp3(a.torque, b.torque),
+        average_position,
+        average_normal: lerp3(a.average_normal, b.average_normal).normalized(),
+        contact_area: lerp(a.contact_area, b.contact_area),
+        spread_m: lerp(a.spread_m, b.spread_m),
+        grip,
+        normal_force: lerp3(a.normal_force, b.normal_force),
+        tangential_force: lerp3(a.tangential_force, b.tangential_force),
+        max_pressure: lerp(a.max_pressure, b.max_pressure),
+        normal_variance: lerp(a.normal_variance, b.normal_variance),
+    }
+}
+
+/// Velocity of the body-fixed point at `contact_point`,
+/// `v + w x (contact_point - body_origin)`, with `body_origin` the point whose
+/// velocity `body_linear_velocity` is (the centre of mass for a rigid body).
+pub fn contact_point_velocity(
+    body_linear_velocity: Vec3,
+    body_angular_velocity: Vec3,
+    contact_point: Vec3,
+    body_origin: Vec3,
+) -> Vec3 {
+    body_linear_velocity + body_angular_velocity.cross(contact_point - body_origin)
+}
+
+/// Velocity of the tread at `contact_point` relative to the hub, from the
+/// wheel spinning at `wheel_omega` about `spin_axis` (normalised here). Take
+/// `spin_axis = up x heading` so positive `wheel_omega` rolls forward, as
+/// everywhere else in the crate; the tread under the hub then moves
+/// backwards.
+pub fn wheel_surface_velocity(
+    wheel_omega: f32,
+    spin_axis: Vec3,
+    contact_point: Vec3,
+    wheel_center: Vec3,
+) -> Vec3 {
+    (spin_axis.normalized() * wheel_omega).cross(contact_point - wheel_center)
+}
+
+/// Tyre-minus-ground velocity of the tread at `contact_point` over a static
+/// ground: the body-carried `contact_point_velocity` plus the
+/// `wheel_surface_velocity`. Zero for a wheel rolling without slip; its
+/// component along the heading is `-axle::longitudinal_slip_velocity`, and it
+/// is what `tire_aggregate_impulses` takes per point.
+pub fn relative_slide_velocity(
+    body_linear_velocity: Vec3,
+    body_angular_velocity: Vec3,
+    body_origin: Vec3,
+    wheel_omega: f32,
+    spin_axis: Vec3,
+    wheel_center: Vec3,
+    contact_point: Vec3,
+) -> Vec3 {
+    let velocity = contact_point_velocity(
+        body_linear_velocity,
+        body_angular_velocity,
+        contact_point,
+        body_origin,
+    ) + wheel_surface_velocity(wheel_omega, spin_axis, contact_point, wheel_center);
+    if velocity.length().is_finite() {
+        velocity
+    } else {
+        Vec3::default()
+    }
+}
+
+/// Impulse form of `aggregate_contacts` for engines that apply impulses at a known
+/// `delta`: each point contributes `force * delta` (so `total_force` is N·s and
+/// `torque` N·m·s). `relative_velocities[i]` is the tyre-minus-ground velocity
+/// at point `i`. The tangential impulse opposing it is capped at
+/// `m_i * |v_t|`, with `m_i = effective_mass_kg / count`, so one step can stop
+/// the sliding but never reverse it (an implicit-friction guard against stiff
+/// grip oscillating at low physics rates). Points without a velocity entry are
+/// not clamped. A non-positive or non-finite `delta` yields an empty aggregate.
+pub fn tire_aggregate_impulses(
+    points: &[ContactPoint],
+    relative_velocities: &[Vec3],
+    effective_mass_kg: f32,
+    global_origin: Vec3,
+    delta: f32,
+) -> ContactAggregate {
+    if points.is_empty() || !delta.is_finite() || delta <= 0.0 {
+        return ContactAggregate::default();
+    }
+    let point_mass = effective_mass_kg.max(0.0) / points.len() as f32;
+    let impulses: Vec<ContactPoint> = points
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let mut impulse = p.force * delta;
+            if let Some(&velocity) = relative_velocities.get(i) {
+                let n = p.normal.normalized();
+                let tangential_velocity = velocity - n * velocity.dot(n);
+                let speed = tangential_velocity.length();
+                if speed > 1.0e-6 {
+                    let slide_dir = tangential_velocity * (1.0 / speed);
+                    let opposing = -impulse.dot(slide_dir);
+                    let cap = point_mass * speed;
+                    if opposing > cap {
+                        impulse = impulse + slide_dir * (opposing - cap);
+                    }
+                }
+            }
+            ContactPoint {
+                force: impulse,
+                ..*p
+            }
+        })
+        .collect();
+    aggregate_contacts(&impulses, global_origin)
+}
+
+/// `aggregate` with `total_force`, `torque` and the force split multiplied by
+/// `delta` (N·s and N·m·s); the other fields, `max_pressure` included, are not
+/// force quantities and are copied. Signs are unchanged: add the results
+/// straight to the body's linear and angular momentum, the torque being about
+/// the same origin as the aggregate's. A non-positive or non-finite `delta`
+/// gives zero impulses.
+pub fn contact_aggregate_as_impulse(aggregate: &ContactAggregate, delta: f32) -> ContactAggregate {
+    let scale = if delta.is_finite() && delta > 0.0 {
+        delta
+    } else {
+        0.0
+    };
+    ContactAggregate {
+        total_force: aggregate.total_force * scale,
+        torque: aggregate.torque * scale,
+        normal_force: aggregate.normal_force * scale,
+        tangential_force: aggregate.tangential_force * scale,
+        ..*aggregate
+    }
+}
+
+/// Ground pressure used to size the patch of an impulsive contact (Pa).
+pub const TYPICAL_CONTACT_PRESSURE_PA: f32 = 220000.0;
+/// Assumed duration over which a collision impulse is delivered (s).
+pub const IMPULSE_CONTACT_DURATION_S: f32 = 0.01;
+
+/// Single-contact aggregate for a discrete collision event (pothole, kerb
+/// strike). `total_force` and `torque` carry the impulse along `contact_normal`
+/// (N·s and N·m·s) for `apply_impulse`. `contact_area` is the mean force over
+/// `IMPULSE_CONTACT_DURATION_S` divided by `TYPICAL_CONTACT_PRESSURE_PA`,
+/// independent of tyre stiffness, so `max_pressure` is that pressure (Pa).
+pub fn tire_impulse_contact(
+    contact_point: Vec3,
+    contact_normal: Vec3,
+    impulse_magnitude: f32,
+    grip: f32,
+    global_origin: Vec3,
+) -> ContactAggregate {
+    let normal = contact_normal.normalized();
+    let impulse = if impulse_magnitude.is_finite() {
+        impulse_magnitude.max(0.0)
+    } else {
+        0.0
+    };
+    let grip = if grip.is_finite() { grip.max(0.0) } else { 0.0 };
+    let total_force = normal * impulse;
+    let contact_area = impulse / IMPULSE_CONTACT_DURATION_S / TYPICAL_CONTACT_PRESSURE_PA;
+    ContactAggregate {
+        count: 1,
+        total_force,
+        torque: (contact_point - global_origin).cross(total_force),
+        average_position: contact_point,
+        average_normal: normal,
+        contact_area,
+        spread_m: 0.0,
+        grip,
+        normal_force: total_force,
+        tangential_force: Vec3::default(),
+        max_pressure: if contact_area > 0.0 {
+            TYPICAL_CONTACT_PRESSURE_PA
+        } else {
+            0.0
+        },
+        normal_variance: 0.0,
+    }
+}
+
+/// Peak contact stress (MPa), `max_pressure` converted.
+pub fn contact_stress_mpa(aggregate: &ContactAggregate) -> f32 {
+    tire_convert(aggregate.max_pressure, Unit::Pascal, Unit::Megapascal).unwrap_or(0.0)
+}
+
+/// Mean contact stress (MPa), `|total_force| / contact_area`; 0 without area.
+pub fn average_contact_stress_mpa(aggregate: &ContactAggregate) -> f32 {
+    if !aggregate.contact_area.is_finite() || aggregate.contact_area <= 0.0 {
+        return 0.0;
+    }
+    let pressure = aggregate.total_force.length() / aggregate.contact_area;
+    tire_convert(pressure, Unit::Pascal, Unit::Megapascal).unwrap_or(0.0)
+}
+
+/// Checks `torque` against `(average_position - global_origin) x total_force`.
+/// The two differ by the moment of the force distribution about the patch
+/// centre, bounded by `spread_m * |total_force|` when the contact forces point
+/// the same way, so the allowed error is that bound plus `tolerance` (N·m).
+pub fn validate_torque_consistency(
+    aggregate: &ContactAggregate,
+    global_origin: Vec3,
+    tolerance: f32,
+) -> bool {
+    let lumped = (aggregate.average_position - global_origin).cross(aggregate.total_force);
+    let error = (aggregate.torque - lumped).length();
+    let allowed = aggregate.spread_m * aggregate.total_force.length() + tolerance.max(0.0);
+    error.is_finite() && error <= allowed
+}
+
+/// Force and torque ready for `RigidBody3D.apply_central_force` and
+/// `apply_torque`: world-space force, torque about the body centre of mass.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HubWrench {
+    pub force: Vec3,
+    pub torque_about_com: Vec3,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HubWrenchBatch {
+    pub wheels: [HubWrench; 4],
+    pub total: HubWrench,
+}
+
+/// Wheel wrench about the body centre of mass. Convention: `aggregate` was built
+/// with `global_origin = wheel_center`, so its torque is about the hub, and
+/// `moments` holds the tyre's pure couples (aligning, rolling resistance,
+/// overturning) in world space. The hub-to-COM `r x F` term is added here, so
+/// the result must be applied with `apply_central_force`, not `apply_force`.
+pub fn tire_hub_wrench(
+    aggregate: &ContactAggregate,
+    moments: Vec3,
+    wheel_center: Vec3,
+    body_center_of_mass: Vec3,
+) -> HubWrench {
+    let force = aggregate.total_force;
+    let torque_about_com =
+        aggregate.torque + moments + (wheel_center - body_center_of_mass).cross(force);
+    if force.length().is_finite() && torque_about_com.length().is_finite() {
+        HubWrench {
+            force,
+            torque_about_com,
+        }
+    } else {
+        HubWrench::default()
+    }
+}
+
+/// `tire_hub_wrench` for four wheels (indexed as in `vehicle`) plus their sum.
+pub fn tire_hub_wrench_batch(
+    aggregates: &[ContactAggregate; 4],
+    moments: &[Vec3; 4],
+    wheel_centers: &[Vec3; 4],
+    body_center_of_mass: Vec3,
+) -> HubWrenchBatch {
+    let mut batch = HubWrenchBatch::default();
+    for i in 0..4 {
+        let w = tire_hub_wrench(
+            &aggregates[i],
+            moments[i],
+            wheel_centers[i],
+            body_center_of_mass,
+        );
+        batch.wheels[i] = w;
+        batch.total.force = batch.total.force + w.force;
+        batch.total.torque_about_com = batch.total.torque_about_com + w.torque_about_com;
+    }
+    batch
+}
+
+/// Reference rigid-body update for one sub-step: `dv = F * dt / m` and
+/// `dw = I_inv * tau * dt`, with `torque` about the centre of mass and
+/// `inertia_tensor_inv` in the same (world) frame. Use it to check Godot's own
+/// integrator against the tyre model. Invalid `delta`, non-positive mass or a
+/// non-finite result leaves the velocities unchanged.
+pub fn integrate_tire_impulse(
+    body_velocity: &mut Vec3,
+    body_angular_velocity: &mut Vec3,
+    force: Vec3,
+    torque: Vec3,
+    mass_kg: f32,
+    inertia_tensor_inv: Mat3,
+    delta: f32,
+) {
+    if !delta.is_finite() || delta <= 0.0 || !mass_kg.is_finite() || mass_kg <= 0.0 {
+        return;
+    }
+    let dv = force * (delta / mass_kg);
+    let dw = inertia_tensor_inv * torque * delta;
+    if dv.length().is_finite() && dw.length().is_finite() {
+        *body_velocity = *body_velocity + dv;
+        *body_angular_velocity = *body_angular_velocity + dw;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patch(offset: Vec3) -> Vec<ContactPoint> {
+        let mut points = Vec::new();
+        for (i, load) in [1200.0, 1800.0, 2500.0, 1500.0].into_iter().enumerate() {
+            let x = -0.06 + 0.04 * i as f32;
+            points.push(ContactPoint {
+                position: offset
+                    + Vec3 {
+                        x,
+                        y: 0.0,
+                        z: 0.02 * (i % 2) as f32,
+                    },
+                normal: Vec3 {
+                    x: 0.0,
+                    y: 1.0,
+                    z: 0.0,
+                },
+                force: Vec3 {
+                    x: 0.3 * load,
+                    y: load,
+                    z: -0.1 * load,
+                },
+                area: 0.004,
+                grip: 1.0,
+            });
+        }
+        points
+    }
+
+    #[test]
+    fn aggregated_torque_matches_lumped_moment_within_spread() {
+        let origin = Vec3 {
+            x: 12.0,
+            y: -3.0,
+            z: 40.0,
+        };
+        let wheel = Vec3 {
+            x: 15.0,
+            y: 0.0,
+            z: 42.0,
+        };
+        let agg = aggregate_contacts(&patch(wheel), origin);
+        assert_eq!(agg.count, 4);
+        assert!((agg.contact_area - 0.016).abs() < 1.0e-6);
+        assert!(validate_torque_consistency(&agg, origin, 1.0e-2));
+
+        // Torque taken about the wrong origin is a regression the check must catch.
+        let wrong = aggregate_contacts(&patch(wheel), Vec3::default());
+        assert!(!validate_torque_consistency(&wrong, origin, 1.0e-2));
+    }
+
+    #[test]
+    fn hub_wrench_matches_hand_computed_single_contact() {
+        let hub = Vec3 {
+            x: 0.8,
+            y: 0.3,
+            z: 1.4,
+        };
+        let com = Vec3 {
+            x: 0.0,
+            y: 0.5,
+            z: 0.0,
+        };
+        // Contact 0.3 m below the hub carrying 4000 N up and 1000 N forward (-z).
+        let contact = ContactPoint {
+            position: hub
+                + Vec3 {
+                    x: 0.0,
+                    y: -0.3,
+                    z: 0.0,
+                },
+            force: Vec3 {
+                x: 0.0,
+                y: 4000.0,
+                z: -1000.0,
+            },
+            ..ContactPoint::default()
+        };
+        let agg = aggregate_contacts(&[contact], hub);
+        let aligning = Vec3 {
+            x: 0.0,
+            y: 25.0,
+            z: 0.0,
+        };
+        let w = tire_hub_wrench(&agg, aligning, hub, com);
+        // r = contact - com = (0.8, -0.5, 1.4); torque = r x F + aligning.
+        assert_eq!(w.force, contact.force);
+        let expected = Vec3 {
+            x: 500.0 - 5600.0,
+            y: 800.0 + 25.0,
+            z: 3200.0,
+        };
+        assert!((w.torque_about_com - expected).length() < 1.0e-2);
+
+        let batch = tire_hub_wrench_batch(&[agg; 4], &[aligning; 4], &[hub; 4], com);
+        assert!((batch.total.force - contact.force * 4.0).length() < 1.0e-2);
+        assert!((batch.total.torque_about_com - expected * 4.0).length() < 1.0e-1);
+    }
+
+    #[test]
+    fn impulse_contact_is_single_point_with_pressure_sized_area() {
+        let up = Vec3 {
+            x: 0.0,
+            y: 2.0,
+            z: 0.0,
+        };
+        let point = Vec3 {
+            x: 1.0,
+            y: 0.0,
+            z: 3.0,
+        };
+        let agg = tire_impulse_contact(point, up, 440.0, 0.9, Vec3::default());
+        assert_eq!(agg.count, 1);
+        assert_eq!(agg.total_force.y, 440.0);
+        assert!((agg.contact_area - 0.2).abs() < 1.0e-5);
+        assert_eq!(agg.grip, 0.9);
+        assert!(validate_torque_consistency(&agg, Vec3::default(), 1.0e-3));
+        // A pressure in Pa: the mean force over the area, not impulse / area.
+        let mean_force = agg.total_force.length() / IMPULSE_CONTACT_DURATION_S;
+        assert!((mean_force / agg.contact_area - agg.max_pressure).abs() < 1.0);
+        assert_eq!(agg.max_pressure, TYPICAL_CONTACT_PRESSURE_PA);
+        assert!((contact_stress_mpa(&agg) - 0.22).abs() < 1.0e-6);
+
+        let none = tire_impulse_contact(point, up, f32::NAN, 0.9, Vec3::default());
+        assert_eq!(none.contact_area, 0.0);
+        assert_eq!(none.max_pressure, 0.0);
+    }
+
+    #[test]
+    fn impulse_aggregation_cannot_reverse_sliding() {
+        let up = Vec3 {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        };
+        let point = ContactPoint {
+            normal: up,
+            force: Vec3 {
+                x: -12000.0,
+                y: 4000.0,
+                z: 300.0,
+            },
+            area: 0.01,
+            grip: 1.0,
+            ..ContactPoint::default()
+        };
+        let sliding = Vec3 {
+            x: 0.1,
+            y: -0.5,
+            z: 0.0,
+        };
+        let dt = 1.0 / 30.0;
+        let agg = tire_aggregate_impulses(&[point], &[sliding], 50.0, Vec3::default(), dt);
+        // Tangential impulse along -x capped at m * |v_t| = 5 N·s; normal and
+        // cross-slide components are untouched.
+        assert!((agg.total_force.x + 5.0).abs() < 1.0e-3);
+        assert!((agg.total_force.y - 4000.0 * dt).abs() < 1.0e-3);
+        assert!((agg.total_force.z - 300.0 * dt).abs() < 1.0e-3);
+
+        let gentle =
+            tire_aggregate_impulses(&[point], &[sliding * 1000.0], 50.0, Vec3::default(), dt);
+        assert!((gentle.total_force.x + 12000.0 * dt).abs() < 1.0e-2);
+        assert_eq!(
+            tire_aggregate_impulses(&[point], &[sliding], 50.0, Vec3::default(), 0.0),
+            ContactAggregate::default()
+        );
+    }
+
+    #[test]
+    fn interpolation_hits_endpoints_and_ignores_unloaded_position() {
+        let origin = Vec3::default();
+        let a = aggregate_contacts(&patch(Vec3::default()), origin);
+        let b = aggregate_contacts(
+            &patch(Vec3 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.5,
+            }),
+            origin,
+        );
+        assert_eq!(interpolate_contact_aggregates(a, b, 0.0), a);
+        let end = interpolate_contact_aggregates(a, b, 1.0);
+        assert!((end.average_position - b.average_position).length() < 1.0e-6);
+        let mid = interpolate_contact_aggregates(a, b, 0.5);
+        assert!((mid.total_force - (a.total_force + b.total_force) * 0.5).length() < 1.0e-2);
+
+        let airborne = ContactAggregate::default();
+        let blended = interpolate_contact_aggregates(a, airborne, 0.5);
+        assert!((blended.average_position - a.average_position).length() < 1.0e-6);
+        assert!((blended.contact_area - 0.5 * a.contact_area).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn flat_aggregate_layout_matches_struct() {
+        let points = [
+            ContactPoint {
+                position: Vec3 {
+                    x: 0.1,
+                    y: -0.3,
+                    z: 0.05,
+                },
+                normal: Vec3 {
+                    x: 0.0,
+                    y: 1.0,
+                    z: 0.0,
+                },
+                force: Vec3 {
+                    x: 50.0,
+                    y: 2000.0,
+                    z: -30.0,
+                },
+                area: 0.01,
+                grip: 1.1,
+            },
+            ContactPoint {
+                position: Vec3 {
+                    x: -0.1,
+                    y: -0.3,
+                    z: -0.05,
+                },
+                normal: Vec3 {
+                    x: 0.1,
+                    y: 1.0,
+                    z: 0.0,
+                },
+                force: Vec3 {
+                    x: 20.0,
+                    y: 1800.0,
+                    z: 10.0,
+                },
+                area: 0.012,
+                grip: 0.9,
+            },
+        ];
+        let origin = Vec3 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.2,
+        };
+        let agg = aggregate_contacts(&points, origin);
+        let mut out = [0.0; CONTACT_AGGREGATE_FLAT_LEN + 3];
+        assert_eq!(
+            tire_aggregate_contacts_flat(&points, origin, &mut out),
+            Ok(CONTACT_AGGREGATE_FLAT_LEN)
+        );
+        let expected = [
+            CONTACT_AGGREGATE_FLAT_VERSION,
+            agg.count as f32,
+            agg.total_force.x,
+            agg.total_force.y,
+            agg.total_force.z,
+            agg.torque.x,
+            agg.torque.y,
+            agg.torque.z,
+            agg.average_position.x,
+            agg.average_position.y,
+            agg.average_position.z,
+            agg.average_normal.x,
+            agg.average_normal.y,
+            agg.average_normal.z,
+            agg.contact_area,
+            agg.spread_m,
+            agg.grip,
+            agg.normal_force.x,
+            agg.normal_force.y,
+            agg.normal_force.z,
+            agg.tangential_force.x,
+            agg.tangential_force.y,
+            agg.tangential_force.z,
+            agg.max_pressure,
+            agg.normal_variance,
+        ];
+        assert_eq!(out[..CONTACT_AGGREGATE_FLAT_LEN], expected);
+        assert_eq!(out[CONTACT_AGGREGATE_FLAT_LEN..], [0.0; 3]);
+
+        let mut short = [7.0; 4];
+        assert_eq!(
+            tire_aggregate_contacts_flat(&points, origin, &mut short),
+            Err(CONTACT_AGGREGATE_FLAT_LEN)
+        );
+        assert_eq!(short, [7.0; 4]);
+    }
+
+    #[test]
+    fn impulse_integration_matches_newton_euler() {
+        let mut v = Vec3 {
+            x: 10.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        let mut w = Vec3::default();
+        let inertia_inv = Mat3::diagonal(Vec3 {
+            x: 1.0 / 500.0,
+            y: 1.0 / 2200.0,
+            z: 1.0 / 2000.0,
+        });
+        let force = Vec3 {
+            x: -7000.0,
+            y: 0.0,
+            z: 1400.0,
+        };
+        let torque = Vec3 {
+            x: 0.0,
+            y: 4400.0,
+            z: 0.0,
+        };
+        integrate_tire_impulse(&mut v, &mut w, force, torque, 1400.0, inertia_inv, 0.01);
+        assert!((v.x - 9.95).abs() < 1.0e-5 && (v.z - 0.01).abs() < 1.0e-6);
+        assert!((w.y - 0.02).abs() < 1.0e-6 && w.x == 0.0 && w.z == 0.0);
+
+        let (v0, w0) = (v, w);
+        integrate_tire_impulse(&mut v, &mut w, force, torque, 0.0, inertia_inv, 0.01);
+        integrate_tire_impulse(&mut v, &mut w, force, torque, 1400.0, inertia_inv, f32::NAN);
+        assert_eq!((v, w), (v0, w0));
+    }
+
+    #[test]
+    fn patch_mz_peaks_then_decays_like_parametric_trail() {
+        use crate::patch::{brush_lateral_forces, parametric_aligning_torque};
+
+        const N: usize = 40;
+        let (fz, mu, c_alpha, a) = (4000.0, 1.0, 60000.0, 0.08);
+        let xs: [f32; N] = std::array::from_fn(|i| -a + (i as f32 + 0.5) * 2.0 * a / N as f32);
+        let lateral_axis = Vec3 {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        };
+        let options = AggregateOptions {
+            compute_mz_from_patch: true,
+            longitudinal_m: &xs,
+            lateral_axis,
+        };
+        let full_sliding = 3.0 * mu * fz / c_alpha;
+
+        let mut emergent = Vec::new();
+        let mut parametric = Vec::new();
+        for step in 1..=30 {
+            let alpha = full_sliding * 1.2 * step as f32 / 30.0;
+            let mut forces = [0.0; N];
+            let fy = brush_lateral_forces(alpha, fz, mu, c_alpha, a, &xs, &mut forces);
+            let points: Vec<ContactPoint> = xs
+                .iter()
+                .zip(forces)
+                .map(|(&x, f)| ContactPoint {
+                    position: Vec3 {
+                        x,
+                        ..Vec3::default()
+                    },
+                    force: lateral_axis * f,
+                    ..ContactPoint::default()
+                })
+                .collect();
+            let (agg, mz) = aggregate_contacts_ex(&points, Vec3::default(), &options);
+            assert!((agg.total_force.y - fy).abs() < 1.0e-2);
+            emergent.push(mz);
+            parametric.push(parametric_aligning_torque(fy, alpha, a / 3.0, full_sliding));
+        }
+
+        let peak_index = |v: &[f32]| {
+            (0..v.len())
+                .max_by(|&i, &j| v[i].total_cmp(&v[j]))
+                .unwrap_or(0)
+        };
+        for curve in [&emergent, &parametric] {
+            let peak = peak_index(curve);
+            assert!(peak > 0 && peak < curve.len() - 1, "peak at {peak}");
+            assert!(curve[peak] > 0.0);
+            assert!(curve[curve.len() - 1].abs() < 0.05 * curve[peak]);
+        }
+        // Near-linear range: both come from the same a/3 trail.
+        assert!((emergent[0] - parametric[0]).abs() / parametric[0] < 0.1);
+
+        let (_, off) = aggregate_contacts_ex(
+            &[ContactPoint::default()],
+            Vec3::default(),
+            &AggregateOptions::default(),
+        );
+        assert_eq!(off, 0.0);
+    }
+
+    #[test]
+    fn region_split_keeps_sidewall_and_rim_out_of_the_tread() {
+        let points = patch(Vec3::default());
+        let regions = [
+            ContactRegion::Tread,
+            ContactRegion::Sidewall,
+            ContactRegion::Rim,
+        ];
+        let split = aggregate_contacts_by_region(&points, &regions, Vec3::default());
+        assert_eq!(split.tread.count, 2);
+        assert_eq!(split.sidewall.count, 1);
+        assert_eq!(split.sidewall.grip, SIDEWALL_FRICTION);
+        assert_eq!(split.rim.grip, RIM_FRICTION);
+        assert!(split.sparks());
+
+        let all = aggregate_contacts(&points, Vec3::default());
+        assert!((split.total_force() - all.total_force).length() < 1.0e-2);
+        assert!((split.total_torque() - all.torque).length() < 1.0e-2);
+
+        let tread_only = aggregate_contacts_by_region(&points, &[], Vec3::default());
+        assert_eq!(tread_only.tread, all);
+        assert!(!tread_only.sparks());
+    }
+
+    #[test]
+    fn aggregate_as_impulse_scales_only_force_quantities() {
+        let aggregate = aggregate_contacts(&patch(Vec3::default()), Vec3::default());
+        let delta = 1.0 / 120.0;
+        let impulse = contact_aggregate_as_impulse(&aggregate, delta);
+        assert_eq!(impulse.total_force, aggregate.total_force * delta);
+        assert_eq!(impulse.torque, aggregate.torque * delta);
+        assert_eq!(impulse.contact_area, aggregate.contact_area);
+        assert_eq!(impulse.grip, aggregate.grip);
+        assert_eq!(impulse.max_pressure, aggregate.max_pressure);
+        assert_eq!(impulse.average_position, aggregate.average_position);
+        // Matches the per-point impulse aggregate when nothing is clamped.
+        let per_point =
+            tire_aggregate_impulses(&patch(Vec3::default()), &[], 0.0, Vec3::default(), delta);
+        assert!((impulse.total_force - per_point.total_force).length() < 1.0e-3);
+        assert_eq!(
+            contact_aggregate_as_impulse(&aggregate, f32::NAN).total_force,
+            Vec3::default()
+        );
+    }
+
+    #[test]
+    fn compact_form_round_trips_forces_pressure_and_grip() {
+        let agg = aggregate_contacts(&patch(Vec3::default()), Vec3::default());
+        let back = contact_aggregate_decompress(contact_aggregate_compress(agg));
+        assert_eq!(back.total_force, agg.total_force);
+        assert_eq!(back.torque, agg.torque);
+        assert_eq!(back.grip, agg.grip);
+        assert_eq!(back.max_pressure, agg.max_pressure);
+        let peak_pressure_area = agg.total_force.length() / agg.max_pressure;
+        assert!((back.contact_area - peak_pressure_area).abs() < 1.0e-6);
+        assert!(back.contact_area <= agg.contact_area * (1.0 + 1.0e-5));
+        assert_eq!(back.count, 1);
+        assert_eq!(back.average_position, Vec3::default());
+
+        let empty =
+            contact_aggregate_decompress(contact_aggregate_compress(ContactAggregate::default()));
+        assert_eq!(empty, ContactAggregate::default());
+    }
+
+    #[test]
+    fn force_split_sums_to_total_for_tilted_normals() {
+        let mut points = patch(Vec3::default());
+        // Rotate the patch so its normals point along +Z: the vertical load
+        // becomes the z component, a side force goes along x.
+        for p in points.iter_mut() {
+            p.normal = Vec3 {
+                x: 0.0,
+                y: 0.2,
+                z: 1.0,
+            };
+            p.force = Vec3 {
+                x: 300.0,
+                y: 0.0,
+                z: 1500.0,
+            };
+        }
+        let agg = aggregate_contacts(&points, Vec3::default());
+        assert_eq!(agg.normal_force + agg.tangential_force, agg.total_force);
+        let n = Vec3 {
+            x: 0.0,
+            y: 0.2,
+            z: 1.0,
+        }
+        .normalized();
+        // Normal part lies along the normal, the rest is perpendicular to it.
+        assert!((agg.normal_force - n * agg.normal_force.dot(n)).length() < 1.0e-2);
+        assert!(agg.tangential_force.dot(n).abs() < 1.0e-2);
+        assert!(agg.tangential_force.x > 0.0);
+
+        let flat = aggregate_contacts(&patch(Vec3::default()), Vec3::default());
+        assert_eq!(flat.normal_force + flat.tangential_force, flat.total_force);
+    }
+
+    #[test]
+    fn dedup_drops_points_repeated_by_overlapping_shapes() {
+        let at = |x: f32, z: f32| Vec3 { x, y: 0.0, z };
+        let points = [
+            at(0.0, 0.0),
+            at(0.1, 0.0),
+            at(0.0005, 0.0),
+            at(0.1, 0.0),
+            at(0.2, 0.05),
+        ];
+        assert_eq!(dedup_contact_points(&points, 1.0e-3), vec![0, 1, 4]);
+        assert_eq!(dedup_contact_points(&points, f32::NAN), vec![0, 1, 2, 4]);
+        assert!(dedup_contact_points(&[], 1.0e-3).is_empty());
+
+        let mut out = [usize::MAX; 5];
+        assert_eq!(dedup_contact_indices_into(&points, 1.0e-3, &mut out), 3);
+        assert_eq!(out[..3], [0, 1, 4]);
+        let mut short = [0; 2];
+        assert_eq!(dedup_contact_indices_into(&points, 1.0e-3, &mut short), 2);
+        assert_eq!(short, [0, 1]);
+    }
+
+    #[test]
+    fn contact_stress_reads_in_megapascals() {
+        let up = Vec3 {
+            x: 0.0,
+            y: 0.0,
+            z: 1.0,
+        };
+        let point = |fz: f32, fx: f32, area: f32| ContactPoint {
+            normal: up,
+            force: Vec3 {
+                x: fx,
+                y: 0.0,
+                z: fz,
+            },
+            area,
+            grip: 1.0,
+            ..ContactPoint::default()
+        };
+        // 0.4 MPa and 0.2 MPa under the two tread blocks; the sideways force
+        // loads neither, and the zero-area sidewall point is skipped.
+        let points = [
+            point(2000.0, 500.0, 0.005),
+            point(1000.0, 0.0, 0.005),
+            point(3000.0, 0.0, 0.0),
+        ];
+        let agg = aggregate_contacts(&points, Vec3::default());
+        assert!((agg.max_pressure - 4.0e5).abs() < 1.0);
+        assert!((contact_stress_mpa(&agg) - 0.4).abs() < 1.0e-6);
+        let mean = agg.total_force.length() / 0.01 / 1.0e6;
+        assert!((average_contact_stress_mpa(&agg) - mean).abs() < 1.0e-6);
+
+        let empty = ContactAggregate::default();
+        assert_eq!(contact_stress_mpa(&empty), 0.0);
+        assert_eq!(average_contact_stress_mpa(&empty), 0.0);
+        let no_area = ContactAggregate {
+            total_force: up,
+            contact_area: f32::NAN,
+            ..empty
+        };
+        assert_eq!(average_contact_stress_mpa(&no_area), 0.0);
+    }
+
+    #[test]
+    fn shared_patch_matches_per_wheel_aggregation() {
+        let point = |x: f32, fz: f32| ContactPoint {
+            position: Vec3 { x, y: 0.0, z: 0.0 },
+            normal: Vec3 {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0,
+            },
+            force: Vec3 {
+                x: 0.1 * fz,
+                y: 0.0,
+                z: fz,
+            },
+            area: 0.004,
+            grip: 0.8 + x,
+        };
+        let points = [
+            point(0.0, 900.0),
+            point(0.6, 1200.0),
+            point(0.1, 800.0),
+            point(1.2, 1500.0),
+            point(0.7, 1100.0),
+            point(2.0, 5000.0),
+        ];
+        let wheel_indices = [0, 1, 0, 2, 1, 7];
+        let origins = [
+            Vec3 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.3,
+            },
+            Vec3 {
+                x: 0.6,
+                y: 0.0,
+                z: 0.3,
+            },
+            Vec3 {
+                x: 1.2,
+                y: 0.0,
+                z: 0.3,
+            },
+        ];
+        let track_origin = Vec3 {
+            x: 0.6,
+            y: 0.0,
+            z: 0.5,
+        };
+        let mut out = [ContactAggregate::default(); 4];
+        let result =
+            tire_aggregate_shared_patch(&points, &wheel_indices, &origins, track_origin, &mut out);
+        assert_eq!(result.skipped, 1);
+
+        for (w, origin) in origins.iter().enumerate() {
+            let own: Vec<ContactPoint> = points
+                .iter()
+                .zip(wheel_indices)
+                .filter(|(_, i)| *i == w as u32)
+                .map(|(p, _)| *p)
+                .collect();
+            assert_eq!(out[w], aggregate_contacts(&own, *origin));
+        }
+        // No origin for the fourth slot: it stays empty.
+        assert_eq!(out[3], ContactAggregate::default());
+        assert_eq!(result.track, aggregate_contacts(&points[..5], track_origin));
+
+        // Short index array: the unindexed tail is skipped too.
+        let result = tire_aggregate_shared_patch(
+            &points,
+            &wheel_indices[..2],
+            &origins,
+            track_origin,
+            &mut out,
+        );
+        assert_eq!(result.skipped, 4);
+        assert_eq!(result.track.count, 2);
+    }
+
+    #[test]
+    fn slide_velocity_signs_with_yaw_and_camber() {
+        use crate::axle::longitudinal_slip_velocity;
+        let v = |x: f32, y: f32, z: f32| Vec3 { x, y, z };
+        let close = |a: Vec3, b: Vec3| (a - b).length() < 1.0e-4;
+        // Godot axes: `x` right, `y` up, forward is `-z`.
+        let (up, heading) = (v(0.0, 1.0, 0.0), v(0.0, 0.0, -1.0));
+        let spin_axis = up.cross(heading);
+        assert_eq!(spin_axis, v(-1.0, 0.0, 0.0));
+        let (radius, speed) = (0.3, 20.0);
+        let com = v(0.0, 0.5, 0.0);
+        let hub = v(0.8, 0.3, -1.2);
+        let contact = v(0.8, 0.0, -1.2);
+        let body_velocity = heading * speed;
+
+        // Free rolling in a straight line: the tread is stationary on the road.
+        let rolling = speed / radius;
+        let slide = relative_slide_velocity(
+            body_velocity,
+            Vec3::default(),
+            com,
+            rolling,
+            spin_axis,
+            hub,
+            contact,
+        );
+        assert!(close(slide, Vec3::default()), "{slide:?}");
+        assert!(close(
+            wheel_surface_velocity(rolling, spin_axis, contact, hub),
+            v(0.0, 0.0, speed)
+        ));
+
+        // Wheelspin: the tread runs backwards over the road.
+        let spinning = 1.2 * rolling;
+        let slide = relative_slide_velocity(
+            body_velocity,
+            Vec3::default(),
+            com,
+            spinning,
+            spin_axis,
+            hub,
+            contact,
+        );
+        let along = slide.dot(heading);
+        assert!(along < 0.0);
+        assert!((along + longitudinal_slip_velocity(spinning, radius, speed)).abs() < 1.0e-4);
+
+        // Left turn (positive yaw about `up`): the right-hand wheel at x = 0.8
+        // runs 0.8 * yaw faster, and the 1.2 m lead swings the front contact
+        // towards -x, into the turn.
+        let yaw = 0.5;
+        let point = contact_point_velocity(body_velocity, up * yaw, contact, com);
+        assert!(
+            close(point, v(-1.2 * yaw, 0.0, -speed - 0.8 * yaw)),
+            "{point:?}"
+        );
+
+        // Camber: tilting the spin axis by 0.1 rad about the heading moves the
+        // contact point off the hub's vertical but keeps free rolling slip-free.
+        let camber: f32 = 0.1;
+        let tilted_axis = v(-camber.cos(), -camber.sin(), 0.0);
+        let tilted_down = v(camber.sin(), -camber.cos(), 0.0);
+        let contact = hub + tilted_down * radius;
+        let slide = relative_slide_velocity(
+            body_velocity,
+            Vec3::default(),
+            com,
+            rolling,
+            tilted_axis,
+            hub,
+            contact,
+        );
+        assert!(close(slide, Vec3::default()), "{slide:?}");
+    }
+
+    #[test]
+    fn quadrant_forces_split_the_patch_about_heading() {
+        let v = |x: f32, y: f32, z: f32| Vec3 { x, y, z };
+        // Godot axes: forward `-z`, up `y`, so right is `+x`.
+        let (heading, up) = (v(0.0, 0.0, -1.0), v(0.0, 1.0, 0.0));
+        let centre = v(2.0, 0.0, 5.0);
+        let points = [
+            centre + v(-0.05, 0.0, -0.04),
+            centre + v(0.05, 0.0, -0.04),
+            centre + v(-0.05, 0.0, 0.04),
+            centre + v(0.05, 0.0, 0.04),
+            // On the lateral axis, and then dead centre.
+            centre + v(0.05, 0.0, 0.0),
+            centre,
+        ];
+        let forces = [100.0, 200.0, 300.0, 400.0, 60.0, 40.0];
+        let q = quadrant_forces(&points, &forces, centre, heading, up);
+        assert_eq!(q, [110.0, 240.0, 310.0, 440.0]);
+        assert_eq!(q.iter().sum::<f32>(), forces.iter().sum::<f32>());
+
+        // A heading tilted out of the patch plane gives the same split, and
+        // the split follows the heading round.
+        let tilted = quadrant_forces(&points, &forces, centre, v(0.0, 0.3, -1.0), up);
+        assert_eq!(tilted, q);
+        let reversed = quadrant_forces(&points, &forces, centre, v(0.0, 0.0, 1.0), up);
+        assert_eq!(reversed, [440.0, 310.0, 240.0, 110.0]);
+
+        let short = quadrant_forces(&points, &[f32::NAN, 5.0], centre, heading, up);
+        assert_eq!(short, [0.0, 5.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn aggregate_normal_variance_matches_the_standalone_metric() {
+        use crate::patch::{contact_normal_variance, NORMAL_VARIANCE_UNRELIABLE};
+
+        let flat = aggregate_contacts(&patch(Vec3::default()), Vec3::default());
+        assert!(flat.normal_variance < 1.0e-6);
+
+        let mut stepped = patch(Vec3::default());
+        stepped[1].normal = Vec3 {
+            x: 1.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        stepped[3].normal = Vec3 {
+            x: -0.6,
+            y: 0.8,
+            z: 0.0,
+        };
+        let normals: Vec<Vec3> = stepped.iter().map(|p| p.normal).collect();
+        let agg = aggregate_contacts(&stepped, Vec3::default());
+        assert!((agg.normal_variance - contact_normal_variance(&normals)).abs() < 1.0e-5);
+        assert!(agg.normal_variance > NORMAL_VARIANCE_UNRELIABLE);
+        assert_eq!(
+            aggregate_contacts(&[], Vec3::default()).normal_variance,
+            0.0
+        );
+    }
+}
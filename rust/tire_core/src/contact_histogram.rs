@@ -0,0 +1,95 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::tire_state::TireCompound;
+
+/// Number of load bins in a `ContactHistogram`.
+pub const CONTACT_HISTOGRAM_BINS: usize = 16;
+/// Load exponent of the histogram wear estimate: the fourth-power law used for
+/// pavement fatigue, so one full-scale contact counts as many light ones.
+pub const CONTACT_HISTOGRAM_WEAR_EXPONENT: f32 = 4.0;
+/// Wear (fraction of tread) from one contact at `bin_max_n` on the medium
+/// compound.
+pub const CONTACT_HISTOGRAM_WEAR_PER_FULL_LOAD: f32 = 1.0e-7;
+
+/// Count of contact events by peak load, evenly binned over `[0, bin_max_n]`;
+/// heavier contacts land in the top bin.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ContactHistogram {
+    pub bins: [u32; CONTACT_HISTOGRAM_BINS],
+    pub bin_max_n: f32,
+}
+
+impl Default for ContactHistogram {
+    fn default() -> Self {
+        Self {
+            bins: [0; CONTACT_HISTOGRAM_BINS],
+            bin_max_n: 10000.0,
+        }
+    }
+}
+
+impl ContactHistogram {
+    /// Counts one contact event peaking at `max_pressure` (same unit as
+    /// `bin_max_n`). Negative or non-finite readings are ignored, and a full
+    /// bin saturates instead of wrapping.
+    pub fn record(&mut self, max_pressure: f32) {
+        if !max_pressure.is_finite() || max_pressure < 0.0 || self.bin_max_n <= 0.0 {
+            return;
+        }
+        let scaled = max_pressure / self.bin_max_n * CONTACT_HISTOGRAM_BINS as f32;
+        let bin = (scaled as usize).min(CONTACT_HISTOGRAM_BINS - 1);
+        self.bins[bin] = self.bins[bin].saturating_add(1);
+    }
+
+    /// Miner's-rule wear from the distribution: each bin contributes its count
+    /// times `(centre / bin_max_n)^CONTACT_HISTOGRAM_WEAR_EXPONENT`, scaled by
+    /// `CONTACT_HISTOGRAM_WEAR_PER_FULL_LOAD` and the compound's
+    /// `wear_rate_scale`.
+    pub fn weighted_wear_estimate(&self, compound: TireCompound) -> f32 {
+        let damage: f32 = self
+            .bins
+            .iter()
+            .enumerate()
+            .map(|(i, &count)| {
+                let centre = (i as f32 + 0.5) / CONTACT_HISTOGRAM_BINS as f32;
+                count as f32 * centre.powf(CONTACT_HISTOGRAM_WEAR_EXPONENT)
+            })
+            .sum();
+        damage * CONTACT_HISTOGRAM_WEAR_PER_FULL_LOAD * compound.wear_rate_scale.max(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contact_histogram_weights_peak_loads() {
+        let mut sustained = ContactHistogram::default();
+        let mut peaky = ContactHistogram::default();
+        // Same total load: 1000 contacts at 3 kN against 300 at 10 kN.
+        for _ in 0..1000 {
+            sustained.record(3000.0);
+        }
+        for _ in 0..300 {
+            peaky.record(10000.0);
+        }
+        peaky.record(f32::NAN);
+        peaky.record(-5.0);
+        assert_eq!(sustained.bins[4], 1000);
+        assert_eq!(peaky.bins[CONTACT_HISTOGRAM_BINS - 1], 300);
+        assert_eq!(peaky.bins.iter().sum::<u32>(), 300);
+        let medium = TireCompound::MEDIUM;
+        assert!(peaky.weighted_wear_estimate(medium) > sustained.weighted_wear_estimate(medium));
+        assert!(
+            peaky.weighted_wear_estimate(TireCompound::SOFT)
+                > peaky.weighted_wear_estimate(TireCompound::HARD)
+        );
+        assert_eq!(
+            ContactHistogram::default().weighted_wear_estimate(medium),
+            0.0
+        );
+    }
+}
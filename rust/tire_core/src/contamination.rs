@@ -0,0 +1,118 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Water depth (mm) at which the tread film saturates.
+pub const WATER_FILM_SATURATION_DEPTH_MM: f32 = 1.0;
+/// Rate (1/s) at which a film builds towards the surface level.
+pub const FILM_PICKUP_RATE_PER_S: f32 = 2.0;
+/// Rolling distance (m) that scrubs a film down by a factor of e.
+pub const WATER_SCRUB_DISTANCE_M: f32 = 150.0;
+pub const DUST_SCRUB_DISTANCE_M: f32 = 300.0;
+/// Sliding energy (J) that scrubs a film down by a factor of e.
+pub const FILM_SCRUB_ENERGY_J: f32 = 40000.0;
+/// Film left over the surface level below this is cleared outright, so the
+/// penalty always decays to exactly zero.
+pub const FILM_RESIDUE_CUTOFF: f32 = 1.0e-3;
+/// Grip lost with a fully saturated film.
+pub const WATER_FILM_GRIP_PENALTY: f32 = 0.3;
+pub const DUST_FILM_GRIP_PENALTY: f32 = 0.2;
+
+/// Temporary films on the tread, each in `[0, 1]` (1 = saturated).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ContaminationState {
+    pub water_film_on_tread: f32,
+    pub dust_film: f32,
+}
+
+fn film_step(film: f32, target: f32, scrub: f32, delta: f32) -> f32 {
+    let film = film.clamp(0.0, 1.0);
+    if target > film {
+        return target + (film - target) * (-FILM_PICKUP_RATE_PER_S * delta).exp();
+    }
+    let next = target + (film - target) * (-scrub).exp();
+    if next - target < FILM_RESIDUE_CUTOFF {
+        target
+    } else {
+        next
+    }
+}
+
+/// Films build towards the surface level (`surface_water_depth_mm` against
+/// `WATER_FILM_SATURATION_DEPTH_MM`, `surface_dust` in `[0, 1]`) and are
+/// scrubbed back down by this step's `wheel_travel_distance_m` and
+/// `sliding_energy_j`. A clean surface always ends at zero film.
+pub fn tire_step_contamination(
+    state: ContaminationState,
+    surface_water_depth_mm: f32,
+    surface_dust: f32,
+    sliding_energy_j: f32,
+    wheel_travel_distance_m: f32,
+    delta: f32,
+) -> ContaminationState {
+    if !delta.is_finite() || delta <= 0.0 {
+        return state;
+    }
+    let finite = |v: f32| if v.is_finite() { v.max(0.0) } else { 0.0 };
+    let distance = finite(wheel_travel_distance_m);
+    let energy_scrub = finite(sliding_energy_j) / FILM_SCRUB_ENERGY_J;
+    let water_target =
+        (finite(surface_water_depth_mm) / WATER_FILM_SATURATION_DEPTH_MM).clamp(0.0, 1.0);
+    let dust_target = finite(surface_dust).clamp(0.0, 1.0);
+
+    ContaminationState {
+        water_film_on_tread: film_step(
+            state.water_film_on_tread,
+            water_target,
+            distance / WATER_SCRUB_DISTANCE_M + energy_scrub,
+            delta,
+        ),
+        dust_film: film_step(
+            state.dust_film,
+            dust_target,
+            distance / DUST_SCRUB_DISTANCE_M + energy_scrub,
+            delta,
+        ),
+    }
+}
+
+/// Grip multiplier from the current films; exactly 1 on a clean tread.
+pub fn tire_contamination_grip(state: ContaminationState) -> f32 {
+    let water = state.water_film_on_tread.clamp(0.0, 1.0);
+    let dust = state.dust_film.clamp(0.0, 1.0);
+    1.0 - WATER_FILM_GRIP_PENALTY * water - DUST_FILM_GRIP_PENALTY * dust
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn films_build_on_a_wet_dusty_surface() {
+        let mut state = ContaminationState::default();
+        for _ in 0..120 {
+            state = tire_step_contamination(state, 2.0, 0.5, 0.0, 0.5, 1.0 / 60.0);
+        }
+        assert!(state.water_film_on_tread > 0.95);
+        assert!((state.dust_film - 0.5).abs() < 0.05);
+        let grip = tire_contamination_grip(state);
+        assert!(grip < 1.0 - WATER_FILM_GRIP_PENALTY);
+    }
+
+    #[test]
+    fn penalty_decays_fully_on_a_clean_surface() {
+        let mut state = ContaminationState {
+            water_film_on_tread: 1.0,
+            dust_film: 1.0,
+        };
+        let rolling_only = tire_step_contamination(state, 0.0, 0.0, 0.0, 50.0, 1.0);
+        let with_sliding = tire_step_contamination(state, 0.0, 0.0, 20000.0, 50.0, 1.0);
+        assert!(with_sliding.water_film_on_tread < rolling_only.water_film_on_tread);
+
+        for _ in 0..60 {
+            state = tire_step_contamination(state, 0.0, 0.0, 500.0, 50.0, 1.0);
+        }
+        assert_eq!(state, ContaminationState::default());
+        assert_eq!(tire_contamination_grip(state), 1.0);
+    }
+}
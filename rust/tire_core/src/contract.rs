@@ -1,11 +1,18 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "debug_verbose")]
+use crate::normalize_weights_with_conventions;
+use crate::patch::contact_normal_variance;
 use crate::{
     aggregate_patch_with_conventions, compute_effective_radius_with_conventions, PatchSample,
-    TireCoreConventions,
+    TireCoreConventions, Vec3,
 };
 
+pub(crate) const CORE_STIFFNESS: f32 = 120000.0;
+const LONGITUDINAL_GAIN: f32 = 0.5;
+const LATERAL_GAIN: f32 = 0.7;
+
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WheelState {
@@ -43,6 +50,7 @@ pub struct DebugScalars {
     pub effective_radius: f32,
     pub contact_area_est: f32,
     pub slip_avg: [f32; 2],
+    pub normal_variance: f32,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
@@ -77,8 +85,16 @@ pub fn solve_core(input: &CoreInput) -> CoreOutput {
 
     let mut weighted_pos = [0.0_f32; 3];
     let mut weight_sum = 0.0_f32;
+    let mut normals = Vec::with_capacity(input.samples.len());
     for s in &input.samples {
         let w = s.penetration.max(0.0) * s.confidence.clamp(0.0, 1.0);
+        if w > 0.0 {
+            normals.push(Vec3 {
+                x: s.normal_local[0],
+                y: s.normal_local[1],
+                z: s.normal_local[2],
+            });
+        }
         weight_sum += w;
         weighted_pos[0] += s.position_local[0] * w;
         weighted_pos[1] += s.position_local[1] * w;
@@ -95,9 +111,9 @@ pub fn solve_core(input: &CoreInput) -> CoreOutput {
         [0.0, 0.0, 0.0]
     };
 
-    let fz = (patch.penetration_avg * 120000.0).max(0.0);
-    let fx = -patch.slip_x_avg * fz * 0.5;
-    let fy = -patch.slip_y_avg * fz * 0.7;
+    let fz = (patch.penetration_avg * CORE_STIFFNESS).max(0.0);
+    let fx = -patch.slip_x_avg * fz * LONGITUDINAL_GAIN;
+    let fy = -patch.slip_y_avg * fz * LATERAL_GAIN;
 
     CoreOutput {
         fx,
@@ -111,11 +127,77 @@ pub fn solve_core(input: &CoreInput) -> CoreOutput {
                 input.wheel.tire_radius,
                 input.wheel.tire_radius * 0.7,
                 fz,
-                120000.0,
+                CORE_STIFFNESS,
                 input.conventions,
             ),
             contact_area_est: weight_sum,
             slip_avg: [patch.slip_x_avg, patch.slip_y_avg],
+            normal_variance: contact_normal_variance(&normals),
         },
     }
 }
+
+/// `solve_core` that also writes each sample's `[fx, fy, fz]` contribution into
+/// `out_per_sample_forces` (extra slots are zeroed). Contributions sum to the
+/// returned forces.
+#[cfg(feature = "debug_verbose")]
+pub fn solve_core_verbose(input: &CoreInput, out_per_sample_forces: &mut [[f32; 3]]) -> CoreOutput {
+    let out = solve_core(input);
+    out_per_sample_forces.fill([0.0; 3]);
+
+    let raw_weights: Vec<f32> = input
+        .samples
+        .iter()
+        .map(|s| s.penetration.max(0.0) * s.confidence.clamp(0.0, 1.0))
+        .collect();
+    let weights = normalize_weights_with_conventions(&raw_weights, input.conventions);
+    for (slot, (s, w)) in out_per_sample_forces
+        .iter_mut()
+        .zip(input.samples.iter().zip(weights))
+    {
+        *slot = [
+            -s.slip_x * w * out.fz * LONGITUDINAL_GAIN,
+            -s.slip_y * w * out.fz * LATERAL_GAIN,
+            s.penetration * w * CORE_STIFFNESS,
+        ];
+    }
+    out
+}
+
+#[cfg(all(test, feature = "debug_verbose"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verbose_contributions_sum_to_output() {
+        let input = CoreInput {
+            samples: vec![
+                ContactSampleRaw {
+                    penetration: 0.018,
+                    confidence: 0.95,
+                    slip_x: 0.06,
+                    slip_y: 0.02,
+                    ..ContactSampleRaw::default()
+                },
+                ContactSampleRaw {
+                    penetration: 0.020,
+                    confidence: 0.90,
+                    slip_x: 0.05,
+                    slip_y: 0.03,
+                    ..ContactSampleRaw::default()
+                },
+                ContactSampleRaw::default(),
+            ],
+            ..CoreInput::default()
+        };
+        let mut per_sample = [[1.0; 3]; 4];
+        let out = solve_core_verbose(&input, &mut per_sample);
+        assert_eq!(out, solve_core(&input));
+        assert_eq!(per_sample[2], [0.0; 3]);
+        assert_eq!(per_sample[3], [0.0; 3]);
+        for (axis, total) in [out.fx, out.fy, out.fz].into_iter().enumerate() {
+            let sum: f32 = per_sample.iter().map(|f| f[axis]).sum();
+            assert!((sum - total).abs() < 1.0e-2);
+        }
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::TireCoreConventions;
+use crate::{Mat3, TireCoreConventions, Vec3};
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -37,3 +37,202 @@ impl SimCalibration {
         Ok(parse_calibration_json(&payload)?)
     }
 }
+
+/// Vehicle axis conventions (see the table in `docs/core_bridge_contract.md`).
+/// - `Godot`: X right, Y up, Z backward.
+/// - `Sae`: X forward, Y right, Z down.
+/// - `Iso`: X forward, Y left, Z up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AxisConvention {
+    Godot,
+    Sae,
+    Iso,
+}
+
+/// `AXIS_CONVERSIONS[from][to]`: signed permutation taking `from` components to `to`.
+const AXIS_CONVERSIONS: [[Mat3; 3]; 3] = [
+    [
+        // Godot -> Godot
+        Mat3::from_rows([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]),
+        // Godot -> Sae
+        Mat3::from_rows([[0.0, 0.0, -1.0], [1.0, 0.0, 0.0], [0.0, -1.0, 0.0]]),
+        // Godot -> Iso
+        Mat3::from_rows([[0.0, 0.0, -1.0], [-1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]),
+    ],
+    [
+        // Sae -> Godot
+        Mat3::from_rows([[0.0, 1.0, 0.0], [0.0, 0.0, -1.0], [-1.0, 0.0, 0.0]]),
+        // Sae -> Sae
+        Mat3::from_rows([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]),
+        // Sae -> Iso
+        Mat3::from_rows([[1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, -1.0]]),
+    ],
+    [
+        // Iso -> Godot
+        Mat3::from_rows([[0.0, -1.0, 0.0], [0.0, 0.0, 1.0], [-1.0, 0.0, 0.0]]),
+        // Iso -> Sae
+        Mat3::from_rows([[1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, -1.0]]),
+        // Iso -> Iso
+        Mat3::from_rows([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]),
+    ],
+];
+
+fn axis_index(convention: AxisConvention) -> usize {
+    match convention {
+        AxisConvention::Godot => 0,
+        AxisConvention::Sae => 1,
+        AxisConvention::Iso => 2,
+    }
+}
+
+/// Re-expresses `v` from one axis convention in another. All three conventions
+/// are right-handed, so the same matrix converts positions, forces and torques.
+pub fn convert_vec3(v: Vec3, from: AxisConvention, to: AxisConvention) -> Vec3 {
+    AXIS_CONVERSIONS[axis_index(from)][axis_index(to)] * v
+}
+
+/// Sign convention for slip angle and lateral force at the host boundary. The
+/// core works in ISO 8855 internally (y left, z up): a wheel whose contact
+/// moves to the left has positive slip angle and a negative (rightward)
+/// lateral force. SAE J670 (y right, z down) mirrors both signs for the same
+/// physical situation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SlipConvention {
+    #[default]
+    Iso8855,
+    SaeJ670,
+}
+
+impl SlipConvention {
+    /// Sign that maps a lateral quantity between this convention and ISO 8855
+    /// (the mapping is its own inverse).
+    pub fn lateral_sign(self) -> f32 {
+        match self {
+            SlipConvention::Iso8855 => 1.0,
+            SlipConvention::SaeJ670 => -1.0,
+        }
+    }
+}
+
+/// Slip angle from the wheel-plane velocity components, both given in the
+/// host's `convention` and returned in it: `atan2(v_lat, |v_long|)`.
+pub fn slip_angle(
+    velocity_longitudinal: f32,
+    velocity_lateral: f32,
+    convention: SlipConvention,
+) -> f32 {
+    let sign = convention.lateral_sign();
+    let lateral_iso = velocity_lateral * sign;
+    if !lateral_iso.is_finite() || !velocity_longitudinal.is_finite() {
+        return 0.0;
+    }
+    if velocity_longitudinal.abs() <= 1.0e-6 && lateral_iso.abs() <= 1.0e-6 {
+        return 0.0;
+    }
+    lateral_iso.atan2(velocity_longitudinal.abs()) * sign
+}
+
+/// Lateral force for a slip angle in the host's `convention`: linear
+/// `-cornering_stiffness * alpha` (ISO) saturated at `mu * normal_load`,
+/// returned in the same convention.
+pub fn lateral_force(
+    slip_angle: f32,
+    cornering_stiffness: f32,
+    normal_load: f32,
+    mu: f32,
+    convention: SlipConvention,
+) -> f32 {
+    let sign = convention.lateral_sign();
+    let alpha_iso = slip_angle * sign;
+    let cap = mu.max(0.0) * normal_load.max(0.0);
+    let fy_iso = (-cornering_stiffness.max(0.0) * alpha_iso).clamp(-cap, cap);
+    if fy_iso.is_finite() {
+        fy_iso * sign
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [AxisConvention; 3] = [
+        AxisConvention::Godot,
+        AxisConvention::Sae,
+        AxisConvention::Iso,
+    ];
+
+    #[test]
+    fn forward_left_up_map_consistently() {
+        let forward_godot = Vec3 {
+            x: 0.0,
+            y: 0.0,
+            z: -1.0,
+        };
+        let left_godot = Vec3 {
+            x: -1.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        let iso_forward = convert_vec3(forward_godot, AxisConvention::Godot, AxisConvention::Iso);
+        assert_eq!(
+            iso_forward,
+            Vec3 {
+                x: 1.0,
+                y: 0.0,
+                z: 0.0
+            }
+        );
+        let sae_left = convert_vec3(left_godot, AxisConvention::Godot, AxisConvention::Sae);
+        assert_eq!(
+            sae_left,
+            Vec3 {
+                x: 0.0,
+                y: -1.0,
+                z: 0.0
+            }
+        );
+    }
+
+    #[test]
+    fn conversions_round_trip_and_compose() {
+        let v = Vec3 {
+            x: 1.5,
+            y: -2.0,
+            z: 0.25,
+        };
+        for a in ALL {
+            for b in ALL {
+                assert_eq!(convert_vec3(convert_vec3(v, a, b), b, a), v);
+                for c in ALL {
+                    let via = convert_vec3(convert_vec3(v, a, b), b, c);
+                    assert_eq!(via, convert_vec3(v, a, c));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn slip_conventions_agree_on_physical_outcome() {
+        // Contact patch drifting to the physical right at 2 m/s while rolling at 20 m/s.
+        let rightward_iso = -2.0;
+        let rightward_sae = 2.0;
+        for (convention, v_lat) in [
+            (SlipConvention::Iso8855, rightward_iso),
+            (SlipConvention::SaeJ670, rightward_sae),
+        ] {
+            let alpha = slip_angle(20.0, v_lat, convention);
+            let fy = lateral_force(alpha, 60000.0, 4000.0, 1.0, convention);
+            // Back in ISO the force must push left (positive), opposing the drift.
+            let fy_left = fy * convention.lateral_sign();
+            assert!(fy_left > 0.0, "{convention:?}: {fy}");
+            assert!((alpha.abs() - (2.0_f32).atan2(20.0)).abs() < 1.0e-6);
+        }
+        let iso = lateral_force(0.05, 60000.0, 4000.0, 1.0, SlipConvention::Iso8855);
+        let sae = lateral_force(-0.05, 60000.0, 4000.0, 1.0, SlipConvention::SaeJ670);
+        assert_eq!(iso, -sae);
+    }
+}
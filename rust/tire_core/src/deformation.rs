@@ -0,0 +1,164 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::tire_state::TireDefinition;
+
+/// Offset of one tread ring point from the undeformed tyre: `radial_m` along
+/// the radius (negative = pushed in), `lateral_m` along the axle.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RingOffset {
+    pub radial_m: f32,
+    pub lateral_m: f32,
+}
+
+/// Carcass stiffness scales with inflation, floored so a flat tyre stays bounded.
+fn pressure_scale(inflation_pressure_kpa: f32, definition: &TireDefinition) -> f32 {
+    if !inflation_pressure_kpa.is_finite() || definition.nominal_pressure_kpa <= 1.0e-3 {
+        return 1.0;
+    }
+    (inflation_pressure_kpa / definition.nominal_pressure_kpa).clamp(0.2, 2.0)
+}
+
+fn deflection(force: f32, stiffness: f32, limit: f32) -> f32 {
+    if !force.is_finite() || stiffness <= 1.0e-3 {
+        return 0.0;
+    }
+    (force / stiffness).clamp(-limit, limit)
+}
+
+/// Lateral sidewall deflection (m) under `lateral_force_n`, signed like the
+/// force. Zero for a non-positive stiffness.
+pub fn carcass_deflection_m(lateral_force_n: f32, sidewall_stiffness_n_per_m: f32) -> f32 {
+    deflection(lateral_force_n, sidewall_stiffness_n_per_m, f32::MAX)
+}
+
+/// Camber of the rim relative to the road once the sidewall has deflected:
+/// `static + atan(deflection / section_height)`. Positive `deflection_m` means
+/// the patch has moved towards the car's centreline under the rim (the outside
+/// wheel in a corner), which leans the wheel out and adds positive camber; that
+/// is the loss static negative camber is set to cancel.
+pub fn effective_camber_from_deflection(
+    static_camber_rad: f32,
+    deflection_m: f32,
+    section_height_m: f32,
+) -> f32 {
+    if !deflection_m.is_finite() || !section_height_m.is_finite() || section_height_m <= 1.0e-4 {
+        return static_camber_rad;
+    }
+    static_camber_rad + (deflection_m / section_height_m).atan()
+}
+
+/// Visual ring deformation for a shader or skinned mesh. Point `i` of
+/// `out_ring_offsets` sits at angle `2*pi*i/N` from the contact centre,
+/// increasing towards the direction of travel.
+///
+/// - The tread flattens against the ground along the chord at the vertical deflection.
+/// - The sidewall bulges sideways under lateral load, concentrated at the patch.
+/// - The patch leans fore-aft under longitudinal load.
+///
+/// Every offset is bounded by `sidewall_height_m` and varies continuously with
+/// the inputs. The function does no allocation and costs a few trig calls per point.
+pub fn tire_visual_deformation(
+    normal_load: f32,
+    lateral_force: f32,
+    longitudinal_force: f32,
+    inflation_pressure_kpa: f32,
+    definition: &TireDefinition,
+    out_ring_offsets: &mut [RingOffset],
+) {
+    out_ring_offsets.fill(RingOffset::default());
+    let n = out_ring_offsets.len();
+    let radius = definition.radius_m;
+    let sidewall = definition.sidewall_height_m.min(radius);
+    if n == 0 || !radius.is_finite() || radius <= 1.0e-3 || sidewall <= 0.0 {
+        return;
+    }
+
+    let scale = pressure_scale(inflation_pressure_kpa, definition);
+    let vertical = deflection(
+        normal_load.max(0.0),
+        definition.vertical_stiffness * scale,
+        sidewall,
+    );
+    let lateral = deflection(
+        lateral_force,
+        definition.lateral_stiffness * scale,
+        sidewall,
+    );
+    let lean = deflection(
+        longitudinal_force,
+        definition.longitudinal_stiffness * scale,
+        sidewall,
+    );
+
+    for (i, offset) in out_ring_offsets.iter_mut().enumerate() {
+        let theta = std::f32::consts::TAU * i as f32 / n as f32;
+        let (sin, cos) = theta.sin_cos();
+        // Weight peaking at the contact centre and fading to zero at the top.
+        let near_patch = 0.25 * (1.0 + cos) * (1.0 + cos);
+
+        let flatten = if cos > 1.0e-3 {
+            ((radius - vertical) / cos - radius).min(0.0)
+        } else {
+            0.0
+        };
+        let radial = flatten - lean * sin * near_patch;
+        offset.radial_m = radial.clamp(-sidewall, sidewall);
+        offset.lateral_m = (lateral * near_patch).clamp(-sidewall, sidewall);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offsets_are_bounded_and_zero_when_unloaded() {
+        let definition = TireDefinition::default();
+        let mut ring = [RingOffset::default(); 32];
+        tire_visual_deformation(0.0, 0.0, 0.0, 220.0, &definition, &mut ring);
+        assert!(ring.iter().all(|o| *o == RingOffset::default()));
+
+        tire_visual_deformation(1.0e9, -1.0e9, 1.0e9, 5.0, &definition, &mut ring);
+        for o in ring {
+            assert!(o.radial_m.abs() <= definition.sidewall_height_m);
+            assert!(o.lateral_m.abs() <= definition.sidewall_height_m);
+        }
+    }
+
+    #[test]
+    fn offsets_vary_smoothly_with_load() {
+        let definition = TireDefinition::default();
+        let mut a = [RingOffset::default(); 24];
+        let mut b = [RingOffset::default(); 24];
+        tire_visual_deformation(4000.0, 2000.0, 800.0, 220.0, &definition, &mut a);
+        tire_visual_deformation(4010.0, 2005.0, 802.0, 220.0, &definition, &mut b);
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert!((x.radial_m - y.radial_m).abs() < 1.0e-3);
+            assert!((x.lateral_m - y.lateral_m).abs() < 1.0e-3);
+        }
+        assert!(a[0].radial_m < 0.0);
+        assert!(a[0].lateral_m > 0.0);
+        assert_eq!(a[12].lateral_m, 0.0);
+    }
+
+    #[test]
+    fn sidewall_deflection_leans_the_wheel() {
+        let definition = TireDefinition::default();
+        let d = carcass_deflection_m(6000.0, definition.lateral_stiffness);
+        assert!((d - 0.04).abs() < 1.0e-6);
+        assert_eq!(carcass_deflection_m(6000.0, 0.0), 0.0);
+        assert_eq!(carcass_deflection_m(-6000.0, 150000.0), -d);
+
+        let static_camber = -0.05;
+        let loaded =
+            effective_camber_from_deflection(static_camber, d, definition.sidewall_height_m);
+        assert!((loaded - (static_camber + (0.04_f32 / 0.11).atan())).abs() < 1.0e-6);
+        assert!(loaded > static_camber);
+        assert_eq!(
+            effective_camber_from_deflection(static_camber, d, 0.0),
+            static_camber
+        );
+    }
+}
@@ -0,0 +1,147 @@
+//! Field-level comparison of saved tyre states and definitions for tuning and
+//! desync debugging.
+
+use crate::tire_state::{TireDefinition, TireState};
+
+/// One differing field: `field_id` resolves through `tire_field_name`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FieldDiff {
+    pub field_id: u32,
+    pub value_a: f32,
+    pub value_b: f32,
+}
+
+/// Field id ranges per struct; ids are `base + declaration index`.
+pub const TIRE_STATE_FIELD_BASE: u32 = 0;
+pub const TIRE_DEFINITION_FIELD_BASE: u32 = 100;
+
+fn collect_diffs(
+    base: u32,
+    table: &[(&str, f32)],
+    a: &[f32],
+    b: &[f32],
+    out: &mut [FieldDiff],
+) -> usize {
+    let mut count = 0;
+    for (i, ((&va, &vb), &(_, epsilon))) in a.iter().zip(b).zip(table).enumerate() {
+        let differs = if va.is_finite() && vb.is_finite() {
+            (va - vb).abs() > epsilon
+        } else {
+            va.to_bits() != vb.to_bits()
+        };
+        if !differs {
+            continue;
+        }
+        if let Some(slot) = out.get_mut(count) {
+            *slot = FieldDiff {
+                field_id: base + i as u32,
+                value_a: va,
+                value_b: vb,
+            };
+        }
+        count += 1;
+    }
+    count
+}
+
+/// Declares the field table and diff function for a struct of `f32` fields.
+/// The struct is destructured without `..`, so adding a field to it fails to
+/// compile until the field is listed here too.
+macro_rules! field_registry {
+    ($(#[$meta:meta])* $diff_fn:ident, $table:ident, $ty:ident, $base:expr, { $($field:ident: $epsilon:expr),* $(,)? }) => {
+        const $table: &[(&str, f32)] = &[$(
+            (concat!(stringify!($ty), ".", stringify!($field)), $epsilon)
+        ),*];
+
+        $(#[$meta])*
+        pub fn $diff_fn(a: &$ty, b: &$ty, out: &mut [FieldDiff]) -> usize {
+            let $ty { $($field: _),* } = *a;
+            collect_diffs($base, $table, &[$(a.$field),*], &[$(b.$field),*], out)
+        }
+    };
+}
+
+field_registry!(
+    /// Fields of `a` and `b` differing by more than their epsilon, written to
+    /// `out` in declaration order. Returns the total number of differences,
+    /// which may exceed `out.len()` (the excess is not written).
+    tire_state_diff,
+    TIRE_STATE_FIELDS,
+    TireState,
+    TIRE_STATE_FIELD_BASE,
+    {
+        surface_temp_c: 1.0e-3,
+        core_temp_c: 1.0e-3,
+        wear: 1.0e-6,
+    }
+);
+
+field_registry!(
+    /// `tire_state_diff` for tyre definitions.
+    tire_definition_diff,
+    TIRE_DEFINITION_FIELDS,
+    TireDefinition,
+    TIRE_DEFINITION_FIELD_BASE,
+    {
+        radius_m: 1.0e-5,
+        width_m: 1.0e-5,
+        sidewall_height_m: 1.0e-5,
+        vertical_stiffness: 1.0e-1,
+        lateral_stiffness: 1.0e-1,
+        longitudinal_stiffness: 1.0e-1,
+        nominal_pressure_kpa: 1.0e-3,
+    }
+);
+
+/// Struct-qualified name of a field id (`"TireState.wear"`), or `""` if unknown.
+pub fn tire_field_name(field_id: u32) -> &'static str {
+    let (table, base) = if field_id >= TIRE_DEFINITION_FIELD_BASE {
+        (TIRE_DEFINITION_FIELDS, TIRE_DEFINITION_FIELD_BASE)
+    } else {
+        (TIRE_STATE_FIELDS, TIRE_STATE_FIELD_BASE)
+    };
+    table
+        .get((field_id - base) as usize)
+        .map_or("", |&(name, _)| name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_reports_only_fields_beyond_epsilon() {
+        let a = TireState::default();
+        let b = TireState {
+            core_temp_c: a.core_temp_c + 1.0e-4,
+            wear: 0.25,
+            ..a
+        };
+        let mut out = [FieldDiff::default(); 4];
+        assert_eq!(tire_state_diff(&a, &b, &mut out), 1);
+        assert_eq!(tire_field_name(out[0].field_id), "TireState.wear");
+        assert_eq!((out[0].value_a, out[0].value_b), (0.0, 0.25));
+        assert_eq!(tire_state_diff(&a, &a, &mut out), 0);
+    }
+
+    #[test]
+    fn diff_counts_entries_past_the_output_buffer() {
+        let a = TireDefinition::default();
+        let b = TireDefinition {
+            radius_m: 0.35,
+            width_m: 0.245,
+            nominal_pressure_kpa: f32::NAN,
+            ..a
+        };
+        let mut out = [FieldDiff::default(); 2];
+        assert_eq!(tire_definition_diff(&a, &b, &mut out), 3);
+        assert_eq!(tire_field_name(out[0].field_id), "TireDefinition.radius_m");
+        assert_eq!(tire_field_name(out[1].field_id), "TireDefinition.width_m");
+        assert_eq!(
+            tire_field_name(TIRE_DEFINITION_FIELD_BASE + 6),
+            "TireDefinition.nominal_pressure_kpa"
+        );
+        assert_eq!(tire_field_name(TIRE_STATE_FIELD_BASE + 3), "");
+        assert_eq!(tire_field_name(u32::MAX), "");
+    }
+}
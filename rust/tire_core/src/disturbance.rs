@@ -0,0 +1,181 @@
+//! Seeded, repeatable external disturbances (crosswind gusts, road crown) for
+//! handling regression runs. Only integer hashing and IEEE add/multiply are
+//! used, so a profile gives bit-identical samples on every platform.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Shortest gust period honoured, so sampling late times stays cheap.
+pub const MIN_GUST_PERIOD_S: f32 = 0.05;
+/// Gusts are held at their value from here on (s).
+pub const MAX_GUST_TIME_S: f32 = 3600.0;
+
+const GUST_STREAM: u32 = 0x6775_7374;
+const GUST_PERIOD_STREAM: u32 = 0x7065_7264;
+const CROWN_STREAM: u32 = 0x6372_6f77;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DisturbanceProfile {
+    pub seed: u32,
+    /// Peak lateral wind force (N), positive to the left (ISO 8855).
+    pub gust_amplitude_n: f32,
+    /// Gust knot spacing is drawn uniformly from `[min, max]` seconds.
+    pub gust_period_range_s: [f32; 2],
+    /// Peak road crown (bank) angle (rad), positive raising the right side.
+    pub crown_amplitude_rad: f32,
+    /// Distance between crown extremes is half of this (m).
+    pub crown_wavelength_m: f32,
+}
+
+impl Default for DisturbanceProfile {
+    fn default() -> Self {
+        Self {
+            seed: 1,
+            gust_amplitude_n: 800.0,
+            gust_period_range_s: [1.0, 3.0],
+            crown_amplitude_rad: 0.02,
+            crown_wavelength_m: 80.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Disturbance {
+    pub lateral_wind_force_n: f32,
+    pub road_crown_angle_rad: f32,
+}
+
+/// Integer hash of `(seed, stream, index)` mapped exactly onto `[-1, 1)`.
+pub(crate) fn hash_signed(seed: u32, stream: u32, index: u32) -> f32 {
+    let mut h = seed ^ stream.wrapping_mul(0x9e37_79b9) ^ index.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x7feb_352d);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x846c_a68b);
+    h ^= h >> 16;
+    (h >> 8) as f32 / (1 << 23) as f32 - 1.0
+}
+
+/// `a` to `b` along a smoothstep in `t` in `[0, 1]`.
+fn smooth_blend(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t * t * (3.0 - 2.0 * t)
+}
+
+/// Gust force: random knot values `gust_amplitude_n * [-1, 1)` at random
+/// spacings, starting from calm at `t = 0`, smoothstepped between knots.
+/// Walks the knots from zero, so cost grows with `time_s / min period`; held
+/// past `MAX_GUST_TIME_S`.
+fn gust_force(time_s: f32, profile: &DisturbanceProfile) -> f32 {
+    let [lo, hi] = profile.gust_period_range_s;
+    let lo = if lo.is_finite() {
+        lo.max(MIN_GUST_PERIOD_S)
+    } else {
+        MIN_GUST_PERIOD_S
+    };
+    let hi = if hi.is_finite() { hi.max(lo) } else { lo };
+    let time_s = time_s.min(MAX_GUST_TIME_S);
+    let mut start = 0.0_f32;
+    let mut previous = 0.0_f32;
+    let mut knot = 0_u32;
+    loop {
+        let u = 0.5 * (hash_signed(profile.seed, GUST_PERIOD_STREAM, knot) + 1.0);
+        let period = lo + (hi - lo) * u;
+        let value = profile.gust_amplitude_n * hash_signed(profile.seed, GUST_STREAM, knot);
+        if time_s < start + period {
+            return smooth_blend(previous, value, (time_s - start) / period);
+        }
+        start += period;
+        previous = value;
+        knot = knot.wrapping_add(1);
+    }
+}
+
+/// Crown angle: value noise over distance with knots every half wavelength.
+fn crown_angle(distance_m: f32, profile: &DisturbanceProfile) -> f32 {
+    let spacing = 0.5 * profile.crown_wavelength_m;
+    if !spacing.is_finite() || spacing <= 1.0e-3 {
+        return 0.0;
+    }
+    let x = (distance_m / spacing).clamp(0.0, u32::MAX as f32 - 1.0);
+    let index = x as u32;
+    let a = hash_signed(profile.seed, CROWN_STREAM, index);
+    let b = hash_signed(profile.seed, CROWN_STREAM, index + 1);
+    profile.crown_amplitude_rad * smooth_blend(a, b, x - index as f32)
+}
+
+/// Disturbance at `time_s` for a car that has covered `distance_m` (the crown
+/// is a property of the road, so it follows distance rather than time). The
+/// same profile, time and distance always give the same value. Negative or
+/// non-finite inputs give no disturbance.
+pub fn disturbance_sample(
+    time_s: f32,
+    distance_m: f32,
+    profile: &DisturbanceProfile,
+) -> Disturbance {
+    let lateral_wind_force_n = if time_s.is_finite() && time_s >= 0.0 {
+        gust_force(time_s, profile)
+    } else {
+        0.0
+    };
+    let road_crown_angle_rad = if distance_m.is_finite() && distance_m >= 0.0 {
+        crown_angle(distance_m, profile)
+    } else {
+        0.0
+    };
+    Disturbance {
+        lateral_wind_force_n: if lateral_wind_force_n.is_finite() {
+            lateral_wind_force_n
+        } else {
+            0.0
+        },
+        road_crown_angle_rad: if road_crown_angle_rad.is_finite() {
+            road_crown_angle_rad
+        } else {
+            0.0
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn samples_are_repeatable_bounded_and_seed_dependent() {
+        let profile = DisturbanceProfile::default();
+        let other = DisturbanceProfile { seed: 2, ..profile };
+        let mut differs = false;
+        for i in 0..600 {
+            let t = i as f32 * 0.05;
+            let d = disturbance_sample(t, 25.0 * t, &profile);
+            assert_eq!(d, disturbance_sample(t, 25.0 * t, &profile));
+            assert!(d.lateral_wind_force_n.abs() <= profile.gust_amplitude_n);
+            assert!(d.road_crown_angle_rad.abs() <= profile.crown_amplitude_rad);
+            differs |= d != disturbance_sample(t, 25.0 * t, &other);
+        }
+        assert!(differs);
+        assert_eq!(
+            disturbance_sample(0.0, 0.0, &profile).lateral_wind_force_n,
+            0.0
+        );
+        assert_eq!(
+            disturbance_sample(f32::NAN, -1.0, &profile),
+            Disturbance::default()
+        );
+    }
+
+    #[test]
+    fn gusts_are_continuous() {
+        let profile = DisturbanceProfile::default();
+        let dt = 1.0e-3;
+        let max_slope = 1.5 * 2.0 * profile.gust_amplitude_n / profile.gust_period_range_s[0];
+        let mut previous = disturbance_sample(0.0, 0.0, &profile).lateral_wind_force_n;
+        for i in 1..20000 {
+            let f = disturbance_sample(i as f32 * dt, 0.0, &profile).lateral_wind_force_n;
+            assert!((f - previous).abs() <= max_slope * dt * 1.01);
+            previous = f;
+        }
+    }
+}
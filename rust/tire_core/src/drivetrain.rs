@@ -0,0 +1,487 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::timestep::{resolve_delta, STEP_STATUS_DELTA_SKIPPED};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RegenParams {
+    pub max_regen: f32,
+    pub blend_speed_low: f32,
+    pub blend_speed_high: f32,
+    pub abs_active: bool,
+}
+
+impl Default for RegenParams {
+    fn default() -> Self {
+        Self {
+            max_regen: 400.0,
+            blend_speed_low: 1.5,
+            blend_speed_high: 5.0,
+            abs_active: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RegenBlendResult {
+    pub regen_torque: f32,
+    pub friction_torque: f32,
+}
+
+impl RegenBlendResult {
+    /// Regen torque as a signed torque about the wheel's spin axis, positive
+    /// driving forwards like `AxleInput::drive_torque`; it always opposes
+    /// `wheel_omega`. Add it to the wheel's torque sum before integrating
+    /// `wheel_omega`.
+    pub fn as_drive_torque(self, wheel_omega: f32) -> f32 {
+        if wheel_omega == 0.0 {
+            return 0.0;
+        }
+        -self.regen_torque * wheel_omega.signum()
+    }
+}
+
+/// Splits a brake torque request between motor regen and friction brakes.
+/// `regen_torque + friction_torque` always equals the (non-negative) request.
+pub fn regen_blend(
+    brake_request: f32,
+    available_regen_torque: f32,
+    wheel_speed: f32,
+    params: RegenParams,
+) -> RegenBlendResult {
+    let request = if brake_request.is_finite() {
+        brake_request.max(0.0)
+    } else {
+        0.0
+    };
+    if params.abs_active {
+        return RegenBlendResult {
+            regen_torque: 0.0,
+            friction_torque: request,
+        };
+    }
+
+    let regen_cap = available_regen_torque
+        .max(0.0)
+        .min(params.max_regen.max(0.0));
+    let speed = wheel_speed.abs();
+    let span = params.blend_speed_high - params.blend_speed_low;
+    let speed_factor = if span <= 1.0e-6 {
+        if speed >= params.blend_speed_high {
+            1.0
+        } else {
+            0.0
+        }
+    } else {
+        let t = ((speed - params.blend_speed_low) / span).clamp(0.0, 1.0);
+        t * t * (3.0 - 2.0 * t)
+    };
+
+    let regen_torque = (regen_cap * speed_factor).min(request);
+    RegenBlendResult {
+        regen_torque,
+        friction_torque: request - regen_torque,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MotorState {
+    pub winding_temperature: f32,
+}
+
+impl Default for MotorState {
+    fn default() -> Self {
+        Self {
+            winding_temperature: 25.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MotorInput {
+    pub throttle: f32,
+    pub rpm: f32,
+    pub delta: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MotorParams {
+    pub peak_torque: f32,
+    pub base_speed: f32,
+    pub max_rpm: f32,
+    pub thermal_mass: f32,
+    pub cooling: f32,
+    pub derate_start_temp: f32,
+    pub derate_end_temp: f32,
+    pub copper_loss: f32,
+    pub ambient_temperature: f32,
+}
+
+impl Default for MotorParams {
+    fn default() -> Self {
+        Self {
+            peak_torque: 350.0,
+            base_speed: 4500.0,
+            max_rpm: 16000.0,
+            thermal_mass: 9000.0,
+            cooling: 40.0,
+            derate_start_temp: 140.0,
+            derate_end_temp: 180.0,
+            copper_loss: 0.05,
+            ambient_temperature: 25.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MotorStepResult {
+    pub torque: f32,
+    pub new_winding_temperature: f32,
+    pub derate_fraction: f32,
+}
+
+/// Torque available at `rpm` before derating: constant torque up to `base_speed`,
+/// constant power above it, zero past `max_rpm`.
+pub fn motor_torque_envelope(rpm: f32, params: MotorParams) -> f32 {
+    let speed = rpm.abs();
+    if !speed.is_finite() || speed >= params.max_rpm {
+        return 0.0;
+    }
+    let peak = params.peak_torque.max(0.0);
+    if speed <= params.base_speed {
+        peak
+    } else {
+        peak * params.base_speed.max(0.0) / speed
+    }
+}
+
+/// Share of the envelope removed by thermal derating (0 = none, 1 = fully derated).
+pub fn motor_derate_fraction(winding_temperature: f32, params: MotorParams) -> f32 {
+    let span = params.derate_end_temp - params.derate_start_temp;
+    if span <= 1.0e-6 {
+        return if winding_temperature >= params.derate_end_temp {
+            1.0
+        } else {
+            0.0
+        };
+    }
+    let t = ((winding_temperature - params.derate_start_temp) / span).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// One motor tick: envelope torque scaled by throttle and derating, then winding
+/// heating from copper losses (`copper_loss * torque^2`) against linear cooling.
+pub fn motor_step(state: MotorState, input: MotorInput, params: MotorParams) -> MotorStepResult {
+    let derate_fraction = motor_derate_fraction(state.winding_temperature, params);
+    let throttle = if input.throttle.is_finite() {
+        input.throttle.clamp(-1.0, 1.0)
+    } else {
+        0.0
+    };
+    let torque = throttle * motor_torque_envelope(input.rpm, params) * (1.0 - derate_fraction);
+
+    if !input.delta.is_finite() || input.delta <= 0.0 {
+        return MotorStepResult {
+            torque,
+            new_winding_temperature: state.winding_temperature,
+            derate_fraction,
+        };
+    }
+
+    let heat = params.copper_loss.max(0.0) * torque * torque;
+    let thermal_mass = params.thermal_mass.max(1.0e-3);
+    let cooling = params.cooling.max(0.0);
+    let new_winding_temperature = if cooling <= 1.0e-6 {
+        state.winding_temperature + heat / thermal_mass * input.delta
+    } else {
+        let equilibrium = params.ambient_temperature + heat / cooling;
+        let decay = (-cooling / thermal_mass * input.delta).exp();
+        equilibrium + (state.winding_temperature - equilibrium) * decay
+    };
+
+    MotorStepResult {
+        torque,
+        new_winding_temperature,
+        derate_fraction,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WindupState {
+    pub twist_rad: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WindupParams {
+    /// Torsional stiffness of the centre coupling (N·m/rad).
+    pub coupling_stiffness: f32,
+    /// Coupling damping on its twist rate (N·m per rad/s).
+    pub coupling_damping: f32,
+    /// Torque above which the centre clutch slips (N·m).
+    pub slip_clutch_torque: f32,
+    /// Coupling torque at which the tyres scrub the axles apart at 1 rad/s
+    /// (N·m per rad/s); 0 treats the tyres as rigid.
+    pub tire_slip_damping: f32,
+}
+
+impl Default for WindupParams {
+    fn default() -> Self {
+        Self {
+            coupling_stiffness: 2000.0,
+            coupling_damping: 5.0,
+            slip_clutch_torque: 150.0,
+            tire_slip_damping: 300.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WindupStepResult {
+    pub coupling_torque: f32,
+    pub new_state: WindupState,
+    pub clutch_slipping: bool,
+    /// Slip ratio the front tyres run at to release the twist (negative while
+    /// the coupling brakes them); add it to the slip fed to the wear step.
+    pub front_slip_ratio: f32,
+    /// Rear counterpart of `front_slip_ratio`.
+    pub rear_slip_ratio: f32,
+}
+
+/// Axle spin rate that rolls without slip at `ground_speed` on the live effective radius.
+pub fn rolling_omega(ground_speed: f32, effective_radius: f32) -> f32 {
+    if effective_radius <= 1.0e-6 {
+        return 0.0;
+    }
+    ground_speed / effective_radius
+}
+
+/// Centre-coupling windup between a front and rear axle (1:1 ratio), given
+/// each axle's free-rolling spin rate on its live effective radius.
+///
+/// The twist winds up at the mismatch `front_omega - rear_omega` less the rate
+/// the tyres scrub off, `coupling_torque / tire_slip_damping`, and is relaxed
+/// exactly, so a steady mismatch settles at `coupling_torque = mismatch *
+/// tire_slip_damping`. The torque is `stiffness * twist + damping * twist
+/// rate`, positive braking the front axle and driving the rear. When it would
+/// exceed `slip_clutch_torque` the clutch slips: the torque is held at the
+/// limit and the twist is released to the matching value. The scrubbed rate is
+/// split evenly between the axles and returned as their parasitic slip ratios.
+pub fn driveline_windup_step(
+    state: WindupState,
+    front_omega: f32,
+    rear_omega: f32,
+    params: &WindupParams,
+    delta: f32,
+) -> WindupStepResult {
+    driveline_windup_step_ex(state, front_omega, rear_omega, params, delta).0
+}
+
+/// `driveline_windup_step` under the crate delta policy (see `timestep`), with
+/// the status bits. A skipped step (bad `delta` or non-finite spin rates)
+/// keeps the twist and reports the torque it holds, with no slip. The clutch
+/// and tyres dissipate the twist, so a negative `delta` is always skipped.
+pub fn driveline_windup_step_ex(
+    state: WindupState,
+    front_omega: f32,
+    rear_omega: f32,
+    params: &WindupParams,
+    delta: f32,
+) -> (WindupStepResult, u32) {
+    let stiffness = params.coupling_stiffness.max(0.0);
+    let tire_damping = params.tire_slip_damping.max(0.0);
+    let limit = params.slip_clutch_torque.max(0.0);
+
+    let relative = front_omega - rear_omega;
+    let (dt, status) = resolve_delta(delta, false);
+    if dt <= 0.0 || !relative.is_finite() {
+        let held = stiffness * state.twist_rad;
+        let result = WindupStepResult {
+            coupling_torque: held.clamp(-limit, limit),
+            new_state: state,
+            clutch_slipping: held.abs() > limit,
+            ..WindupStepResult::default()
+        };
+        return (result, status | STEP_STATUS_DELTA_SKIPPED);
+    }
+
+    let start = state.twist_rad;
+    let mut twist = if stiffness > 0.0 && tire_damping > 0.0 {
+        let equilibrium = relative * tire_damping / stiffness;
+        equilibrium + (start - equilibrium) * (-stiffness / tire_damping * dt).exp()
+    } else {
+        start + relative * dt
+    };
+    let twist_rate = (twist - start) / dt;
+    let mut torque = stiffness * twist + params.coupling_damping.max(0.0) * twist_rate;
+    let clutch_slipping = torque.abs() > limit;
+    if clutch_slipping {
+        torque = limit * torque.signum();
+        if stiffness > 0.0 {
+            twist = torque / stiffness;
+        }
+    }
+
+    let scrub_rate = if tire_damping > 0.0 {
+        torque / tire_damping
+    } else {
+        0.0
+    };
+    let slip_ratio = |omega: f32, rate: f32| {
+        if omega.abs() > 1.0e-3 {
+            rate / omega
+        } else {
+            0.0
+        }
+    };
+    let result = WindupStepResult {
+        coupling_torque: torque,
+        new_state: WindupState { twist_rad: twist },
+        clutch_slipping,
+        front_slip_ratio: slip_ratio(front_omega, -0.5 * scrub_rate),
+        rear_slip_ratio: slip_ratio(rear_omega, 0.5 * scrub_rate),
+    };
+    (result, status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regen_blend_sums_to_request() {
+        for speed in [0.0, 1.0, 3.0, 10.0, 40.0] {
+            let out = regen_blend(600.0, 300.0, speed, RegenParams::default());
+            assert!((out.regen_torque + out.friction_torque - 600.0).abs() < 1.0e-3);
+            assert!(out.regen_torque <= 300.0);
+        }
+    }
+
+    #[test]
+    fn regen_phases_out_at_low_speed_and_under_abs() {
+        let params = RegenParams::default();
+        assert_eq!(regen_blend(200.0, 300.0, 0.5, params).regen_torque, 0.0);
+        assert_eq!(regen_blend(200.0, 300.0, 20.0, params).regen_torque, 200.0);
+
+        let abs = RegenParams {
+            abs_active: true,
+            ..params
+        };
+        let out = regen_blend(200.0, 300.0, 20.0, abs);
+        assert_eq!(out.regen_torque, 0.0);
+        assert_eq!(out.friction_torque, 200.0);
+    }
+
+    #[test]
+    fn regen_drive_torque_opposes_rotation() {
+        let out = regen_blend(200.0, 300.0, 20.0, RegenParams::default());
+        assert!(out.as_drive_torque(50.0) < 0.0);
+        assert!(out.as_drive_torque(-50.0) > 0.0);
+    }
+
+    #[test]
+    fn motor_envelope_is_constant_torque_then_constant_power() {
+        let params = MotorParams::default();
+        assert_eq!(motor_torque_envelope(1000.0, params), params.peak_torque);
+        let high = motor_torque_envelope(9000.0, params);
+        assert!((high * 9000.0 - params.peak_torque * params.base_speed).abs() < 1.0);
+        assert_eq!(motor_torque_envelope(params.max_rpm, params), 0.0);
+    }
+
+    #[test]
+    fn motor_heats_under_load_and_derates() {
+        let params = MotorParams::default();
+        let mut state = MotorState::default();
+        let input = MotorInput {
+            throttle: 1.0,
+            rpm: 3000.0,
+            delta: 1.0,
+        };
+        let first = motor_step(state, input, params);
+        assert_eq!(first.derate_fraction, 0.0);
+        let mut last = first;
+        for _ in 0..2000 {
+            last = motor_step(state, input, params);
+            state.winding_temperature = last.new_winding_temperature;
+        }
+        assert!(last.derate_fraction > 0.0);
+        assert!(last.torque < first.torque);
+        assert!(last.new_winding_temperature.is_finite());
+    }
+
+    #[test]
+    fn radius_mismatch_settles_to_constant_parasitic_torque() {
+        let speed = 30.0;
+        let front = rolling_omega(speed, 0.33 * 0.98);
+        let rear = rolling_omega(speed, 0.33);
+        let params = WindupParams::default();
+        let mut state = WindupState::default();
+        let mut last = WindupStepResult::default();
+        for _ in 0..600 {
+            last = driveline_windup_step(state, front, rear, &params, 1.0 / 60.0);
+            state = last.new_state;
+        }
+        assert!(last.clutch_slipping);
+        assert_eq!(last.coupling_torque, 150.0);
+
+        let matched =
+            driveline_windup_step(WindupState::default(), rear, rear, &params, 1.0 / 60.0);
+        assert_eq!(matched.coupling_torque, 0.0);
+        assert_eq!(matched.rear_slip_ratio, 0.0);
+    }
+
+    #[test]
+    fn two_percent_radius_mismatch_scrubs_and_wears_the_tyres() {
+        use crate::compute_effective_radius;
+        use crate::tire_state::TireState;
+        use crate::wear::{tire_wear_thermal_step, LapSample};
+
+        let speed = 30.0;
+        let load = 4000.0;
+        let radius = |unloaded: f32| compute_effective_radius(unloaded, 0.25, load, 250000.0);
+        let front = rolling_omega(speed, radius(0.33 * 0.98));
+        let rear = rolling_omega(speed, radius(0.33));
+        // A locked coupling: everything is released through the tyres.
+        let params = WindupParams {
+            slip_clutch_torque: 5000.0,
+            ..WindupParams::default()
+        };
+        let mut state = WindupState::default();
+        let mut last = WindupStepResult::default();
+        for _ in 0..600 {
+            last = driveline_windup_step(state, front, rear, &params, 1.0 / 60.0);
+            state = last.new_state;
+        }
+        assert!(!last.clutch_slipping);
+        let parasitic = (front - rear) * params.tire_slip_damping;
+        assert!((last.coupling_torque - parasitic).abs() < 1.0e-2 * parasitic);
+        assert!(last.front_slip_ratio < 0.0 && last.rear_slip_ratio > 0.0);
+        // The two axles scrub the whole 2% between them.
+        let total = last.rear_slip_ratio - last.front_slip_ratio;
+        assert!((total - 0.02).abs() < 2.0e-3, "{total}");
+
+        let wear_at = |slip_ratio: f32| {
+            let sample = LapSample {
+                load,
+                slip_ratio,
+                speed,
+                ..LapSample::default()
+            };
+            let mut tire = TireState::default();
+            for _ in 0..600 {
+                tire = tire_wear_thermal_step(tire, &sample, None, 0.1);
+            }
+            tire.wear
+        };
+        assert!(wear_at(last.rear_slip_ratio) > wear_at(0.0));
+        assert!(wear_at(last.front_slip_ratio) > wear_at(0.0));
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EstimatorParams {
+    /// Time constant of the exponential forgetting on the stiffness fit (s).
+    pub forgetting_time_s: f32,
+    /// Time constant over which the peak-grip estimate relaxes downwards (s).
+    pub mu_forgetting_time_s: f32,
+    /// Samples with `|slip_angle|` below this carry no information and are rejected.
+    pub min_slip_rad: f32,
+    /// Slip angle scale of the near-linear region the stiffness fit favours.
+    pub linear_slip_rad: f32,
+    /// Fit information (seconds of weighted excitation) at which confidence
+    /// reaches `1 - 1/e`.
+    pub confidence_reference: f32,
+}
+
+impl Default for EstimatorParams {
+    fn default() -> Self {
+        Self {
+            forgetting_time_s: 3.0,
+            mu_forgetting_time_s: 30.0,
+            min_slip_rad: 0.005,
+            linear_slip_rad: 0.04,
+            confidence_reference: 0.025,
+        }
+    }
+}
+
+/// Online tyre estimate. `estimated_stiffness` is the load-normalised cornering
+/// stiffness (`Fy / Fz` per radian), `estimated_mu` the peak `|Fy| / Fz` seen
+/// recently, `confidence` in `[0, 1)` grows with informative samples.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EstimatorState {
+    pub estimated_stiffness: f32,
+    pub estimated_mu: f32,
+    pub confidence: f32,
+    pub sum_slip_sq: f32,
+    pub sum_slip_force: f32,
+    pub information: f32,
+}
+
+/// Exponential-forgetting least squares for `Fy / Fz = k * alpha`, weighting
+/// samples by `exp(-(alpha / linear_slip_rad)^2)` so the fit tracks the initial
+/// slope rather than the saturated region. Near-zero slip angles, unloaded
+/// tyres and invalid `delta` leave the state untouched.
+pub fn tire_estimator_step(
+    state: EstimatorState,
+    measured_lateral_force: f32,
+    measured_slip_angle: f32,
+    normal_load: f32,
+    delta: f32,
+    params: EstimatorParams,
+) -> EstimatorState {
+    if !delta.is_finite() || delta <= 0.0 {
+        return state;
+    }
+    let alpha = measured_slip_angle;
+    let valid = alpha.is_finite()
+        && measured_lateral_force.is_finite()
+        && normal_load.is_finite()
+        && normal_load > 1.0
+        && alpha.abs() >= params.min_slip_rad;
+    if !valid {
+        return state;
+    }
+
+    let ratio = measured_lateral_force / normal_load;
+    let forget = (-delta / params.forgetting_time_s.max(1.0e-3)).exp();
+    let linear = params.linear_slip_rad.max(1.0e-4);
+    let weight = (-(alpha / linear) * (alpha / linear)).exp();
+
+    // Exact integral of a constant sample over `delta` under the forgetting
+    // decay, so the sums are in seconds and do not depend on the tick rate.
+    let held = params.forgetting_time_s.max(1.0e-3) * (1.0 - forget);
+    let mut next = state;
+    next.sum_slip_sq = state.sum_slip_sq * forget + held * weight * alpha * alpha;
+    next.sum_slip_force = state.sum_slip_force * forget + held * weight * alpha * ratio;
+    next.information =
+        state.information * forget + held * weight * (alpha / linear) * (alpha / linear);
+    if next.sum_slip_sq > 1.0e-12 {
+        next.estimated_stiffness = next.sum_slip_force / next.sum_slip_sq;
+    }
+
+    let mu_relax = 1.0 - (-delta / params.mu_forgetting_time_s.max(1.0e-3)).exp();
+    let observed = ratio.abs();
+    next.estimated_mu = if observed >= state.estimated_mu {
+        observed
+    } else {
+        state.estimated_mu + (observed - state.estimated_mu) * mu_relax
+    };
+    next.confidence = 1.0 - (-next.information / params.confidence_reference.max(1.0e-3)).exp();
+    next
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pacejka::{pacejka_fx, PacejkaCoeffs};
+
+    #[test]
+    fn estimator_converges_on_noisy_pacejka_data() {
+        let coeffs = PacejkaCoeffs {
+            b: 10.0,
+            c: 1.3,
+            mu: 1.1,
+            e: 0.0,
+        };
+        let fz = 4500.0;
+        let true_stiffness = coeffs.b * coeffs.c * coeffs.mu;
+
+        let mut seed = 12345_u32;
+        let mut noise = || {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (seed >> 8) as f32 / (1 << 24) as f32 * 2.0 - 1.0
+        };
+
+        let dt = 1.0 / 60.0;
+        let mut state = EstimatorState::default();
+        for i in 0..(4.0 / dt) as usize {
+            // Weaving through corners up to just past the peak slip angle.
+            let t = i as f32 * dt;
+            let alpha = 0.3 * (t * 2.0).sin();
+            let fy = pacejka_fx(&coeffs, alpha, fz) * (1.0 + 0.02 * noise());
+            state = tire_estimator_step(state, fy, alpha, fz, dt, EstimatorParams::default());
+        }
+        assert!(
+            (state.estimated_stiffness - true_stiffness).abs() / true_stiffness < 0.15,
+            "{} vs {true_stiffness}",
+            state.estimated_stiffness
+        );
+        assert!((state.estimated_mu - coeffs.mu).abs() < 0.05);
+        assert!(state.confidence > 0.9);
+    }
+
+    #[test]
+    fn estimator_rejects_unidentifiable_samples() {
+        let state = EstimatorState::default();
+        let params = EstimatorParams::default();
+        assert_eq!(
+            tire_estimator_step(state, 50.0, 0.001, 4000.0, 0.1, params),
+            state
+        );
+        assert_eq!(
+            tire_estimator_step(state, 50.0, 0.05, 0.0, 0.1, params),
+            state
+        );
+        assert_eq!(
+            tire_estimator_step(state, 50.0, 0.05, 4000.0, f32::NAN, params),
+            state
+        );
+    }
+}
@@ -0,0 +1,165 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::contamination::ContaminationState;
+use crate::failure::FailureState;
+use crate::tire_state::{is_at_operating_temp, TireCompound, TireState};
+
+pub const TIRE_EVENT_LOCKUP: u32 = 1 << 0;
+pub const TIRE_EVENT_HYDROPLANING: u32 = 1 << 1;
+pub const TIRE_EVENT_TEMPERATURE_WINDOW_ENTERED: u32 = 1 << 2;
+pub const TIRE_EVENT_CORD_EXPOSED: u32 = 1 << 3;
+pub const TIRE_EVENT_PUNCTURE: u32 = 1 << 4;
+
+/// Levels at which the event conditions hold. The temperature window comes
+/// from the `TireCompound`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EventThresholds {
+    /// Braking slip ratio magnitude counted as a locked wheel.
+    pub lockup_slip_ratio: f32,
+    /// `water_film_on_tread` at which the tread is riding on water.
+    pub hydroplaning_water_film: f32,
+    /// Wear at which the cords show (`tire_visual_wear` forces `cord_blend` at 1).
+    pub cord_exposed_wear: f32,
+}
+
+impl Default for EventThresholds {
+    fn default() -> Self {
+        Self {
+            lockup_slip_ratio: 0.9,
+            hydroplaning_water_film: 0.8,
+            cord_exposed_wear: 1.0,
+        }
+    }
+}
+
+/// The per-tick values the events are read from.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TireEventInput {
+    pub tire: TireState,
+    /// Most worn circumferential segment (`worst_segment_wear`), 0 without a
+    /// wear map; the cords show at the worst point, not on average.
+    pub worst_segment_wear: f32,
+    pub slip_ratio: f32,
+    pub contamination: ContaminationState,
+    pub failure: FailureState,
+}
+
+/// Conditions that held on the previous tick. Part of the tyre's saved state so
+/// a replay from a snapshot emits the same events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TireEventState {
+    pub active: u32,
+}
+
+/// Every event condition currently holding, as `TIRE_EVENT_*` bits.
+pub fn tire_event_conditions(
+    input: &TireEventInput,
+    compound: TireCompound,
+    thresholds: &EventThresholds,
+) -> u32 {
+    let mut bits = 0;
+    if input.slip_ratio <= -thresholds.lockup_slip_ratio {
+        bits |= TIRE_EVENT_LOCKUP;
+    }
+    if input.contamination.water_film_on_tread >= thresholds.hydroplaning_water_film {
+        bits |= TIRE_EVENT_HYDROPLANING;
+    }
+    if is_at_operating_temp(&input.tire, compound) {
+        bits |= TIRE_EVENT_TEMPERATURE_WINDOW_ENTERED;
+    }
+    if input.tire.wear.max(input.worst_segment_wear) >= thresholds.cord_exposed_wear {
+        bits |= TIRE_EVENT_CORD_EXPOSED;
+    }
+    if input.failure.is_blown_out() {
+        bits |= TIRE_EVENT_PUNCTURE;
+    }
+    bits
+}
+
+/// Edge-triggered events: the bits whose condition starts this tick (held on
+/// this tick, not on the previous one). `state` is advanced in place.
+pub fn tire_events_step(
+    state: &mut TireEventState,
+    input: &TireEventInput,
+    compound: TireCompound,
+    thresholds: &EventThresholds,
+) -> u32 {
+    let active = tire_event_conditions(input, compound, thresholds);
+    let started = active & !state.active;
+    state.active = active;
+    started
+}
+
+/// Name of a single `TIRE_EVENT_*` bit for logging, or `""`.
+pub fn tire_event_name(bit: u32) -> &'static str {
+    match bit {
+        TIRE_EVENT_LOCKUP => "lockup",
+        TIRE_EVENT_HYDROPLANING => "hydroplaning",
+        TIRE_EVENT_TEMPERATURE_WINDOW_ENTERED => "temperature_window_entered",
+        TIRE_EVENT_CORD_EXPOSED => "cord_exposed",
+        TIRE_EVENT_PUNCTURE => "puncture",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_fire_once_when_the_condition_starts() {
+        let compound = TireCompound::MEDIUM;
+        let thresholds = EventThresholds::default();
+        let mut state = TireEventState::default();
+        let mut input = TireEventInput::default();
+        assert_eq!(
+            tire_events_step(&mut state, &input, compound, &thresholds),
+            0
+        );
+
+        input.slip_ratio = -1.0;
+        let fired = tire_events_step(&mut state, &input, compound, &thresholds);
+        assert_eq!(fired, TIRE_EVENT_LOCKUP);
+        assert_eq!(
+            tire_events_step(&mut state, &input, compound, &thresholds),
+            0
+        );
+
+        input.slip_ratio = 0.0;
+        tire_events_step(&mut state, &input, compound, &thresholds);
+        input.slip_ratio = -0.95;
+        input.tire.surface_temp_c = 100.0;
+        input.tire.core_temp_c = 100.0;
+        input.failure.sidewall_damage = 1.0;
+        let fired = tire_events_step(&mut state, &input, compound, &thresholds);
+        assert_eq!(
+            fired,
+            TIRE_EVENT_LOCKUP | TIRE_EVENT_TEMPERATURE_WINDOW_ENTERED | TIRE_EVENT_PUNCTURE
+        );
+
+        // One segment through to the cords while the mean is still low.
+        input.tire.wear = 0.2;
+        input.worst_segment_wear = 1.0;
+        let fired = tire_events_step(&mut state, &input, compound, &thresholds);
+        assert_eq!(fired, TIRE_EVENT_CORD_EXPOSED);
+    }
+
+    #[test]
+    fn every_event_bit_has_a_name() {
+        for bit in [
+            TIRE_EVENT_LOCKUP,
+            TIRE_EVENT_HYDROPLANING,
+            TIRE_EVENT_TEMPERATURE_WINDOW_ENTERED,
+            TIRE_EVENT_CORD_EXPOSED,
+            TIRE_EVENT_PUNCTURE,
+        ] {
+            assert!(!tire_event_name(bit).is_empty());
+        }
+        assert_eq!(tire_event_name(TIRE_EVENT_LOCKUP | TIRE_EVENT_PUNCTURE), "");
+        assert_eq!(tire_event_name(0), "");
+    }
+}
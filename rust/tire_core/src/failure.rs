@@ -0,0 +1,85 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::contact::ContactAggregate;
+
+/// Sidewall scrubbing energy that tears the sidewall (damage 1).
+pub const SIDEWALL_FAILURE_ENERGY_J: f32 = 200000.0;
+
+/// Accumulated structural damage, `0` (new) to `1` (failed).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FailureState {
+    pub sidewall_damage: f32,
+}
+
+impl FailureState {
+    pub fn is_blown_out(&self) -> bool {
+        self.sidewall_damage >= 1.0
+    }
+}
+
+/// Adds the friction work of the sidewall contacts over `delta`,
+/// `grip * |F . n| * sliding_speed * delta`, as a fraction of
+/// `SIDEWALL_FAILURE_ENERGY_J`. Damage never decreases and saturates at 1.
+pub fn sidewall_damage_step(
+    state: FailureState,
+    sidewall: &ContactAggregate,
+    sliding_speed_m_per_s: f32,
+    delta: f32,
+) -> FailureState {
+    if !delta.is_finite() || delta <= 0.0 {
+        return state;
+    }
+    let normal_load = sidewall.total_force.dot(sidewall.average_normal).abs();
+    let energy = sidewall.grip * normal_load * sliding_speed_m_per_s.abs() * delta;
+    if !energy.is_finite() || energy <= 0.0 {
+        return state;
+    }
+    FailureState {
+        sidewall_damage: (state.sidewall_damage + energy / SIDEWALL_FAILURE_ENERGY_J).min(1.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contact::SIDEWALL_FRICTION;
+    use crate::Vec3;
+
+    #[test]
+    fn sustained_wall_scrub_eventually_blows_the_tyre() {
+        let scrub = ContactAggregate {
+            count: 2,
+            total_force: Vec3 {
+                x: 0.0,
+                y: 2000.0,
+                z: 0.0,
+            },
+            average_normal: Vec3 {
+                x: 0.0,
+                y: 1.0,
+                z: 0.0,
+            },
+            grip: SIDEWALL_FRICTION,
+            ..ContactAggregate::default()
+        };
+        let dt = 1.0 / 60.0;
+        let mut state = FailureState::default();
+        let mut steps = 0;
+        while !state.is_blown_out() {
+            let next = sidewall_damage_step(state, &scrub, 10.0, dt);
+            assert!(next.sidewall_damage > state.sidewall_damage);
+            state = next;
+            steps += 1;
+        }
+        assert_eq!(state.sidewall_damage, 1.0);
+        assert!((steps as f32 * dt - 200000.0 / (0.6 * 2000.0 * 10.0)).abs() < 0.1);
+
+        let untouched = ContactAggregate::default();
+        assert_eq!(
+            sidewall_damage_step(FailureState::default(), &untouched, 10.0, dt),
+            FailureState::default()
+        );
+    }
+}
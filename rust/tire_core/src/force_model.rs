@@ -0,0 +1,508 @@
+//! Tyre force models behind one interface, with the force Jacobian an implicit
+//! vehicle integrator needs.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::pacejka::{pacejka_force_jacobian, pacejka_fx, pacejka_fy, PacejkaCoeffs};
+
+/// Slip step of the finite-difference fallback.
+const FD_SLIP_STEP: f32 = 1.0e-3;
+/// Relative load step of the finite-difference fallback.
+const FD_LOAD_STEP_FRACTION: f32 = 1.0e-3;
+
+/// Combined-slip brush (Fiala) model with a parabolic pressure distribution,
+/// the closed form of `patch::brush_lateral_forces`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BrushParams {
+    /// `dFx/dkappa` at zero slip (N).
+    pub longitudinal_stiffness: f32,
+    /// `dFy/dalpha` at zero slip (N/rad).
+    pub cornering_stiffness: f32,
+    pub mu: f32,
+}
+
+impl Default for BrushParams {
+    fn default() -> Self {
+        Self {
+            longitudinal_stiffness: 80000.0,
+            cornering_stiffness: 60000.0,
+            mu: 1.0,
+        }
+    }
+}
+
+/// Which force curve to evaluate. Forces are positive for positive slip (flip
+/// `fy` for ISO 8855 at the call site, as for `pacejka_fy`).
+#[derive(Clone, Copy)]
+pub enum ForceModel<'a> {
+    /// Pure-slip Magic Formula: `pacejka_fx` on the slip ratio, `pacejka_fy` on
+    /// the slip angle.
+    Pacejka(PacejkaCoeffs),
+    Brush(BrushParams),
+    /// Any other curve, e.g. table driven: `(slip_ratio, slip_angle, fz) ->
+    /// (fx, fy)`. Differentiated by central differences.
+    Curve(&'a dyn Fn(f32, f32, f32) -> (f32, f32)),
+}
+
+/// Partial derivatives of `(fx, fy)` with respect to slip ratio, slip angle
+/// (rad) and normal load (N).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ForceJacobian {
+    pub dfx_dslip: f32,
+    pub dfx_dalpha: f32,
+    pub dfx_dload: f32,
+    pub dfy_dslip: f32,
+    pub dfy_dalpha: f32,
+    pub dfy_dload: f32,
+}
+
+/// Linear brush forces `(Cx kappa, Cy tan(alpha))`, their magnitude and the
+/// saturation ratio `lambda = |.| / (3 mu fz)`.
+fn brush_terms(
+    params: &BrushParams,
+    slip_ratio: f32,
+    slip_angle: f32,
+    fz: f32,
+) -> (f32, f32, f32, f32) {
+    let u = params.longitudinal_stiffness.max(0.0) * slip_ratio;
+    let v = params.cornering_stiffness.max(0.0) * slip_angle.tan();
+    let phi = (u * u + v * v).sqrt();
+    let lambda = phi / (3.0 * params.mu.max(1.0e-6) * fz);
+    (u, v, phi, lambda)
+}
+
+/// `F / phi` of the brush model and its derivative in `lambda`: the cubic
+/// `1 - lambda + lambda^2 / 3` while part of the patch adheres, `1 / (3 lambda)`
+/// in full sliding. Both pieces meet with equal value and slope at 1.
+fn brush_shape(lambda: f32) -> (f32, f32) {
+    if lambda <= 1.0 {
+        (
+            1.0 - lambda + lambda * lambda / 3.0,
+            -1.0 + 2.0 * lambda / 3.0,
+        )
+    } else {
+        (1.0 / (3.0 * lambda), -1.0 / (3.0 * lambda * lambda))
+    }
+}
+
+fn brush_forces(params: &BrushParams, slip_ratio: f32, slip_angle: f32, fz: f32) -> (f32, f32) {
+    if !fz.is_finite() || fz <= 0.0 {
+        return (0.0, 0.0);
+    }
+    let (u, v, _, lambda) = brush_terms(params, slip_ratio, slip_angle, fz);
+    let (h, _) = brush_shape(lambda);
+    (u * h, v * h)
+}
+
+fn brush_jacobian(
+    params: &BrushParams,
+    slip_ratio: f32,
+    slip_angle: f32,
+    fz: f32,
+) -> ForceJacobian {
+    if !fz.is_finite() || fz <= 0.0 {
+        return ForceJacobian::default();
+    }
+    let (u, v, phi, lambda) = brush_terms(params, slip_ratio, slip_angle, fz);
+    let (h, dh) = brush_shape(lambda);
+    let cx = params.longitudinal_stiffness.max(0.0);
+    let cos = slip_angle.cos();
+    let cy = params.cornering_stiffness.max(0.0) / (cos * cos);
+    // d(lambda)/du = u / (3 mu fz phi); `k` carries the shared factor.
+    let k = if phi > 1.0e-9 {
+        dh * lambda / (phi * phi)
+    } else {
+        0.0
+    };
+    let dload = -dh * lambda / fz;
+    ForceJacobian {
+        dfx_dslip: cx * (h + k * u * u),
+        dfx_dalpha: cy * k * u * v,
+        dfx_dload: u * dload,
+        dfy_dslip: cx * k * u * v,
+        dfy_dalpha: cy * (h + k * v * v),
+        dfy_dload: v * dload,
+    }
+}
+
+/// `(fx, fy)` of `model` at the given slips and normal load.
+pub fn tire_forces(
+    model: &ForceModel,
+    slip_ratio: f32,
+    slip_angle: f32,
+    normal_load: f32,
+) -> (f32, f32) {
+    match model {
+        ForceModel::Pacejka(coeffs) => (
+            pacejka_fx(coeffs, slip_ratio, normal_load),
+            pacejka_fy(coeffs, slip_angle, normal_load),
+        ),
+        ForceModel::Brush(params) => brush_forces(params, slip_ratio, slip_angle, normal_load),
+        ForceModel::Curve(curve) => curve(slip_ratio, slip_angle, normal_load),
+    }
+}
+
+/// Jacobian of `tire_forces`: analytic for the Pacejka and brush models,
+/// central differences (slip step 1e-3, load step 0.1% of the load but at
+/// least 1 N) for `Curve`. Non-finite results read as 0.
+pub fn tire_force_jacobian(
+    model: &ForceModel,
+    slip_ratio: f32,
+    slip_angle: f32,
+    normal_load: f32,
+) -> ForceJacobian {
+    let jacobian = match model {
+        ForceModel::Pacejka(coeffs) => {
+            let (dfx_dslip, dfx_dalpha, dfy_dslip, dfy_dalpha) =
+                pacejka_force_jacobian(coeffs, slip_ratio, slip_angle, normal_load);
+            // Both forces are linear in the load while it is positive.
+            let (dfx_dload, dfy_dload) = if normal_load > 0.0 {
+                (
+                    pacejka_fx(coeffs, slip_ratio, normal_load) / normal_load,
+                    pacejka_fy(coeffs, slip_angle, normal_load) / normal_load,
+                )
+            } else {
+                (0.0, 0.0)
+            };
+            ForceJacobian {
+                dfx_dslip,
+                dfx_dalpha,
+                dfx_dload,
+                dfy_dslip,
+                dfy_dalpha,
+                dfy_dload,
+            }
+        }
+        ForceModel::Brush(params) => brush_jacobian(params, slip_ratio, slip_angle, normal_load),
+        ForceModel::Curve(_) => {
+            let f = |s: f32, a: f32, z: f32| tire_forces(model, s, a, z);
+            let h = FD_SLIP_STEP;
+            let hz = (FD_LOAD_STEP_FRACTION * normal_load.abs()).max(1.0);
+            let (sp, sm) = (
+                f(slip_ratio + h, slip_angle, normal_load),
+                f(slip_ratio - h, slip_angle, normal_load),
+            );
+            let (ap, am) = (
+                f(slip_ratio, slip_angle + h, normal_load),
+                f(slip_ratio, slip_angle - h, normal_load),
+            );
+            let (zp, zm) = (
+                f(slip_ratio, slip_angle, normal_load + hz),
+                f(slip_ratio, slip_angle, normal_load - hz),
+            );
+            ForceJacobian {
+                dfx_dslip: (sp.0 - sm.0) / (2.0 * h),
+                dfx_dalpha: (ap.0 - am.0) / (2.0 * h),
+                dfx_dload: (zp.0 - zm.0) / (2.0 * hz),
+                dfy_dslip: (sp.1 - sm.1) / (2.0 * h),
+                dfy_dalpha: (ap.1 - am.1) / (2.0 * h),
+                dfy_dload: (zp.1 - zm.1) / (2.0 * hz),
+            }
+        }
+    };
+    let finite = |x: f32| if x.is_finite() { x } else { 0.0 };
+    ForceJacobian {
+        dfx_dslip: finite(jacobian.dfx_dslip),
+        dfx_dalpha: finite(jacobian.dfx_dalpha),
+        dfx_dload: finite(jacobian.dfx_dload),
+        dfy_dslip: finite(jacobian.dfy_dslip),
+        dfy_dalpha: finite(jacobian.dfy_dalpha),
+        dfy_dload: finite(jacobian.dfy_dload),
+    }
+}
+
+/// Samples per axis `tire_compare_models` accepts at most.
+pub const COMPARE_MAX_DENSITY: u32 = 64;
+/// Points of the pure-slip sweeps used to locate the force peaks.
+const COMPARE_PEAK_SAMPLES: u32 = 401;
+
+/// Region of operating points two force models are compared over. Ranges are
+/// `[min, max]`; loads must be positive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OperatingEnvelope {
+    pub load_range_n: [f32; 2],
+    pub slip_ratio_range: [f32; 2],
+    pub slip_angle_range_rad: [f32; 2],
+}
+
+impl Default for OperatingEnvelope {
+    fn default() -> Self {
+        Self {
+            load_range_n: [1000.0, 8000.0],
+            slip_ratio_range: [-0.3, 0.3],
+            slip_angle_range_rad: [-0.3, 0.3],
+        }
+    }
+}
+
+/// Divergence of model `b` from model `a`. Force differences are the length
+/// of `(dfx, dfy)` over the grid; the peak and stiffness entries are `b - a`
+/// at the envelope's mean load, peaks taken as the slip magnitude of the
+/// largest pure-slip force inside the range. `worst_*` is the grid point of
+/// `max_force_difference_n`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ModelComparisonReport {
+    pub max_force_difference_n: f32,
+    pub rms_force_difference_n: f32,
+    pub peak_slip_ratio_difference: f32,
+    pub peak_slip_angle_difference_rad: f32,
+    pub longitudinal_stiffness_difference: f32,
+    pub cornering_stiffness_difference: f32,
+    pub worst_load_n: f32,
+    pub worst_slip_ratio: f32,
+    pub worst_slip_angle_rad: f32,
+    pub samples: u32,
+}
+
+fn grid_axis(range: [f32; 2], i: u32, n: u32) -> f32 {
+    range[0] + (range[1] - range[0]) * i as f32 / (n - 1) as f32
+}
+
+/// Slip magnitude of the largest `|force|` along a pure-slip sweep.
+fn peak_slip(force: impl Fn(f32) -> f32, range: [f32; 2]) -> f32 {
+    let mut best = (0.0_f32, f32::MIN);
+    for i in 0..COMPARE_PEAK_SAMPLES {
+        let slip = grid_axis(range, i, COMPARE_PEAK_SAMPLES);
+        let f = force(slip).abs();
+        if f > best.1 {
+            best = (slip.abs(), f);
+        }
+    }
+    best.0
+}
+
+/// Compares two models on a `density`-per-axis grid over `envelope` (load,
+/// slip ratio, slip angle). `None` when `density` is outside
+/// `2..=COMPARE_MAX_DENSITY` or the envelope is not finite, ordered and
+/// positively loaded.
+pub fn tire_compare_models(
+    a: &ForceModel,
+    b: &ForceModel,
+    envelope: &OperatingEnvelope,
+    density: u32,
+) -> Option<ModelComparisonReport> {
+    let ranges = [
+        envelope.load_range_n,
+        envelope.slip_ratio_range,
+        envelope.slip_angle_range_rad,
+    ];
+    let valid = ranges
+        .iter()
+        .all(|r| r[0].is_finite() && r[1].is_finite() && r[0] <= r[1])
+        && envelope.load_range_n[0] > 0.0;
+    if !valid || !(2..=COMPARE_MAX_DENSITY).contains(&density) {
+        return None;
+    }
+
+    let mut report = ModelComparisonReport::default();
+    let mut sum_sq = 0.0_f64;
+    for i in 0..density {
+        let load = grid_axis(envelope.load_range_n, i, density);
+        for j in 0..density {
+            let slip_ratio = grid_axis(envelope.slip_ratio_range, j, density);
+            for k in 0..density {
+                let slip_angle = grid_axis(envelope.slip_angle_range_rad, k, density);
+                let fa = tire_forces(a, slip_ratio, slip_angle, load);
+                let fb = tire_forces(b, slip_ratio, slip_angle, load);
+                let diff = (fb.0 - fa.0).hypot(fb.1 - fa.1);
+                let diff = if diff.is_finite() { diff } else { 0.0 };
+                sum_sq += f64::from(diff) * f64::from(diff);
+                report.samples += 1;
+                if diff > report.max_force_difference_n || report.samples == 1 {
+                    report.max_force_difference_n = diff;
+                    report.worst_load_n = load;
+                    report.worst_slip_ratio = slip_ratio;
+                    report.worst_slip_angle_rad = slip_angle;
+                }
+            }
+        }
+    }
+    report.rms_force_difference_n = (sum_sq / f64::from(report.samples)).sqrt() as f32;
+
+    let load = 0.5 * (envelope.load_range_n[0] + envelope.load_range_n[1]);
+    let peak_kappa = |m: &ForceModel| {
+        peak_slip(
+            |s| tire_forces(m, s, 0.0, load).0,
+            envelope.slip_ratio_range,
+        )
+    };
+    let peak_alpha = |m: &ForceModel| {
+        peak_slip(
+            |s| tire_forces(m, 0.0, s, load).1,
+            envelope.slip_angle_range_rad,
+        )
+    };
+    report.peak_slip_ratio_difference = peak_kappa(b) - peak_kappa(a);
+    report.peak_slip_angle_difference_rad = peak_alpha(b) - peak_alpha(a);
+    let ja = tire_force_jacobian(a, 0.0, 0.0, load);
+    let jb = tire_force_jacobian(b, 0.0, 0.0, load);
+    report.longitudinal_stiffness_difference = jb.dfx_dslip - ja.dfx_dslip;
+    report.cornering_stiffness_difference = jb.dfy_dalpha - ja.dfy_dalpha;
+    Some(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pacejka::peak_slip_ratio;
+
+    /// Central differences in f64 of the f32 model, as the reference.
+    fn reference(model: &ForceModel, s: f32, a: f32, z: f32) -> [f32; 6] {
+        let h = 2.0e-3_f32;
+        let hz = 20.0_f32;
+        let d = |p: (f32, f32), m: (f32, f32), step: f32| {
+            (
+                ((p.0 as f64 - m.0 as f64) / (2.0 * step as f64)) as f32,
+                ((p.1 as f64 - m.1 as f64) / (2.0 * step as f64)) as f32,
+            )
+        };
+        let ds = d(
+            tire_forces(model, s + h, a, z),
+            tire_forces(model, s - h, a, z),
+            h,
+        );
+        let da = d(
+            tire_forces(model, s, a + h, z),
+            tire_forces(model, s, a - h, z),
+            h,
+        );
+        let dz = d(
+            tire_forces(model, s, a, z + hz),
+            tire_forces(model, s, a, z - hz),
+            hz,
+        );
+        [ds.0, da.0, dz.0, ds.1, da.1, dz.1]
+    }
+
+    fn assert_matches(model: &ForceModel, s: f32, a: f32, z: f32, slip_scale: f32) {
+        let j = tire_force_jacobian(model, s, a, z);
+        let analytic = [
+            j.dfx_dslip,
+            j.dfx_dalpha,
+            j.dfx_dload,
+            j.dfy_dslip,
+            j.dfy_dalpha,
+            j.dfy_dload,
+        ];
+        for (i, (x, r)) in analytic.iter().zip(reference(model, s, a, z)).enumerate() {
+            // Slip derivatives relative to the initial slope, load ones to mu.
+            let scale = if i % 3 == 2 { 1.0 } else { slip_scale };
+            assert!(
+                (x - r).abs() / scale < 5.0e-3,
+                "entry {i}: {x} vs {r} at ({s}, {a}, {z})"
+            );
+        }
+    }
+
+    #[test]
+    fn pacejka_jacobian_matches_finite_differences_through_the_peak() {
+        let coeffs = PacejkaCoeffs::default();
+        let model = ForceModel::Pacejka(coeffs);
+        let fz = 4000.0;
+        let peak = peak_slip_ratio(&coeffs, fz, 1.0e-5);
+        let scale = coeffs.b * coeffs.c * coeffs.mu * fz;
+        for s in [
+            0.01,
+            0.5 * peak,
+            peak - 0.005,
+            peak,
+            peak + 0.005,
+            0.3,
+            -0.08,
+        ] {
+            assert_matches(&model, s, s, fz, scale);
+        }
+        let before = tire_force_jacobian(&model, peak - 0.01, 0.0, fz).dfx_dslip;
+        let after = tire_force_jacobian(&model, peak + 0.01, 0.0, fz).dfx_dslip;
+        assert!(before > 0.0 && after < 0.0);
+    }
+
+    #[test]
+    fn brush_jacobian_matches_finite_differences_with_combined_slip() {
+        let params = BrushParams::default();
+        let model = ForceModel::Brush(params);
+        let fz = 4000.0;
+        // Full sliding starts at |(Cx k, Cy tan a)| = 3 mu fz = 12 kN.
+        for (s, a) in [
+            (0.01, 0.005),
+            (0.05, 0.08),
+            (0.12, 0.0),
+            (0.15, 0.0),
+            (0.1, 0.12),
+            (-0.2, 0.3),
+            (0.0, -0.05),
+        ] {
+            assert_matches(&model, s, a, fz, params.cornering_stiffness);
+        }
+        let j = tire_force_jacobian(&model, 0.0, 0.0, fz);
+        assert_eq!(j.dfx_dslip, params.longitudinal_stiffness);
+        assert_eq!(j.dfy_dalpha, params.cornering_stiffness);
+        assert_eq!(
+            tire_force_jacobian(&model, 0.1, 0.1, 0.0),
+            ForceJacobian::default()
+        );
+    }
+
+    #[test]
+    fn curve_falls_back_to_finite_differences() {
+        let table = |s: f32, a: f32, z: f32| (z * (2.0 * s).min(1.0), z * a - 100.0 * s * a);
+        let model = ForceModel::Curve(&table);
+        assert_matches(&model, 0.2, 0.1, 3000.0, 3000.0);
+    }
+
+    #[test]
+    fn model_comparison_reports_divergence_and_worst_point() {
+        let envelope = OperatingEnvelope::default();
+        let pacejka = ForceModel::Pacejka(PacejkaCoeffs::default());
+        let brush = ForceModel::Brush(BrushParams::default());
+
+        let same = tire_compare_models(&brush, &brush, &envelope, 8).unwrap();
+        assert_eq!(same.max_force_difference_n, 0.0);
+        assert_eq!(same.rms_force_difference_n, 0.0);
+        assert_eq!(same.peak_slip_ratio_difference, 0.0);
+        assert_eq!(same.samples, 512);
+
+        let report = tire_compare_models(&pacejka, &brush, &envelope, 11).unwrap();
+        assert!(report.max_force_difference_n > 0.0);
+        assert!(report.rms_force_difference_n <= report.max_force_difference_n);
+        let worst = tire_forces(
+            &pacejka,
+            report.worst_slip_ratio,
+            report.worst_slip_angle_rad,
+            report.worst_load_n,
+        );
+        let other = tire_forces(
+            &brush,
+            report.worst_slip_ratio,
+            report.worst_slip_angle_rad,
+            report.worst_load_n,
+        );
+        let diff = (other.0 - worst.0).hypot(other.1 - worst.1);
+        assert_eq!(diff, report.max_force_difference_n);
+        // Default Pacejka stiffness at 4500 N is B C D = 10 * 1.9 * 4500.
+        let expected = 80000.0 - 10.0 * 1.9 * 4500.0;
+        assert!((report.longitudinal_stiffness_difference - expected).abs() < 1.0);
+
+        // Doubling B halves the slip at the peak.
+        let stiff = ForceModel::Pacejka(PacejkaCoeffs {
+            b: 20.0,
+            ..PacejkaCoeffs::default()
+        });
+        let peaks = tire_compare_models(&pacejka, &stiff, &envelope, 2).unwrap();
+        let peak = peak_slip_ratio(&PacejkaCoeffs::default(), 4500.0, 1.0e-5);
+        assert!((peaks.peak_slip_ratio_difference + 0.5 * peak).abs() < 2.0e-3);
+        assert!((peaks.peak_slip_angle_difference_rad + 0.5 * peak).abs() < 2.0e-3);
+
+        assert!(tire_compare_models(&pacejka, &brush, &envelope, 1).is_none());
+        let unloaded = OperatingEnvelope {
+            load_range_n: [0.0, 1000.0],
+            ..envelope
+        };
+        assert!(tire_compare_models(&pacejka, &brush, &unloaded, 4).is_none());
+    }
+}
@@ -0,0 +1,94 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Directional tread grip: separate friction coefficients along the rolling
+/// direction and across it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AnisotropicGrip {
+    pub longitudinal_mu: f32,
+    pub lateral_mu: f32,
+}
+
+impl AnisotropicGrip {
+    pub fn isotropic(mu: f32) -> Self {
+        Self {
+            longitudinal_mu: mu,
+            lateral_mu: mu,
+        }
+    }
+}
+
+impl Default for AnisotropicGrip {
+    fn default() -> Self {
+        Self::isotropic(1.0)
+    }
+}
+
+/// Limits `(fx, fy)` to the ellipse with semi-axes `longitudinal_mu * fz` and
+/// `lateral_mu * fz`. Forces inside pass through unchanged; forces outside are
+/// scaled uniformly back onto the ellipse, keeping their direction. An unloaded
+/// tyre (`fz <= 0`) or a zero axis produces no force.
+pub fn tire_friction_ellipse_anisotropic(
+    fx: f32,
+    fy: f32,
+    fz: f32,
+    grip: &AnisotropicGrip,
+) -> (f32, f32) {
+    let fx_max = grip.longitudinal_mu.max(0.0) * fz;
+    let fy_max = grip.lateral_mu.max(0.0) * fz;
+    if !fx.is_finite() || !fy.is_finite() || !fx_max.is_finite() || !fy_max.is_finite() {
+        return (0.0, 0.0);
+    }
+    if fx_max <= 0.0 || fy_max <= 0.0 {
+        return (0.0, 0.0);
+    }
+    let usage = (fx / fx_max).hypot(fy / fy_max);
+    if usage <= 1.0 {
+        (fx, fy)
+    } else {
+        (fx / usage, fy / usage)
+    }
+}
+
+/// Isotropic friction circle: `tire_friction_ellipse_anisotropic` with both
+/// coefficients equal to `friction_coeff`.
+pub fn friction_ellipse(fx: f32, fy: f32, fz: f32, friction_coeff: f32) -> (f32, f32) {
+    tire_friction_ellipse_anisotropic(fx, fy, fz, &AnisotropicGrip::isotropic(friction_coeff))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ellipse_clamps_asymmetrically() {
+        // Studded ice tyre: bites laterally, little braking grip.
+        let ice = AnisotropicGrip {
+            longitudinal_mu: 0.15,
+            lateral_mu: 0.4,
+        };
+        let fz = 4000.0;
+        let (fx, fy) = tire_friction_ellipse_anisotropic(-3000.0, 0.0, fz, &ice);
+        assert!((fx + 600.0).abs() < 1.0e-2 && fy == 0.0);
+        let (fx, fy) = tire_friction_ellipse_anisotropic(0.0, 3000.0, fz, &ice);
+        assert!(fx == 0.0 && (fy - 1600.0).abs() < 1.0e-2);
+
+        let (fx, fy) = tire_friction_ellipse_anisotropic(1000.0, 2000.0, fz, &ice);
+        let on_ellipse = (fx / 600.0).hypot(fy / 1600.0);
+        assert!((on_ellipse - 1.0).abs() < 1.0e-4);
+        assert!((fy / fx - 2.0).abs() < 1.0e-4);
+
+        assert_eq!(
+            tire_friction_ellipse_anisotropic(300.0, 500.0, fz, &ice),
+            (300.0, 500.0)
+        );
+    }
+
+    #[test]
+    fn isotropic_case_is_a_friction_circle() {
+        let (fx, fy) = friction_ellipse(3000.0, 4000.0, 4000.0, 1.0);
+        assert!((fx.hypot(fy) - 4000.0).abs() < 1.0e-2);
+        assert_eq!(friction_ellipse(3000.0, 4000.0, 0.0, 1.0), (0.0, 0.0));
+    }
+}
@@ -0,0 +1,127 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::tire_state::TireCompound;
+
+/// Grain size at which the surface is fully grained.
+pub const MAX_GRAIN_SIZE_UM: f32 = 200.0;
+/// Growth rate (1/s) at the reference slip and load, fully cold.
+pub const GRAIN_GROWTH_RATE_PER_S: f32 = 0.05;
+pub const GRAIN_REFERENCE_SLIP: f32 = 0.1;
+pub const GRAIN_REFERENCE_LOAD_N: f32 = 4000.0;
+/// Degrees below `optimal_temp_min` at which growth reaches its full rate.
+pub const GRAIN_COLD_SPAN_C: f32 = 30.0;
+/// Rate (1/s) at which grains clear at the bottom of the window; doubles
+/// `GRAIN_COLD_SPAN_C` above it.
+pub const GRAIN_CLEAR_RATE_PER_S: f32 = 0.1;
+/// Grip lost with the whole tread covered in grains of `MAX_GRAIN_SIZE_UM`.
+pub const GRAINING_GRIP_PENALTY: f32 = 0.15;
+
+/// Surface graining: size of the torn rubber grains and the tread fraction
+/// they cover.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GrainState {
+    pub grain_size_um: f32,
+    pub coverage_fraction: f32,
+}
+
+/// Below the compound's window, sliding tears the cold rubber into grains:
+/// size and coverage rise towards their maxima at a rate proportional to
+/// coldness, slip and load. Inside or above the window the grains are worn
+/// off and both decay. Exact exponential updates; invalid `delta` leaves the
+/// state alone.
+pub fn graining_step(
+    state: &mut GrainState,
+    temp_c: f32,
+    slip_ratio: f32,
+    fz_n: f32,
+    compound: TireCompound,
+    delta: f32,
+) {
+    if !delta.is_finite() || delta <= 0.0 || !temp_c.is_finite() {
+        return;
+    }
+    let size = state.grain_size_um.clamp(0.0, MAX_GRAIN_SIZE_UM);
+    let coverage = state.coverage_fraction.clamp(0.0, 1.0);
+    let below = compound.optimal_temp_min - temp_c;
+    let (size, coverage) = if below > 0.0 {
+        let cold = (below / GRAIN_COLD_SPAN_C).min(1.0);
+        let slip = if slip_ratio.is_finite() {
+            slip_ratio.abs() / GRAIN_REFERENCE_SLIP
+        } else {
+            0.0
+        };
+        let load = if fz_n.is_finite() {
+            fz_n.max(0.0) / GRAIN_REFERENCE_LOAD_N
+        } else {
+            0.0
+        };
+        let keep = (-GRAIN_GROWTH_RATE_PER_S * cold * slip * load * delta).exp();
+        (
+            MAX_GRAIN_SIZE_UM - (MAX_GRAIN_SIZE_UM - size) * keep,
+            1.0 - (1.0 - coverage) * keep,
+        )
+    } else {
+        let warm = (-below / GRAIN_COLD_SPAN_C).min(1.0);
+        let keep = (-GRAIN_CLEAR_RATE_PER_S * (1.0 + warm) * delta).exp();
+        (size * keep, coverage * keep)
+    };
+    state.grain_size_um = size;
+    state.coverage_fraction = coverage;
+}
+
+/// Grip multiplier in `[1 - GRAINING_GRIP_PENALTY, 1]`.
+pub fn graining_grip_penalty(state: &GrainState) -> f32 {
+    let size = (state.grain_size_um / MAX_GRAIN_SIZE_UM).clamp(0.0, 1.0);
+    let coverage = state.coverage_fraction.clamp(0.0, 1.0);
+    let multiplier = 1.0 - GRAINING_GRIP_PENALTY * size * coverage;
+    if multiplier.is_finite() {
+        multiplier
+    } else {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cold_sliding_grains_and_warming_clears() {
+        let compound = TireCompound::MEDIUM;
+        let dt = 1.0 / 60.0;
+        let mut state = GrainState::default();
+        for _ in 0..(30.0 / dt) as usize {
+            graining_step(&mut state, 60.0, 0.12, 4500.0, compound, dt);
+        }
+        assert!(state.grain_size_um > 0.5 * MAX_GRAIN_SIZE_UM);
+        assert!(state.coverage_fraction > 0.5);
+        let grained = graining_grip_penalty(&state);
+        assert!((1.0 - GRAINING_GRIP_PENALTY..0.95).contains(&grained));
+
+        // Cold but not sliding: no growth.
+        let before = state;
+        graining_step(&mut state, 60.0, 0.0, 4500.0, compound, 1.0);
+        assert_eq!(state, before);
+
+        for _ in 0..(60.0 / dt) as usize {
+            graining_step(&mut state, 105.0, 0.05, 4500.0, compound, dt);
+        }
+        assert!(state.grain_size_um < 1.0);
+        assert!(graining_grip_penalty(&state) > 0.999);
+    }
+
+    #[test]
+    fn graining_ignores_invalid_delta() {
+        let mut state = GrainState {
+            grain_size_um: 50.0,
+            coverage_fraction: 0.3,
+        };
+        let before = state;
+        graining_step(&mut state, 40.0, 0.2, 4000.0, TireCompound::SOFT, f32::NAN);
+        graining_step(&mut state, 40.0, 0.2, 4000.0, TireCompound::SOFT, 0.0);
+        assert_eq!(state, before);
+        assert_eq!(graining_grip_penalty(&GrainState::default()), 1.0);
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::tire_state::TireState;
+
+/// Tyre state captured at each sector line, for strategy and post-session
+/// analysis without frame-by-frame history. Entries are in crossing order.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LapTimer {
+    pub sector_states: Vec<TireState>,
+    pub sector_distances_m: Vec<f32>,
+}
+
+impl LapTimer {
+    /// Records `current_state` at the sector line reached at `distance_m`.
+    pub fn mark_sector(&mut self, current_state: TireState, distance_m: f32) {
+        self.sector_states.push(current_state);
+        self.sector_distances_m.push(distance_m);
+    }
+
+    /// Wear gained between marks `from` and `to`; 0 if either index was never
+    /// marked.
+    pub fn wear_between_sectors(&self, from: usize, to: usize) -> f32 {
+        match (self.sector_states.get(from), self.sector_states.get(to)) {
+            (Some(a), Some(b)) => b.wear - a.wear,
+            _ => 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lap_timer_reports_wear_between_marks() {
+        let mut timer = LapTimer::default();
+        for (i, wear) in [0.0, 0.01, 0.025, 0.03].into_iter().enumerate() {
+            let state = TireState {
+                wear,
+                ..TireState::default()
+            };
+            timer.mark_sector(state, 1500.0 * i as f32);
+        }
+        assert_eq!(timer.sector_distances_m.len(), 4);
+        assert!((timer.wear_between_sectors(1, 3) - 0.02).abs() < 1.0e-6);
+        assert_eq!(timer.wear_between_sectors(0, 0), 0.0);
+        assert_eq!(timer.wear_between_sectors(2, 9), 0.0);
+    }
+}
@@ -1,8 +1,51 @@
 //! [CORE_RS] tire_core
 //! Deterministic Rust golden core for tire logic parity.
+//!
+//! Threading: the crate holds no global or hidden state. Every function reads
+//! its arguments and writes only through the `&mut` it is given, so calls on
+//! distinct state values may run concurrently from any thread (physics or
+//! main); sharing one state value across threads is arbitrated by the borrow
+//! checker, not at run time. The original stateless entry points
+//! (`normalize_weights`, `aggregate_patch`, `compute_effective_radius`) are
+//! unconditionally thread-safe. All public state types are `Send + Sync`; the
+//! one shared table, `surface::SurfaceRegistry`, is edited through `&mut` and
+//! read on other threads through its snapshots.
+pub mod axle;
+pub mod build_info;
+pub mod contact;
+pub mod contact_histogram;
+pub mod contamination;
 pub mod contract;
 pub mod conventions;
+pub mod deformation;
+pub mod diff;
+pub mod disturbance;
+pub mod drivetrain;
+pub mod estimator;
+pub mod events;
+pub mod failure;
+pub mod force_model;
+pub mod friction;
+pub mod graining;
+pub mod lap_timing;
+pub mod nvh;
+pub mod pacejka;
+pub mod packing;
+pub mod patch;
+pub mod presets;
+pub mod pressure;
+pub mod report;
+pub mod scenarios;
+pub mod setup;
+pub mod stiction;
+pub mod surface;
+pub mod suspension;
+pub mod thermal;
+pub mod timestep;
+pub mod tire_state;
 pub mod transients;
+pub mod vehicle;
+pub mod wear;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -273,6 +316,140 @@ impl Vec3 {
     pub fn dot(self, rhs: Self) -> f32 {
         self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
     }
+    pub fn cross(self, rhs: Self) -> Self {
+        Self {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+    pub fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+    /// Unsigned angle in `[0, pi]`. Uses `atan2(|a x b|, a . b)`, which stays
+    /// accurate near parallel and antiparallel where `acos` loses precision.
+    /// Returns 0 if either vector is (near) zero.
+    pub fn angle_between(self, other: Self) -> f32 {
+        if self.length() <= 1.0e-6 || other.length() <= 1.0e-6 {
+            return 0.0;
+        }
+        self.cross(other).length().atan2(self.dot(other))
+    }
+    pub fn normalized(self) -> Self {
+        let len = self.length();
+        if len <= 1.0e-6 {
+            Self::default()
+        } else {
+            Self {
+                x: self.x / len,
+                y: self.y / len,
+                z: self.z / len,
+            }
+        }
+    }
+}
+
+impl std::ops::Add for Vec3 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl std::ops::Sub for Vec3 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl std::ops::Mul<f32> for Vec3 {
+    type Output = Self;
+    fn mul(self, rhs: f32) -> Self {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+impl std::ops::Neg for Vec3 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+/// Row-major 3x3 matrix (e.g. a world-space inverse inertia tensor).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Mat3 {
+    pub rows: [Vec3; 3],
+}
+
+impl Mat3 {
+    /// Matrix from plain rows, usable in `const` tables.
+    pub const fn from_rows(rows: [[f32; 3]; 3]) -> Self {
+        const fn row(r: [f32; 3]) -> Vec3 {
+            Vec3 {
+                x: r[0],
+                y: r[1],
+                z: r[2],
+            }
+        }
+        Self {
+            rows: [row(rows[0]), row(rows[1]), row(rows[2])],
+        }
+    }
+    pub fn diagonal(d: Vec3) -> Self {
+        Self {
+            rows: [
+                Vec3 {
+                    x: d.x,
+                    ..Vec3::default()
+                },
+                Vec3 {
+                    y: d.y,
+                    ..Vec3::default()
+                },
+                Vec3 {
+                    z: d.z,
+                    ..Vec3::default()
+                },
+            ],
+        }
+    }
+    pub fn identity() -> Self {
+        Self::diagonal(Vec3 {
+            x: 1.0,
+            y: 1.0,
+            z: 1.0,
+        })
+    }
+}
+
+impl std::ops::Mul<Vec3> for Mat3 {
+    type Output = Vec3;
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        Vec3 {
+            x: self.rows[0].dot(rhs),
+            y: self.rows[1].dot(rhs),
+            z: self.rows[2].dot(rhs),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -452,7 +629,11 @@ pub fn step_wheel_mirror(
     };
 
     if patch.patch_confidence < config.confidence_min_for_contact && raycast_samples.is_empty() {
-        let t = (dt * config.emergency_fz_falloff_rate).clamp(0.0, 1.0);
+        let t = if dt.is_finite() {
+            (dt * config.emergency_fz_falloff_rate).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
         out.fz = previous_fz + (0.0 - previous_fz) * t;
         return out;
     }
@@ -627,4 +808,77 @@ mod tests {
         assert!(out.fz < 1000.0);
         assert!(out.fz >= 0.0);
     }
+
+    #[test]
+    fn angle_between_handles_parallel_and_antiparallel() {
+        let x = Vec3 {
+            x: 2.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        let y = Vec3 {
+            x: 0.0,
+            y: 0.5,
+            z: 0.0,
+        };
+        assert!((x.angle_between(y) - std::f32::consts::FRAC_PI_2).abs() < 1.0e-6);
+        assert_eq!(x.angle_between(x), 0.0);
+        assert!((x.angle_between(-x) - std::f32::consts::PI).abs() < 1.0e-6);
+
+        let nudged = Vec3 {
+            x: 1.0,
+            y: 1.0e-4,
+            z: 0.0,
+        };
+        assert!((x.angle_between(nudged) - 1.0e-4).abs() < 1.0e-7);
+        assert_eq!(x.angle_between(Vec3::default()), 0.0);
+    }
+
+    /// Compile-time check that per-tyre state can move to and be shared with
+    /// the physics thread.
+    #[test]
+    fn state_types_are_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<crate::tire_state::TireState>();
+        assert_send_sync::<crate::tire_state::TireFullState>();
+        assert_send_sync::<crate::wear::WearSegments>();
+        assert_send_sync::<crate::tire_state::TireSimParameters>();
+        assert_send_sync::<crate::contact::ContactAggregate>();
+        assert_send_sync::<crate::axle::AxleState>();
+        assert_send_sync::<crate::events::TireEventState>();
+        assert_send_sync::<crate::surface::SurfaceRegistry>();
+        assert_send_sync::<crate::surface::SurfaceSnapshot>();
+    }
+
+    #[test]
+    fn concurrent_steps_on_distinct_state_match_serial_steps() {
+        use crate::wear::{tire_wear_step, LapSample, WearStepInput, WearStepOutput};
+        let run = |seed: u32| {
+            let mut input = WearStepInput {
+                current_surface_temp_c: 25.0,
+                current_core_temp_c: 25.0,
+                sample: LapSample {
+                    load: 3000.0 + 100.0 * seed as f32,
+                    slip_ratio: 0.05,
+                    slip_angle: 0.02 * seed as f32,
+                    speed: 30.0,
+                    duration_s: 0.0,
+                },
+                delta: 1.0 / 120.0,
+                ..WearStepInput::default()
+            };
+            let mut out = WearStepOutput::default();
+            for _ in 0..2000 {
+                out = tire_wear_step(&input, None);
+                input = out.to_next_input(input);
+            }
+            out
+        };
+        let serial: Vec<_> = (0..8).map(run).collect();
+        let threads: Vec<_> = (0..8)
+            .map(|seed| std::thread::spawn(move || run(seed)))
+            .collect();
+        let parallel: Vec<_> = threads.into_iter().map(|t| t.join().unwrap()).collect();
+        assert_eq!(parallel, serial);
+    }
 }
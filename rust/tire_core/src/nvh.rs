@@ -0,0 +1,324 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::disturbance::hash_signed;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VibrationState {
+    pub amplitude: f32,
+    pub frequency_hz: f32,
+}
+
+/// Rumble-strip excitation: strips are crossed at `speed / spacing` Hz, each
+/// crossing adds `contact_impulse` and the amplitude decays at `decay_rate` (1/s).
+pub fn rumble_strip_vibration(
+    speed_m_per_s: f32,
+    strip_spacing_m: f32,
+    contact_impulse: f32,
+    decay_rate: f32,
+    state: &mut VibrationState,
+    delta: f32,
+) {
+    if !delta.is_finite() || delta <= 0.0 {
+        return;
+    }
+    let frequency_hz = if strip_spacing_m > 1.0e-4 && speed_m_per_s.is_finite() {
+        speed_m_per_s.abs() / strip_spacing_m
+    } else {
+        0.0
+    };
+    let impulse = if contact_impulse.is_finite() {
+        contact_impulse.max(0.0)
+    } else {
+        0.0
+    };
+    // Hits arrive at a steady rate within the step; integrate them against the
+    // decay exactly so the result does not depend on the tick rate.
+    let input_rate = impulse * frequency_hz;
+    let rate = decay_rate.max(0.0);
+    let decay = (-rate * delta).exp();
+    let added = if rate > 1.0e-6 {
+        input_rate / rate * (1.0 - decay)
+    } else {
+        input_rate * delta
+    };
+
+    state.frequency_hz = frequency_hz;
+    state.amplitude = (state.amplitude * decay + added).max(0.0);
+    if !state.amplitude.is_finite() {
+        state.amplitude = 0.0;
+    }
+}
+
+/// Wheel speed at which radial force variation has doubled over its low-speed
+/// value (about 200 km/h on a 0.33 m tyre).
+pub const RFV_HIGH_SPEED_OMEGA_RAD_PER_S: f32 = 170.0;
+/// Largest speed gain on radial force variation, reached at about
+/// `1.7 * RFV_HIGH_SPEED_OMEGA_RAD_PER_S`.
+pub const RFV_MAX_SPEED_GAIN: f32 = 4.0;
+/// Spread of per-tyre amplitudes around the nominal ones in `from_seed`.
+pub const RFV_AMPLITUDE_SPREAD: f32 = 0.5;
+
+const RFV_STREAM: u32 = 0x7266_7631;
+
+/// Radial force variation of one tyre. Amplitudes are fractions of the normal
+/// load; phases are wheel angles (rad) and are saved with the tyre so the
+/// shake carries on unchanged after a load.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NonUniformityParams {
+    pub first_harmonic_amplitude: f32,
+    pub second_harmonic_amplitude: f32,
+    pub first_harmonic_phase_rad: f32,
+    pub second_harmonic_phase_rad: f32,
+}
+
+impl NonUniformityParams {
+    /// Per-tyre manufacturing variance: amplitudes within
+    /// `±RFV_AMPLITUDE_SPREAD` of the nominal ones and uniform phases, all
+    /// drawn deterministically from `seed` (e.g. the tyre's serial number).
+    pub fn from_seed(seed: u32, nominal_first: f32, nominal_second: f32) -> Self {
+        let unit = |i: u32| 0.5 * (hash_signed(seed, RFV_STREAM, i) + 1.0);
+        let spread = |i: u32| 1.0 + RFV_AMPLITUDE_SPREAD * hash_signed(seed, RFV_STREAM, i);
+        Self {
+            first_harmonic_amplitude: nominal_first.max(0.0) * spread(0),
+            second_harmonic_amplitude: nominal_second.max(0.0) * spread(1),
+            first_harmonic_phase_rad: std::f32::consts::TAU * unit(2),
+            second_harmonic_phase_rad: std::f32::consts::TAU * unit(3),
+        }
+    }
+}
+
+/// Vertical force ripple (N) to add to the wheel's normal load:
+/// `load * g(omega) * (a1 cos(theta - p1) + a2 cos(2 theta - p2))`, where
+/// `g = 1 + (omega / RFV_HIGH_SPEED_OMEGA_RAD_PER_S)^2`, capped at
+/// `RFV_MAX_SPEED_GAIN`, grows the variation at speed. Zero for an airborne
+/// (unloaded) wheel.
+pub fn tire_nonuniformity_force(
+    wheel_angle_rad: f32,
+    omega_rad_per_s: f32,
+    normal_load: f32,
+    params: &NonUniformityParams,
+) -> f32 {
+    if !normal_load.is_finite() || normal_load <= 0.0 || !wheel_angle_rad.is_finite() {
+        return 0.0;
+    }
+    let speed = if omega_rad_per_s.is_finite() {
+        omega_rad_per_s / RFV_HIGH_SPEED_OMEGA_RAD_PER_S
+    } else {
+        0.0
+    };
+    let theta = wheel_angle_rad.rem_euclid(std::f32::consts::TAU);
+    let ripple = params.first_harmonic_amplitude * (theta - params.first_harmonic_phase_rad).cos()
+        + params.second_harmonic_amplitude * (2.0 * theta - params.second_harmonic_phase_rad).cos();
+    let gain = (1.0 + speed * speed).min(RFV_MAX_SPEED_GAIN);
+    let force = normal_load * gain * ripple;
+    if force.is_finite() {
+        force
+    } else {
+        0.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AudioParams {
+    pub roar_reference_speed: f32,
+    pub squeal_onset_slide_speed: f32,
+    pub squeal_full_slide_speed: f32,
+    pub squeal_base_hz: f32,
+    pub squeal_hz_per_rad: f32,
+    pub reference_load: f32,
+}
+
+impl Default for AudioParams {
+    fn default() -> Self {
+        Self {
+            roar_reference_speed: 30.0,
+            squeal_onset_slide_speed: 0.3,
+            squeal_full_slide_speed: 3.0,
+            squeal_base_hz: 700.0,
+            squeal_hz_per_rad: 2500.0,
+            reference_load: 4000.0,
+        }
+    }
+}
+
+/// Periodic excitation driving `thump_rate`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ThumpSource {
+    /// Kerb or rumble strip, as tracked by `rumble_strip_vibration`.
+    Kerb(VibrationState),
+    /// Flat-spotted tyre: one thump per revolution.
+    FlatSpot { rolling_radius_m: f32 },
+}
+
+/// Levels and brightness are normalized to `[0, 1]`; frequencies are in Hz.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TireAudio {
+    pub roar_level: f32,
+    pub roar_brightness: f32,
+    pub squeal_level: f32,
+    pub squeal_frequency: f32,
+    pub thump_rate: f32,
+}
+
+fn finite_or(v: f32, fallback: f32) -> f32 {
+    if v.is_finite() {
+        v
+    } else {
+        fallback
+    }
+}
+
+/// Per-wheel audio descriptors. Every output is a continuous function of the
+/// inputs (no thresholds), so smooth inputs give zipper-free parameter curves.
+///
+/// - Roar rises with speed and surface roughness (0 = glass, 1 = coarse chip).
+/// - Squeal fades in with sliding speed and load; its pitch rises with slip
+///   angle and falls with load.
+/// - `thump_rate` is 0 unless a periodic source is supplied.
+pub fn tire_audio_descriptors(
+    sliding_speed: f32,
+    slip_angle: f32,
+    normal_load: f32,
+    surface_roughness: f32,
+    speed: f32,
+    params: AudioParams,
+    thump: Option<ThumpSource>,
+) -> TireAudio {
+    let speed = finite_or(speed, 0.0).abs();
+    let roughness = finite_or(surface_roughness, 0.0).clamp(0.0, 1.0);
+    let load_ref = params.reference_load.max(1.0);
+    let load = finite_or(normal_load, 0.0).max(0.0) / load_ref;
+
+    let speed_n = speed / params.roar_reference_speed.max(1.0e-3);
+    let roar_level = (speed_n * (0.25 + 0.75 * roughness)).tanh();
+    let roar_brightness = 0.5 * roughness + 0.5 * speed_n.tanh();
+
+    let slide = finite_or(sliding_speed, 0.0).abs();
+    let span = (params.squeal_full_slide_speed - params.squeal_onset_slide_speed).max(1.0e-3);
+    let t = ((slide - params.squeal_onset_slide_speed) / span).clamp(0.0, 1.0);
+    let squeal_level = t * t * (3.0 - 2.0 * t) * load.tanh() * (1.0 - 0.5 * roughness);
+    let squeal_frequency = (params.squeal_base_hz
+        + params.squeal_hz_per_rad * finite_or(slip_angle, 0.0).abs())
+        / load.max(0.1).sqrt();
+
+    let thump_rate = match thump {
+        Some(ThumpSource::Kerb(state)) => finite_or(state.frequency_hz, 0.0).max(0.0),
+        Some(ThumpSource::FlatSpot { rolling_radius_m }) if rolling_radius_m > 1.0e-3 => {
+            speed / (std::f32::consts::TAU * rolling_radius_m)
+        }
+        _ => 0.0,
+    };
+
+    TireAudio {
+        roar_level,
+        roar_brightness,
+        squeal_level,
+        squeal_frequency: finite_or(squeal_frequency, params.squeal_base_hz),
+        thump_rate: finite_or(thump_rate, 0.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rumble_frequency_follows_speed_and_amplitude_decays() {
+        let mut state = VibrationState::default();
+        for _ in 0..60 {
+            rumble_strip_vibration(30.0, 0.5, 0.2, 8.0, &mut state, 1.0 / 60.0);
+        }
+        assert!((state.frequency_hz - 60.0).abs() < 1.0e-4);
+        let on_strip = state.amplitude;
+        assert!(on_strip > 0.0);
+
+        for _ in 0..60 {
+            rumble_strip_vibration(30.0, 0.5, 0.0, 8.0, &mut state, 1.0 / 60.0);
+        }
+        assert!(state.amplitude < on_strip * 0.01);
+    }
+
+    #[test]
+    fn audio_descriptors_are_normalized_and_continuous() {
+        let params = AudioParams::default();
+        let a = tire_audio_descriptors(2.0, 0.12, 4500.0, 0.6, 25.0, params, None);
+        let b = tire_audio_descriptors(2.01, 0.1205, 4510.0, 0.6, 25.05, params, None);
+        for (x, y) in [
+            (a.roar_level, b.roar_level),
+            (a.roar_brightness, b.roar_brightness),
+            (a.squeal_level, b.squeal_level),
+        ] {
+            assert!((0.0..=1.0).contains(&x));
+            assert!((x - y).abs() < 1.0e-2);
+        }
+        assert!((a.squeal_frequency - b.squeal_frequency).abs() < 5.0);
+        assert_eq!(a.thump_rate, 0.0);
+
+        let gripping = tire_audio_descriptors(0.0, 0.0, 4500.0, 0.6, 25.0, params, None);
+        assert_eq!(gripping.squeal_level, 0.0);
+    }
+
+    #[test]
+    fn thump_rate_follows_supplied_periodicity() {
+        let params = AudioParams::default();
+        let flat = ThumpSource::FlatSpot {
+            rolling_radius_m: 0.3,
+        };
+        let audio = tire_audio_descriptors(0.0, 0.0, 4000.0, 0.2, 18.85, params, Some(flat));
+        assert!((audio.thump_rate - 10.0).abs() < 1.0e-2);
+
+        let kerb = ThumpSource::Kerb(VibrationState {
+            amplitude: 0.5,
+            frequency_hz: 42.0,
+        });
+        let audio = tire_audio_descriptors(0.0, 0.0, 4000.0, 0.2, 18.0, params, Some(kerb));
+        assert_eq!(audio.thump_rate, 42.0);
+    }
+
+    #[test]
+    fn nonuniformity_scales_with_load_and_vanishes_airborne() {
+        let params = NonUniformityParams {
+            first_harmonic_amplitude: 0.01,
+            second_harmonic_amplitude: 0.004,
+            first_harmonic_phase_rad: 0.7,
+            second_harmonic_phase_rad: 2.1,
+        };
+        let at = |load: f32| tire_nonuniformity_force(1.3, 20.0, load, &params);
+        assert!((at(8000.0) - 2.0 * at(4000.0)).abs() < 1.0e-3);
+        assert_eq!(at(0.0), 0.0);
+        // Peak of the first harmonic at its phase, once per revolution.
+        let first_only = NonUniformityParams {
+            second_harmonic_amplitude: 0.0,
+            ..params
+        };
+        let peak = tire_nonuniformity_force(0.7, 0.0, 4000.0, &first_only);
+        assert!((peak - 40.0).abs() < 1.0e-3);
+        let next_rev =
+            tire_nonuniformity_force(0.7 + std::f32::consts::TAU, 0.0, 4000.0, &first_only);
+        assert!((next_rev - peak).abs() < 1.0e-3);
+        assert!(tire_nonuniformity_force(0.7, 170.0, 4000.0, &first_only) > 1.99 * peak);
+        // The speed gain saturates instead of growing without bound.
+        for omega in [1000.0, 1.0e6, f32::MAX] {
+            let fast = tire_nonuniformity_force(0.7, omega, 4000.0, &first_only);
+            assert!((fast - RFV_MAX_SPEED_GAIN * peak).abs() < 1.0e-2, "{omega}");
+        }
+    }
+
+    #[test]
+    fn seeded_nonuniformity_differs_per_tyre_and_is_repeatable() {
+        let a = NonUniformityParams::from_seed(11, 0.01, 0.004);
+        let b = NonUniformityParams::from_seed(12, 0.01, 0.004);
+        assert_eq!(a, NonUniformityParams::from_seed(11, 0.01, 0.004));
+        assert_ne!(a, b);
+        for p in [a, b] {
+            assert!((0.005..=0.015).contains(&p.first_harmonic_amplitude));
+            assert!((0.0..std::f32::consts::TAU).contains(&p.second_harmonic_phase_rad));
+        }
+    }
+}
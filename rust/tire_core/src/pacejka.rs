@@ -0,0 +1,287 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const GOLDEN_RATIO_INV: f32 = 0.618_034;
+const MAX_SEARCH_ITERATIONS: u32 = 50;
+const SLIP_SEARCH_MAX: f32 = 0.5;
+const PEAK_SAMPLE_TOLERANCE: f32 = 1.0e-5;
+
+/// Simplified Magic Formula coefficients: `b` stiffness, `c` shape, `mu` peak
+/// friction (so `D = mu * fz`), `e` curvature.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PacejkaCoeffs {
+    pub b: f32,
+    pub c: f32,
+    pub mu: f32,
+    pub e: f32,
+}
+
+impl Default for PacejkaCoeffs {
+    fn default() -> Self {
+        Self {
+            b: 10.0,
+            c: 1.9,
+            mu: 1.0,
+            e: 0.97,
+        }
+    }
+}
+
+/// `fx = mu * fz * sin(c * atan(b*s - e*(b*s - atan(b*s))))`.
+pub fn pacejka_fx(coeffs: &PacejkaCoeffs, slip_ratio: f32, fz_n: f32) -> f32 {
+    let bs = coeffs.b * slip_ratio;
+    let phi = bs - coeffs.e * (bs - bs.atan());
+    let fx = coeffs.mu * fz_n.max(0.0) * (coeffs.c * phi.atan()).sin();
+    if fx.is_finite() {
+        fx
+    } else {
+        0.0
+    }
+}
+
+/// Slip ratio in `[0, 0.5]` that maximises `pacejka_fx`, by golden-section search
+/// (at most 50 iterations). Returns 0 when the tyre is unloaded.
+pub fn peak_slip_ratio(coeffs: &PacejkaCoeffs, fz_n: f32, tolerance: f32) -> f32 {
+    if !fz_n.is_finite() || fz_n <= 0.0 {
+        return 0.0;
+    }
+    let tolerance = if tolerance.is_finite() {
+        tolerance.max(1.0e-6)
+    } else {
+        1.0e-6
+    };
+
+    let (mut lo, mut hi) = (0.0_f32, SLIP_SEARCH_MAX);
+    let mut x1 = hi - GOLDEN_RATIO_INV * (hi - lo);
+    let mut x2 = lo + GOLDEN_RATIO_INV * (hi - lo);
+    let mut f1 = pacejka_fx(coeffs, x1, fz_n);
+    let mut f2 = pacejka_fx(coeffs, x2, fz_n);
+    for _ in 0..MAX_SEARCH_ITERATIONS {
+        if hi - lo <= tolerance {
+            break;
+        }
+        if f1 < f2 {
+            lo = x1;
+            x1 = x2;
+            f1 = f2;
+            x2 = lo + GOLDEN_RATIO_INV * (hi - lo);
+            f2 = pacejka_fx(coeffs, x2, fz_n);
+        } else {
+            hi = x2;
+            x2 = x1;
+            f2 = f1;
+            x1 = hi - GOLDEN_RATIO_INV * (hi - lo);
+            f1 = pacejka_fx(coeffs, x1, fz_n);
+        }
+    }
+    0.5 * (lo + hi)
+}
+
+/// Lateral force from the same curve with the slip angle (rad) as input:
+/// `fy = mu * fz * sin(c * atan(b*a - e*(b*a - atan(b*a))))`, positive for
+/// positive slip angle (flip for ISO 8855 at the call site).
+pub fn pacejka_fy(coeffs: &PacejkaCoeffs, slip_angle_rad: f32, fz_n: f32) -> f32 {
+    pacejka_fx(coeffs, slip_angle_rad, fz_n)
+}
+
+/// `sample_lateral_curve_into` into a new vector of `n` points; a range
+/// given as `(max, min)` is used as is, giving a descending sweep.
+pub fn sample_lateral_curve(
+    coeffs: &PacejkaCoeffs,
+    fz_n: f32,
+    alpha_range_rad: (f32, f32),
+    n: usize,
+) -> Vec<(f32, f32)> {
+    let mut out = vec![(0.0, 0.0); n];
+    sample_lateral_curve_into(coeffs, fz_n, alpha_range_rad.0, alpha_range_rad.1, &mut out);
+    out
+}
+
+/// Fills `out` with `(slip_angle, pacejka_fy)` pairs from `alpha_min` to
+/// `alpha_max` inclusive (a single slot takes `alpha_min`). Each force peak
+/// (`±peak_slip_ratio`) strictly inside the range takes one slot as an extra
+/// point, inserted in sweep order; the remaining slots (at least two) stay
+/// evenly spaced over the whole range. Non-finite bounds leave `out` zeroed.
+pub fn sample_lateral_curve_into(
+    coeffs: &PacejkaCoeffs,
+    fz_n: f32,
+    alpha_min: f32,
+    alpha_max: f32,
+    out: &mut [(f32, f32)],
+) {
+    if !alpha_min.is_finite() || !alpha_max.is_finite() {
+        out.fill((0.0, 0.0));
+        return;
+    }
+    let (lo, hi) = (alpha_min.min(alpha_max), alpha_min.max(alpha_max));
+    let peak = peak_slip_ratio(coeffs, fz_n, PEAK_SAMPLE_TOLERANCE);
+    let mut peaks = [-peak, peak];
+    if alpha_max < alpha_min {
+        peaks.reverse();
+    }
+    let inside = |alpha: &f32| peak > 0.0 && lo < *alpha && *alpha < hi;
+    let extra = peaks.iter().filter(|a| inside(a)).count();
+    let extra = if out.len() >= 2 + extra { extra } else { 0 };
+
+    let grid = out.len() - extra;
+    let last = grid.saturating_sub(1).max(1) as f32;
+    let step = (alpha_max - alpha_min) / last;
+    for (i, slot) in out[..grid].iter_mut().enumerate() {
+        let alpha = alpha_min + step * i as f32;
+        *slot = (alpha, pacejka_fy(coeffs, alpha, fz_n));
+    }
+    if extra == 0 {
+        return;
+    }
+    for (filled, alpha) in (grid..).zip(peaks.into_iter().filter(inside)) {
+        let ahead = |sample: &(f32, f32)| (sample.0 - alpha) * step > 0.0;
+        let at = out[..filled].iter().position(ahead).unwrap_or(filled);
+        out[at..=filled].rotate_right(1);
+        out[at] = (alpha, pacejka_fy(coeffs, alpha, fz_n));
+    }
+}
+
+/// `d/dx` of the Magic Formula at `x`.
+fn magic_formula_slope(coeffs: &PacejkaCoeffs, x: f32, fz_n: f32) -> f32 {
+    let bx = coeffs.b * x;
+    let phi = bx - coeffs.e * (bx - bx.atan());
+    let dphi = coeffs.b * (1.0 - coeffs.e + coeffs.e / (1.0 + bx * bx));
+    let slope = coeffs.mu * fz_n.max(0.0) * (coeffs.c * phi.atan()).cos() * coeffs.c
+        / (1.0 + phi * phi)
+        * dphi;
+    if slope.is_finite() {
+        slope
+    } else {
+        0.0
+    }
+}
+
+/// Analytic Jacobian `(dFx/dkappa, dFx/dalpha, dFy/dkappa, dFy/dalpha)` of
+/// `pacejka_fx` and `pacejka_fy`. The model is pure-slip, so the cross terms
+/// are zero.
+pub fn pacejka_force_jacobian(
+    coeffs: &PacejkaCoeffs,
+    slip_ratio: f32,
+    slip_angle_rad: f32,
+    fz: f32,
+) -> (f32, f32, f32, f32) {
+    (
+        magic_formula_slope(coeffs, slip_ratio, fz),
+        0.0,
+        0.0,
+        magic_formula_slope(coeffs, slip_angle_rad, fz),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peak_slip_matches_analytical_optimum() {
+        // With e = 0 the peak sits where c * atan(b*s) = pi/2.
+        let coeffs = PacejkaCoeffs {
+            e: 0.0,
+            ..PacejkaCoeffs::default()
+        };
+        let tolerance = 1.0e-4;
+        let expected = (std::f32::consts::FRAC_PI_2 / coeffs.c).tan() / coeffs.b;
+        let found = peak_slip_ratio(&coeffs, 4000.0, tolerance);
+        assert!(
+            (found - expected).abs() <= tolerance,
+            "{found} vs {expected}"
+        );
+    }
+
+    #[test]
+    fn peak_slip_matches_gradient_zero_with_curvature() {
+        // Peak where the inner argument reaches tan(pi / 2c); it is monotonic in
+        // slip for e < 1, so bisect for the crossing.
+        let coeffs = PacejkaCoeffs::default();
+        let target = (std::f32::consts::FRAC_PI_2 / coeffs.c).tan();
+        let phi = |s: f32| {
+            let bs = coeffs.b * s;
+            bs - coeffs.e * (bs - bs.atan())
+        };
+        let (mut lo, mut hi) = (0.0_f32, 0.5_f32);
+        for _ in 0..60 {
+            let mid = 0.5 * (lo + hi);
+            if phi(mid) < target {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let tolerance = 1.0e-4;
+        let found = peak_slip_ratio(&coeffs, 4000.0, tolerance);
+        assert!((found - lo).abs() <= tolerance, "{found} vs {lo}");
+        assert_eq!(peak_slip_ratio(&coeffs, 0.0, tolerance), 0.0);
+    }
+
+    #[test]
+    fn jacobian_matches_finite_differences() {
+        let coeffs = PacejkaCoeffs::default();
+        let fz = 4000.0;
+        let h = 1.0e-3;
+        for (kappa, alpha) in [(0.02, 0.01), (0.08, 0.05), (0.15, 0.12), (-0.05, -0.03)] {
+            let (dfx_dk, dfx_da, dfy_dk, dfy_da) =
+                pacejka_force_jacobian(&coeffs, kappa, alpha, fz);
+            let fd_x = (pacejka_fx(&coeffs, kappa + h, fz) - pacejka_fx(&coeffs, kappa - h, fz))
+                / (2.0 * h);
+            let fd_y = (pacejka_fy(&coeffs, alpha + h, fz) - pacejka_fy(&coeffs, alpha - h, fz))
+                / (2.0 * h);
+            for (analytic, numeric) in [(dfx_dk, fd_x), (dfy_da, fd_y)] {
+                let scale = numeric.abs().max(0.01 * coeffs.b * fz);
+                assert!(
+                    (analytic - numeric).abs() / scale < 0.01,
+                    "{analytic} vs {numeric} at ({kappa}, {alpha})"
+                );
+            }
+            assert_eq!((dfx_da, dfy_dk), (0.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn lateral_curve_is_evenly_spaced_and_hits_the_peak() {
+        let coeffs = PacejkaCoeffs::default();
+        let fz = 4000.0;
+        let curve = sample_lateral_curve(&coeffs, fz, (-0.3, 0.3), 61);
+        assert_eq!(curve.len(), 61);
+        assert!((curve[0].0 + 0.3).abs() < 1.0e-6);
+        assert!((curve[60].0 - 0.3).abs() < 1.0e-6);
+        assert!(curve.windows(2).all(|w| w[0].0 < w[1].0));
+
+        // Both peaks are extra points; the other 59 stay evenly spaced.
+        let peak = peak_slip_ratio(&coeffs, fz, PEAK_SAMPLE_TOLERANCE);
+        assert!(curve.iter().any(|p| p.0 == peak) && curve.iter().any(|p| p.0 == -peak));
+        let grid: Vec<f32> = curve
+            .iter()
+            .map(|p| p.0)
+            .filter(|&a| a.abs() != peak)
+            .collect();
+        assert_eq!(grid.len(), 59);
+        let step = 0.6 / 58.0;
+        assert!(grid.windows(2).all(|w| (w[1] - w[0] - step).abs() < 1.0e-5));
+
+        // A descending sweep inserts the peaks in its own order.
+        let descending = sample_lateral_curve(&coeffs, fz, (0.3, -0.3), 61);
+        assert!(descending.windows(2).all(|w| w[0].0 > w[1].0));
+        let mut reversed = descending.clone();
+        reversed.reverse();
+        assert!(reversed
+            .iter()
+            .zip(&curve)
+            .all(|(a, b)| (a.0 - b.0).abs() < 1.0e-5));
+        let peak = curve.iter().map(|p| p.1).fold(f32::MIN, f32::max);
+        assert!((peak - coeffs.mu * fz).abs() < 1.0e-3 * fz, "{peak}");
+        let trough = curve.iter().map(|p| p.1).fold(f32::MAX, f32::min);
+        assert!((trough + coeffs.mu * fz).abs() < 1.0e-3 * fz, "{trough}");
+
+        let mut buffer = [(0.0, 0.0); 61];
+        sample_lateral_curve_into(&coeffs, fz, -0.3, 0.3, &mut buffer);
+        assert_eq!(buffer.to_vec(), curve);
+        assert!(sample_lateral_curve(&coeffs, fz, (0.0, 0.1), 0).is_empty());
+        assert_eq!(sample_lateral_curve(&coeffs, fz, (0.05, 0.1), 1)[0].0, 0.05);
+    }
+}
@@ -0,0 +1,207 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::tire_state::TireState;
+
+/// Quantization of one field: `bits` wide (1..=32) over `[min, max]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FieldSpec {
+    pub bits: u8,
+    pub min: f32,
+    pub max: f32,
+}
+
+impl FieldSpec {
+    fn steps(self) -> u64 {
+        (1_u64 << self.bits) - 1
+    }
+
+    fn is_valid(self) -> bool {
+        (1..=32).contains(&self.bits)
+            && self.min.is_finite()
+            && self.max.is_finite()
+            && self.max > self.min
+    }
+
+    /// Largest round-trip error for values inside the range: half a quantization step.
+    pub fn max_error(self) -> f32 {
+        0.5 * (self.max - self.min) / self.steps() as f32
+    }
+
+    /// Clamp into range, scale to `[0, 2^bits - 1]` and round half up
+    /// (`floor(x + 0.5)`). NaN encodes as `min`.
+    fn quantize(self, value: f32) -> u32 {
+        let v = if value.is_nan() {
+            self.min
+        } else {
+            value.clamp(self.min, self.max)
+        };
+        let scaled = (v as f64 - self.min as f64) / (self.max as f64 - self.min as f64)
+            * self.steps() as f64;
+        (scaled + 0.5).floor().min(self.steps() as f64) as u32
+    }
+
+    fn dequantize(self, q: u32) -> f32 {
+        (self.min as f64 + q as f64 / self.steps() as f64 * (self.max as f64 - self.min as f64))
+            as f32
+    }
+}
+
+/// Bit layout for `TireState`, packed in field order surface, core, wear.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PackingSpec {
+    pub surface_temp_c: FieldSpec,
+    pub core_temp_c: FieldSpec,
+    pub wear: FieldSpec,
+}
+
+impl Default for PackingSpec {
+    /// 36 bits (5 bytes): temperatures to ±0.05 °C over -40..300 °C, wear to ±0.00013.
+    fn default() -> Self {
+        let temperature = FieldSpec {
+            bits: 12,
+            min: -40.0,
+            max: 300.0,
+        };
+        Self {
+            surface_temp_c: temperature,
+            core_temp_c: temperature,
+            wear: FieldSpec {
+                bits: 12,
+                min: 0.0,
+                max: 1.0,
+            },
+        }
+    }
+}
+
+impl PackingSpec {
+    fn fields(&self) -> [FieldSpec; 3] {
+        [self.surface_temp_c, self.core_temp_c, self.wear]
+    }
+
+    fn is_valid(&self) -> bool {
+        self.fields().iter().all(|f| f.is_valid())
+    }
+
+    /// Bytes needed for one packed state.
+    pub fn packed_len(&self) -> usize {
+        let bits: usize = self.fields().iter().map(|f| f.bits as usize).sum();
+        bits.div_ceil(8)
+    }
+}
+
+/// Packs `state` into `out_buf`, least significant bit first (little-endian bit
+/// and byte order), unused high bits of the last byte zeroed. Returns the bytes
+/// written, or `None` if the spec is invalid or the buffer too short.
+pub fn tire_state_pack(state: &TireState, spec: &PackingSpec, out_buf: &mut [u8]) -> Option<usize> {
+    if !spec.is_valid() {
+        return None;
+    }
+    let len = spec.packed_len();
+    let out = out_buf.get_mut(..len)?;
+    out.fill(0);
+
+    let values = [state.surface_temp_c, state.core_temp_c, state.wear];
+    let mut bit = 0_usize;
+    for (field, value) in spec.fields().into_iter().zip(values) {
+        let q = field.quantize(value) as u64;
+        for i in 0..field.bits as usize {
+            if (q >> i) & 1 == 1 {
+                out[(bit + i) / 8] |= 1 << ((bit + i) % 8);
+            }
+        }
+        bit += field.bits as usize;
+    }
+    Some(len)
+}
+
+/// Inverse of `tire_state_pack` for the same spec.
+pub fn tire_state_unpack(buf: &[u8], spec: &PackingSpec) -> Option<TireState> {
+    if !spec.is_valid() {
+        return None;
+    }
+    let input = buf.get(..spec.packed_len())?;
+
+    let mut values = [0.0_f32; 3];
+    let mut bit = 0_usize;
+    for (field, value) in spec.fields().into_iter().zip(values.iter_mut()) {
+        let mut q = 0_u32;
+        for i in 0..field.bits as usize {
+            if (input[(bit + i) / 8] >> ((bit + i) % 8)) & 1 == 1 {
+                q |= 1 << i;
+            }
+        }
+        *value = field.dequantize(q);
+        bit += field.bits as usize;
+    }
+    Some(TireState {
+        surface_temp_c: values[0],
+        core_temp_c: values[1],
+        wear: values[2],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_spec_round_trips_within_guarantee() {
+        let spec = PackingSpec::default();
+        assert!(spec.packed_len() < 16);
+        let mut buf = [0_u8; 16];
+        for i in 0..500 {
+            let t = i as f32 / 499.0;
+            let state = TireState {
+                surface_temp_c: -40.0 + 340.0 * t,
+                core_temp_c: 300.0 - 337.3 * t,
+                wear: (t * 7.3).fract(),
+            };
+            let len = tire_state_pack(&state, &spec, &mut buf).unwrap();
+            let back = tire_state_unpack(&buf[..len], &spec).unwrap();
+            let tol = 1.0e-5;
+            assert!(
+                (back.surface_temp_c - state.surface_temp_c).abs()
+                    <= spec.surface_temp_c.max_error() + tol
+            );
+            assert!(
+                (back.core_temp_c - state.core_temp_c).abs() <= spec.core_temp_c.max_error() + tol
+            );
+            assert!((back.wear - state.wear).abs() <= spec.wear.max_error() + tol);
+        }
+    }
+
+    #[test]
+    fn packing_is_little_endian_and_rejects_short_buffers() {
+        let spec = PackingSpec {
+            surface_temp_c: FieldSpec {
+                bits: 8,
+                min: 0.0,
+                max: 255.0,
+            },
+            core_temp_c: FieldSpec {
+                bits: 4,
+                min: 0.0,
+                max: 15.0,
+            },
+            wear: FieldSpec {
+                bits: 4,
+                min: 0.0,
+                max: 15.0,
+            },
+        };
+        let state = TireState {
+            surface_temp_c: 0x12 as f32,
+            core_temp_c: 0x4 as f32,
+            wear: 0x3 as f32,
+        };
+        let mut buf = [0_u8; 2];
+        assert_eq!(tire_state_pack(&state, &spec, &mut buf), Some(2));
+        assert_eq!(buf, [0x12, 0x34]);
+        assert_eq!(tire_state_pack(&state, &spec, &mut buf[..1]), None);
+        assert_eq!(tire_state_unpack(&buf[..1], &spec), None);
+    }
+}
@@ -0,0 +1,615 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::contact::ContactAggregate;
+use crate::tire_state::TireDefinition;
+use crate::Vec3;
+
+/// Lateral shift of the load centre per unit of `tan(camber)`, as a fraction of the patch width.
+const CAMBER_SHIFT_PER_TAN: f32 = 2.0;
+/// Gaussian spread across the tread, as a fraction of the patch width.
+const WIDTH_SIGMA_FRACTION: f32 = 0.25;
+
+/// Load carried by each of `N` strips across the tread width (strip 0 at `-width/2`).
+/// Positive camber moves the load centre towards strip 0. Values sum to
+/// `aggregate.total_force.length()`.
+pub fn pressure_distribution_across_width<const N: usize>(
+    aggregate: &ContactAggregate,
+    camber_rad: f32,
+    contact_width_m: f32,
+) -> [f32; N] {
+    let mut out = [0.0_f32; N];
+    if N == 0 {
+        return out;
+    }
+    let total = aggregate.total_force.length();
+    if !total.is_finite() || total <= 0.0 {
+        return out;
+    }
+    if !contact_width_m.is_finite() || contact_width_m <= 1.0e-6 || !camber_rad.is_finite() {
+        out.fill(total / N as f32);
+        return out;
+    }
+
+    let half_width = 0.5 * contact_width_m;
+    let center =
+        (-camber_rad.tan() * contact_width_m * CAMBER_SHIFT_PER_TAN).clamp(-half_width, half_width);
+    let sigma = contact_width_m * WIDTH_SIGMA_FRACTION;
+    let strip = contact_width_m / N as f32;
+
+    let mut sum = 0.0;
+    for (i, v) in out.iter_mut().enumerate() {
+        let y = -half_width + (i as f32 + 0.5) * strip;
+        let d = (y - center) / sigma;
+        *v = (-0.5 * d * d).exp();
+        sum += *v;
+    }
+    for v in out.iter_mut() {
+        *v = *v / sum * total;
+    }
+    out
+}
+
+/// Normal variance above which a patch should be down-weighted in force integration.
+pub const NORMAL_VARIANCE_UNRELIABLE: f32 = 0.1;
+
+/// Spread of contact normals: the variance of `n_i . n_mean` over the unit
+/// normals, where `n_mean` is their normalized sum. Zero when all normals
+/// agree (flat ground), growing as they diverge; 1 when they cancel out and
+/// leave no mean direction. Zero-length normals are skipped.
+pub fn contact_normal_variance(normals: &[Vec3]) -> f32 {
+    let mut sum = Vec3::default();
+    let mut count = 0.0;
+    for n in normals {
+        let n = n.normalized();
+        if n == Vec3::default() {
+            continue;
+        }
+        sum.x += n.x;
+        sum.y += n.y;
+        sum.z += n.z;
+        count += 1.0;
+    }
+    if count == 0.0 {
+        return 0.0;
+    }
+    let mean = sum.normalized();
+    if mean == Vec3::default() {
+        // Opposing normals cancel out: no usable mean direction.
+        return 1.0;
+    }
+
+    let dots = normals
+        .iter()
+        .map(|n| n.normalized())
+        .filter(|&n| n != Vec3::default())
+        .map(|n| n.dot(mean));
+    let mean_dot = dots.clone().sum::<f32>() / count;
+    dots.map(|d| (d - mean_dot) * (d - mean_dot)).sum::<f32>() / count
+}
+
+/// Fraction of the flat-road contact length left on a curved road. The tyre
+/// deflection is recovered from `nominal_length_m` on flat ground and the chord
+/// is recomputed for the same deflection against a road circle of
+/// `road_radius_m` (positive = crest, negative = valley, 0 or non-finite =
+/// flat). Below 1 on crests, above 1 in valleys, capped at the tyre diameter.
+/// Multiply `contact_area` by it.
+pub fn curvature_contact_length_factor(
+    nominal_length_m: f32,
+    road_radius_m: f32,
+    tire_radius_m: f32,
+) -> f32 {
+    if !nominal_length_m.is_finite()
+        || !tire_radius_m.is_finite()
+        || nominal_length_m <= 1.0e-6
+        || tire_radius_m <= 1.0e-6
+        || !road_radius_m.is_finite()
+        || road_radius_m == 0.0
+    {
+        return 1.0;
+    }
+    let r = tire_radius_m;
+    let half = 0.5 * nominal_length_m;
+    if half >= r {
+        return 1.0;
+    }
+    let deflection = r - (r * r - half * half).sqrt();
+    let max_factor = 2.0 * r / nominal_length_m;
+
+    let road = road_radius_m.abs();
+    // Offset of the centre distance from the road radius, kept separate so the
+    // subtraction stays exact for very gentle curvature.
+    let offset = if road_radius_m > 0.0 {
+        r - deflection
+    } else {
+        deflection - r
+    };
+    let centre_distance = road + offset;
+    if centre_distance <= 1.0e-6 {
+        return max_factor;
+    }
+    let a = (offset * (centre_distance + road) + r * r) / (2.0 * centre_distance);
+    let chord_half_sq = r * r - a * a;
+    if !chord_half_sq.is_finite() || chord_half_sq <= 0.0 {
+        return max_factor;
+    }
+    (chord_half_sq.sqrt() / half).min(max_factor)
+}
+
+/// Length/width of the patch once rolling, at `PATCH_REFERENCE_INFLATION_KPA`
+/// and `PATCH_REFERENCE_RADIUS_M`.
+pub const PATCH_ROLLING_ASPECT_RATIO: f32 = 1.6;
+/// Rolling speed at which `1 - 1/e` of the elongation has developed.
+pub const PATCH_ELONGATION_SPEED_M_PER_S: f32 = 5.0;
+pub const PATCH_REFERENCE_INFLATION_KPA: f32 = 220.0;
+pub const PATCH_REFERENCE_RADIUS_M: f32 = 0.33;
+
+/// Contact patch `length / width`: 1 (round) at rest, approaching the rolling
+/// shape as `max(|speed|, |omega * radius|)` grows. Softer tyres and larger
+/// radii elongate more (elongation scales with `reference / inflation`, clamped
+/// to `[0.5, 2]`, and `sqrt(radius / reference)`). Invalid input gives 1.
+pub fn contact_patch_aspect_ratio(
+    speed_m_per_s: f32,
+    omega_rad_per_s: f32,
+    tire_radius_m: f32,
+    inflation_kpa: f32,
+) -> f32 {
+    let rolling = speed_m_per_s
+        .abs()
+        .max((omega_rad_per_s * tire_radius_m).abs());
+    if !rolling.is_finite()
+        || !inflation_kpa.is_finite()
+        || inflation_kpa <= 0.0
+        || !tire_radius_m.is_finite()
+        || tire_radius_m <= 0.0
+    {
+        return 1.0;
+    }
+    let inflation = (PATCH_REFERENCE_INFLATION_KPA / inflation_kpa).clamp(0.5, 2.0);
+    let size = (tire_radius_m / PATCH_REFERENCE_RADIUS_M).sqrt();
+    let elongation = (PATCH_ROLLING_ASPECT_RATIO - 1.0) * inflation * size;
+    let developed = 1.0 - (-rolling / PATCH_ELONGATION_SPEED_M_PER_S).exp();
+    1.0 + elongation * developed
+}
+
+/// `(length, width)` of an elliptical patch of `area_m2` with the given
+/// `length / width`. Zero for a non-positive area; an invalid ratio is taken as 1.
+pub fn contact_ellipse(area_m2: f32, aspect_ratio: f32) -> (f32, f32) {
+    if !area_m2.is_finite() || area_m2 <= 0.0 {
+        return (0.0, 0.0);
+    }
+    let ratio = if aspect_ratio.is_finite() && aspect_ratio > 0.0 {
+        aspect_ratio
+    } else {
+        1.0
+    };
+    let width = (4.0 * area_m2 / (std::f32::consts::PI * ratio)).sqrt();
+    (ratio * width, width)
+}
+
+/// Centrifugal stiffening of the tread belt at speed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CentrifugalParams {
+    /// Tread and belt mass per metre of circumference (kg/m).
+    pub tread_mass_per_m: f32,
+    /// Belt tension (N) that doubles the vertical stiffness.
+    pub belt_stiffness: f32,
+    /// Lowest fraction of the static patch area the correction may leave.
+    pub min_area_fraction: f32,
+}
+
+impl Default for CentrifugalParams {
+    fn default() -> Self {
+        Self {
+            tread_mass_per_m: 3.0,
+            belt_stiffness: 1.0e5,
+            min_area_fraction: 0.7,
+        }
+    }
+}
+
+/// Vertical stiffness multiplier `1 + m' (omega r)^2 / belt_stiffness` from the
+/// centrifugal belt tension, capped so the patch area (which scales with
+/// `1 / sqrt` of it) stays above `min_area_fraction`. About 1.006 at 50 km/h
+/// and 1.2 at 300 km/h with the defaults.
+pub fn centrifugal_stiffening(
+    omega_rad_per_s: f32,
+    radius_m: f32,
+    params: &CentrifugalParams,
+) -> f32 {
+    let surface_speed = omega_rad_per_s * radius_m;
+    let tension = params.tread_mass_per_m.max(0.0) * surface_speed * surface_speed;
+    let ratio = 1.0 + tension / params.belt_stiffness.max(1.0);
+    let min_fraction = params.min_area_fraction.clamp(1.0e-3, 1.0);
+    if ratio.is_finite() {
+        ratio.min(1.0 / (min_fraction * min_fraction))
+    } else {
+        1.0
+    }
+}
+
+/// `definition.vertical_stiffness` at wheel speed `omega_rad_per_s`.
+pub fn tire_vertical_stiffness(
+    definition: &TireDefinition,
+    omega_rad_per_s: f32,
+    params: &CentrifugalParams,
+) -> f32 {
+    definition.vertical_stiffness
+        * centrifugal_stiffening(omega_rad_per_s, definition.radius_m, params)
+}
+
+/// Rectangular contact patch of a loaded tyre.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ContactPatch {
+    pub deflection_m: f32,
+    pub length_m: f32,
+    pub width_m: f32,
+    pub area_m2: f32,
+}
+
+/// Patch under `normal_load`: deflection against `tire_vertical_stiffness`
+/// (capped at the radius), length as the chord `2 sqrt(2 r d - d^2)` and the
+/// full tread width.
+pub fn tire_contact_patch(
+    definition: &TireDefinition,
+    normal_load: f32,
+    omega_rad_per_s: f32,
+    params: &CentrifugalParams,
+) -> ContactPatch {
+    let stiffness = tire_vertical_stiffness(definition, omega_rad_per_s, params);
+    let r = definition.radius_m;
+    if !normal_load.is_finite() || normal_load <= 0.0 || stiffness <= 0.0 || r <= 0.0 {
+        return ContactPatch::default();
+    }
+    let deflection = (normal_load / stiffness).min(r);
+    let length = 2.0 * (deflection * (2.0 * r - deflection)).max(0.0).sqrt();
+    let width = definition.width_m.max(0.0);
+    ContactPatch {
+        deflection_m: deflection,
+        length_m: length,
+        width_m: width,
+        area_m2: length * width,
+    }
+}
+
+/// Brush-model lateral force carried by each of `out.len()` evenly spaced
+/// points along the patch, ISO 8855 signs (positive `slip_angle` gives negative
+/// forces). `longitudinal_m[i]` is the point's position from the patch centre,
+/// positive towards the leading edge, within `half_length_m`. Bristles deflect
+/// `(a - x) tan(alpha)` from the leading edge until the parabolic pressure
+/// `3 fz / 4a (1 - (x/a)^2)` times `mu` can no longer hold them, so the force
+/// centroid sits behind the centre at small slip and returns to it at full
+/// sliding. Returns the summed force.
+pub fn brush_lateral_forces(
+    slip_angle: f32,
+    normal_load: f32,
+    mu: f32,
+    cornering_stiffness: f32,
+    half_length_m: f32,
+    longitudinal_m: &[f32],
+    out: &mut [f32],
+) -> f32 {
+    out.fill(0.0);
+    let a = half_length_m;
+    let n = out.len().min(longitudinal_m.len());
+    if n == 0 || !a.is_finite() || a <= 1.0e-6 || !slip_angle.is_finite() || normal_load <= 0.0 {
+        return 0.0;
+    }
+    let tan = slip_angle.tan();
+    let bristle_stiffness = cornering_stiffness.max(0.0) / (2.0 * a * a);
+    let peak_pressure = 0.75 * normal_load / a;
+    let dx = 2.0 * a / n as f32;
+    let mut total = 0.0;
+    for (f, &x) in out.iter_mut().zip(longitudinal_m).take(n) {
+        let x = x.clamp(-a, a);
+        let adhesion = bristle_stiffness * (a - x) * tan.abs();
+        let sliding = mu.max(0.0) * peak_pressure * (1.0 - (x / a) * (x / a));
+        *f = -tan.signum() * adhesion.min(sliding) * dx;
+        total += *f;
+    }
+    total
+}
+
+/// Parametric self-aligning moment `-t * fy` (ISO 8855) with a pneumatic trail
+/// falling linearly from `trail_at_zero_m` to zero at `slip_at_zero_trail_rad`.
+pub fn parametric_aligning_torque(
+    lateral_force: f32,
+    slip_angle: f32,
+    trail_at_zero_m: f32,
+    slip_at_zero_trail_rad: f32,
+) -> f32 {
+    if slip_at_zero_trail_rad <= 1.0e-6 || !slip_angle.is_finite() {
+        return 0.0;
+    }
+    let trail = trail_at_zero_m * (1.0 - slip_angle.abs() / slip_at_zero_trail_rad).max(0.0);
+    -trail * lateral_force
+}
+
+/// Pressure shape along the patch for `tire_solve_patch_forces`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PressureDistribution {
+    #[default]
+    Uniform,
+    /// `1 - u^2` over the point order, `u` running from -1 to 1 across the patch.
+    Parabolic,
+}
+
+pub const PATCH_STATUS_OK: u32 = 0;
+/// No point penetrated but the target load was positive; it was spread evenly.
+pub const PATCH_STATUS_DEGENERATE_UNIFORM: u32 = 1 << 0;
+/// `depths` and `out_forces` differ in length; only the common prefix was solved.
+pub const PATCH_STATUS_LENGTH_MISMATCH: u32 = 1 << 1;
+
+/// Per-point normal force from penetration depth. Each penetrating point gets
+/// `patch_stiffness * depth * shape(i)`, non-penetrating points get zero, and a
+/// positive `total_target_load` rescales the result so it sums to the target
+/// exactly (otherwise the stiffness-derived forces are returned as they are).
+/// Points are taken in order along the patch. Returns `PATCH_STATUS_*` bits.
+pub fn tire_solve_patch_forces(
+    penetration_depths: &[f32],
+    total_target_load: f32,
+    distribution: PressureDistribution,
+    patch_stiffness: f32,
+    out_forces: &mut [f32],
+) -> u32 {
+    let mut status = PATCH_STATUS_OK;
+    if penetration_depths.len() != out_forces.len() {
+        status |= PATCH_STATUS_LENGTH_MISMATCH;
+    }
+    let n = penetration_depths.len().min(out_forces.len());
+    out_forces.fill(0.0);
+    if n == 0 {
+        return status;
+    }
+    let out = &mut out_forces[..n];
+    let target = if total_target_load.is_finite() {
+        total_target_load
+    } else {
+        0.0
+    };
+    let stiffness = if patch_stiffness.is_finite() {
+        patch_stiffness.max(0.0)
+    } else {
+        0.0
+    };
+
+    let mut sum = 0.0;
+    for (i, (f, &depth)) in out.iter_mut().zip(penetration_depths).enumerate() {
+        let shape = match distribution {
+            PressureDistribution::Uniform => 1.0,
+            PressureDistribution::Parabolic => {
+                let u = (2 * i + 1) as f32 / n as f32 - 1.0;
+                1.0 - u * u
+            }
+        };
+        let depth = if depth.is_finite() {
+            depth.max(0.0)
+        } else {
+            0.0
+        };
+        *f = stiffness.max(1.0e-6) * depth * shape;
+        sum += *f;
+    }
+    if target <= 0.0 {
+        if stiffness <= 0.0 {
+            out.fill(0.0);
+        }
+        return status;
+    }
+    if sum <= 0.0 {
+        out.fill(target / n as f32);
+        return status | PATCH_STATUS_DEGENERATE_UNIFORM;
+    }
+
+    let scale = target / sum;
+    for f in out.iter_mut() {
+        *f *= scale;
+    }
+    // Put the rounding residue on the most loaded point so the sum is exact.
+    let total: f32 = out.iter().sum();
+    let largest = (0..n).fold(0, |best, i| if out[i] > out[best] { i } else { best });
+    out[largest] += target - total;
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::{tire_convert, Unit};
+
+    fn loaded(fz: f32) -> ContactAggregate {
+        ContactAggregate {
+            count: 1,
+            total_force: Vec3 {
+                x: 300.0,
+                y: -400.0,
+                z: fz,
+            },
+            ..ContactAggregate::default()
+        }
+    }
+
+    #[test]
+    fn width_distribution_sums_to_force_magnitude() {
+        let output = loaded(4000.0);
+        let strips: [f32; 8] = pressure_distribution_across_width(&output, 0.05, 0.22);
+        let total = (300.0_f32 * 300.0 + 400.0 * 400.0 + 4000.0 * 4000.0).sqrt();
+        assert!((strips.iter().sum::<f32>() - total).abs() < 1.0e-2);
+
+        let airborne: [f32; 4] =
+            pressure_distribution_across_width(&ContactAggregate::default(), 0.05, 0.22);
+        assert_eq!(airborne, [0.0; 4]);
+    }
+
+    #[test]
+    fn camber_shifts_load_towards_one_edge() {
+        let output = loaded(4000.0);
+        let flat: [f32; 6] = pressure_distribution_across_width(&output, 0.0, 0.22);
+        assert!((flat[0] - flat[5]).abs() < 1.0e-3);
+
+        let cambered: [f32; 6] = pressure_distribution_across_width(&output, 0.05, 0.22);
+        assert!(cambered[0] > cambered[5]);
+    }
+
+    #[test]
+    fn normal_variance_separates_flat_and_stepped_ground() {
+        let up = Vec3 {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        };
+        assert!(contact_normal_variance(&[up, up, up]) < 1.0e-6);
+
+        let stepped = [
+            up,
+            Vec3 {
+                x: 1.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            Vec3 {
+                x: -1.0,
+                y: 0.2,
+                z: 0.0,
+            },
+        ];
+        assert!(contact_normal_variance(&stepped) > NORMAL_VARIANCE_UNRELIABLE);
+        assert_eq!(contact_normal_variance(&[]), 0.0);
+
+        // Two up and one sideways: the dot products are 2/sqrt(5) twice and
+        // 1/sqrt(5), whose variance about their mean is 2/45.
+        let side = Vec3 {
+            x: 1.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        let variance = contact_normal_variance(&[up, up, side]);
+        assert!((variance - 2.0 / 45.0).abs() < 1.0e-6, "{variance}");
+    }
+
+    #[test]
+    fn crest_shortens_and_valley_lengthens_contact() {
+        let (length, radius) = (0.14, 0.33);
+        assert_eq!(curvature_contact_length_factor(length, 0.0, radius), 1.0);
+        assert!((curvature_contact_length_factor(length, 1.0e6, radius) - 1.0).abs() < 1.0e-3);
+
+        let crest = curvature_contact_length_factor(length, 2.0, radius);
+        let small_deflection = (2.0_f32 / (2.0 + radius)).sqrt();
+        assert!(crest < 1.0);
+        assert!((crest - small_deflection).abs() < 1.0e-2);
+
+        let valley = curvature_contact_length_factor(length, -2.0, radius);
+        assert!(valley > 1.0 && valley <= 2.0 * radius / length);
+    }
+
+    #[test]
+    fn patch_forces_follow_depth_and_sum_to_target() {
+        let depths = [0.0, 0.002, 0.004, 0.006, 0.004, 0.002, -0.001];
+        let mut out = [0.0; 7];
+        let status = tire_solve_patch_forces(
+            &depths,
+            4000.0,
+            PressureDistribution::Uniform,
+            2.0e6,
+            &mut out,
+        );
+        assert_eq!(status, PATCH_STATUS_OK);
+        assert!((out.iter().sum::<f32>() - 4000.0).abs() < 1.0e-3);
+        assert_eq!(out[0], 0.0);
+        assert_eq!(out[6], 0.0);
+        assert!((out[3] / out[1] - 3.0).abs() < 1.0e-4);
+
+        let flat = [0.003; 5];
+        let mut parabolic = [0.0; 5];
+        tire_solve_patch_forces(
+            &flat,
+            4000.0,
+            PressureDistribution::Parabolic,
+            2.0e6,
+            &mut parabolic,
+        );
+        assert!(parabolic[2] > parabolic[1] && parabolic[1] > parabolic[0]);
+        assert!((parabolic[0] - parabolic[4]).abs() < 1.0e-3);
+        assert!((parabolic.iter().sum::<f32>() - 4000.0).abs() < 1.0e-3);
+    }
+
+    #[test]
+    fn patch_forces_spread_load_when_nothing_penetrates() {
+        let mut out = [1.0; 4];
+        let status = tire_solve_patch_forces(
+            &[0.0; 4],
+            4000.0,
+            PressureDistribution::Uniform,
+            2.0e6,
+            &mut out,
+        );
+        assert_eq!(status, PATCH_STATUS_DEGENERATE_UNIFORM);
+        assert_eq!(out, [1000.0; 4]);
+
+        let mut short = [0.0; 3];
+        let status = tire_solve_patch_forces(
+            &[0.001; 4],
+            0.0,
+            PressureDistribution::Uniform,
+            1.0e6,
+            &mut short,
+        );
+        assert_eq!(status, PATCH_STATUS_LENGTH_MISMATCH);
+        assert!((short[0] - 1000.0).abs() < 1.0e-3);
+    }
+
+    #[test]
+    fn patch_is_round_at_rest_and_elongates_with_speed() {
+        assert_eq!(contact_patch_aspect_ratio(0.0, 0.0, 0.33, 220.0), 1.0);
+        let creeping = contact_patch_aspect_ratio(0.5, 0.5 / 0.33, 0.33, 220.0);
+        let cruising = contact_patch_aspect_ratio(30.0, 30.0 / 0.33, 0.33, 220.0);
+        assert!(1.0 < creeping && creeping < cruising);
+        assert!((cruising - PATCH_ROLLING_ASPECT_RATIO).abs() < 5.0e-3);
+        // Wheelspin from rest elongates like rolling.
+        assert!(
+            (contact_patch_aspect_ratio(0.0, 30.0 / 0.33, 0.33, 220.0) - cruising).abs() < 1.0e-5
+        );
+        assert!(contact_patch_aspect_ratio(30.0, 0.0, 0.33, 150.0) > cruising);
+        assert_eq!(contact_patch_aspect_ratio(30.0, 0.0, 0.33, 0.0), 1.0);
+        for radius in [f32::INFINITY, f32::NAN] {
+            assert_eq!(contact_patch_aspect_ratio(30.0, 0.0, radius, 220.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn contact_ellipse_preserves_area_and_ratio() {
+        let (length, width) = contact_ellipse(0.02, 1.5);
+        assert!((length / width - 1.5).abs() < 1.0e-5);
+        assert!((std::f32::consts::FRAC_PI_4 * length * width - 0.02).abs() < 1.0e-6);
+        assert_eq!(contact_ellipse(0.0, 1.5), (0.0, 0.0));
+    }
+
+    #[test]
+    fn patch_shortens_monotonically_with_wheel_speed() {
+        let definition = TireDefinition::default();
+        let params = CentrifugalParams::default();
+        let omega = |kmh: f32| {
+            tire_convert(kmh, Unit::KilometrePerHour, Unit::MetrePerSecond).unwrap()
+                / definition.radius_m
+        };
+        let at_rest = tire_contact_patch(&definition, 4000.0, 0.0, &params);
+        let city = tire_contact_patch(&definition, 4000.0, omega(50.0), &params);
+        assert!(at_rest.length_m - city.length_m < 0.005 * at_rest.length_m);
+
+        let mut previous = at_rest;
+        for kmh in (20..=400).step_by(20) {
+            let patch = tire_contact_patch(&definition, 4000.0, omega(kmh as f32), &params);
+            assert!(patch.length_m < previous.length_m);
+            assert!(patch.area_m2 >= params.min_area_fraction * at_rest.area_m2 * 0.999);
+            previous = patch;
+        }
+        let floored = CentrifugalParams {
+            belt_stiffness: 1.0,
+            ..params
+        };
+        let fast = tire_contact_patch(&definition, 4000.0, omega(300.0), &floored);
+        assert!(fast.area_m2 >= floored.min_area_fraction * at_rest.area_m2 * 0.98);
+    }
+}
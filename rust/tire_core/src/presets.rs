@@ -0,0 +1,232 @@
+//! Reference vehicles that wire the crate's pieces into a drivable car. See
+//! `tests/full_vehicle_step.rs` for the step loop they are meant for.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::axle::AxleParams;
+use crate::pacejka::PacejkaCoeffs;
+use crate::suspension::TravelStopParams;
+use crate::tire_state::{CompoundId, TireDefinition, TireSimParameters};
+use crate::vehicle::{VehicleConfig, VehicleMassProperties, GRAVITY};
+use crate::Vec3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PresetId {
+    /// Front-wheel-drive road car on hard road tyres.
+    Hatchback,
+    /// Rear-wheel-drive GT race car on softs.
+    GtRace,
+    /// Four-wheel-drive rally car on mediums.
+    Rally,
+}
+
+/// Everything a four-wheel step loop needs. The axle parameters are derived
+/// from `config`, so their static loads sum to the vehicle weight and their hub
+/// positions sit on the wheelbase and track.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VehicleSetup {
+    pub config: VehicleConfig,
+    pub front_axle: AxleParams,
+    pub rear_axle: AxleParams,
+    pub tire: TireDefinition,
+    pub compound: CompoundId,
+    /// Peak drive torque at the wheels, summed over all driven wheels (N·m).
+    pub max_drive_torque_nm: f32,
+    /// Share of the drive torque sent to the front axle (0 = RWD, 1 = FWD).
+    pub front_drive_share: f32,
+    /// Spin inertia of one wheel and its share of the driveline (kg·m²).
+    pub wheel_inertia_kg_m2: f32,
+}
+
+/// Per-preset numbers `vehicle_preset` expands.
+struct PresetSpec {
+    mass_kg: f32,
+    wheelbase_m: f32,
+    track_m: f32,
+    cg_to_front_m: f32,
+    cg_height_m: f32,
+    tire: TireDefinition,
+    compound: CompoundId,
+    mu: f32,
+    cornering_stiffness: f32,
+    spring_rate: f32,
+    damping: f32,
+    anti_roll_rate: [f32; 2],
+    max_drive_torque_nm: f32,
+    front_drive_share: f32,
+}
+
+fn axle_params(spec: &PresetSpec, front: bool) -> AxleParams {
+    let front_share = 1.0 - spec.cg_to_front_m / spec.wheelbase_m;
+    let share = if front {
+        front_share
+    } else {
+        1.0 - front_share
+    };
+    let x = if front {
+        spec.cg_to_front_m
+    } else {
+        spec.cg_to_front_m - spec.wheelbase_m
+    };
+    // SAE `z` is down: the hub centre sits a tyre radius above the ground.
+    let z = spec.cg_height_m - spec.tire.radius_m;
+    let hub = |y: f32| Vec3 { x, y, z };
+    let half_track = 0.5 * spec.track_m;
+    AxleParams {
+        static_load_n: 0.5 * share * spec.mass_kg * GRAVITY,
+        spring_rate: spec.spring_rate,
+        damping: spec.damping,
+        anti_roll_rate: spec.anti_roll_rate[usize::from(!front)],
+        stops: TravelStopParams::default(),
+        tire_radius_m: spec.tire.radius_m,
+        cornering_stiffness: spec.cornering_stiffness,
+        pacejka: PacejkaCoeffs {
+            mu: spec.mu,
+            ..PacejkaCoeffs::default()
+        },
+        wear: TireSimParameters {
+            friction: spec.mu,
+            ..TireSimParameters::for_compound(spec.compound.compound())
+        },
+        hub_positions: [hub(-half_track), hub(half_track)],
+        ..AxleParams::default()
+    }
+}
+
+pub fn vehicle_preset(id: PresetId) -> VehicleSetup {
+    let spec = match id {
+        PresetId::Hatchback => PresetSpec {
+            mass_kg: 1200.0,
+            wheelbase_m: 2.55,
+            track_m: 1.5,
+            cg_to_front_m: 1.0,
+            cg_height_m: 0.55,
+            tire: TireDefinition {
+                radius_m: 0.31,
+                width_m: 0.205,
+                ..TireDefinition::default()
+            },
+            compound: CompoundId::Hard,
+            mu: 0.95,
+            cornering_stiffness: 55000.0,
+            spring_rate: 25000.0,
+            damping: 2200.0,
+            anti_roll_rate: [12000.0, 8000.0],
+            max_drive_torque_nm: 2200.0,
+            front_drive_share: 1.0,
+        },
+        PresetId::GtRace => PresetSpec {
+            mass_kg: 1300.0,
+            wheelbase_m: 2.7,
+            track_m: 1.65,
+            cg_to_front_m: 1.3,
+            cg_height_m: 0.42,
+            tire: TireDefinition {
+                radius_m: 0.34,
+                width_m: 0.3,
+                sidewall_height_m: 0.09,
+                vertical_stiffness: 300000.0,
+                lateral_stiffness: 250000.0,
+                longitudinal_stiffness: 400000.0,
+                nominal_pressure_kpa: 180.0,
+            },
+            compound: CompoundId::Soft,
+            mu: 1.3,
+            cornering_stiffness: 120000.0,
+            spring_rate: 90000.0,
+            damping: 6000.0,
+            anti_roll_rate: [40000.0, 30000.0],
+            max_drive_torque_nm: 4500.0,
+            front_drive_share: 0.0,
+        },
+        PresetId::Rally => PresetSpec {
+            mass_kg: 1230.0,
+            wheelbase_m: 2.5,
+            track_m: 1.55,
+            cg_to_front_m: 1.2,
+            cg_height_m: 0.55,
+            tire: TireDefinition {
+                radius_m: 0.32,
+                width_m: 0.2,
+                sidewall_height_m: 0.12,
+                ..TireDefinition::default()
+            },
+            compound: CompoundId::Medium,
+            mu: 1.0,
+            cornering_stiffness: 60000.0,
+            spring_rate: 30000.0,
+            damping: 3500.0,
+            anti_roll_rate: [10000.0, 10000.0],
+            max_drive_torque_nm: 3500.0,
+            front_drive_share: 0.45,
+        },
+    };
+    VehicleSetup {
+        config: VehicleConfig {
+            mass: VehicleMassProperties {
+                dry_mass_kg: spec.mass_kg,
+                wheelbase_m: spec.wheelbase_m,
+                track_width_m: spec.track_m,
+                cg_to_front_axle_m: spec.cg_to_front_m,
+            },
+            cg_height_m: spec.cg_height_m,
+            ..VehicleConfig::default()
+        },
+        front_axle: axle_params(&spec, true),
+        rear_axle: axle_params(&spec, false),
+        tire: spec.tire,
+        compound: spec.compound,
+        max_drive_torque_nm: spec.max_drive_torque_nm,
+        front_drive_share: spec.front_drive_share,
+        wheel_inertia_kg_m2: 1.2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vehicle::{static_wheel_loads, ConsumableMassState, ConsumableParams};
+
+    #[test]
+    fn preset_axles_carry_the_static_weight() {
+        for id in [PresetId::Hatchback, PresetId::GtRace, PresetId::Rally] {
+            let setup = vehicle_preset(id);
+            let loads = static_wheel_loads(
+                setup.config.mass,
+                ConsumableParams::default(),
+                ConsumableMassState::default(),
+            );
+            let front = setup.front_axle.static_load_n;
+            let rear = setup.rear_axle.static_load_n;
+            assert!((front - loads.wheel_loads_n[0]).abs() < 1.0e-2, "{id:?}");
+            assert!((rear - loads.wheel_loads_n[2]).abs() < 1.0e-2, "{id:?}");
+            let [left, right] = setup.front_axle.hub_positions;
+            assert_eq!(right.y - left.y, setup.config.mass.track_width_m);
+            let wheelbase =
+                setup.front_axle.hub_positions[0].x - setup.rear_axle.hub_positions[0].x;
+            assert!((wheelbase - setup.config.mass.wheelbase_m).abs() < 1.0e-5);
+            assert!((0.0..=1.0).contains(&setup.front_drive_share));
+        }
+    }
+
+    #[test]
+    fn preset_tyres_heat_and_wear_by_compound() {
+        let wear = |id: PresetId| vehicle_preset(id).rear_axle.wear;
+        let (soft, medium, hard) = (
+            wear(PresetId::GtRace),
+            wear(PresetId::Rally),
+            wear(PresetId::Hatchback),
+        );
+        assert!(soft.surface_heat_capacity < medium.surface_heat_capacity);
+        assert!(medium.surface_heat_capacity < hard.surface_heat_capacity);
+        assert!(soft.wear_per_joule > medium.wear_per_joule);
+        assert!(medium.wear_per_joule > hard.wear_per_joule);
+        for id in [PresetId::Hatchback, PresetId::GtRace, PresetId::Rally] {
+            let setup = vehicle_preset(id);
+            assert_eq!(setup.front_axle.wear, setup.rear_axle.wear, "{id:?}");
+        }
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::thermal::CELSIUS_TO_KELVIN;
+use crate::tire_state::TireSimParameters;
+
+/// Standard atmosphere, for converting gauge to absolute pressure (kPa).
+pub const ATMOSPHERIC_PRESSURE_KPA: f32 = 101.325;
+
+/// Hot gauge pressure from the cold set-up by the ideal gas law at constant
+/// volume. The gas temperature is clamped to the parameter bounds first, so the
+/// absolute pressure stays positive whatever the thermal step produced.
+pub fn tire_hot_pressure_kpa(
+    cold_gauge_kpa: f32,
+    cold_temp_c: f32,
+    gas_temp_c: f32,
+    params: &TireSimParameters,
+) -> f32 {
+    let (gas_temp_c, _) = params.clamp_temperature(gas_temp_c);
+    let (cold_temp_c, _) = params.clamp_temperature(cold_temp_c);
+    let cold_k = (cold_temp_c + CELSIUS_TO_KELVIN).max(1.0);
+    let hot_k = (gas_temp_c + CELSIUS_TO_KELVIN).max(1.0);
+    let absolute = (cold_gauge_kpa + ATMOSPHERIC_PRESSURE_KPA).max(0.0) * hot_k / cold_k;
+    absolute - ATMOSPHERIC_PRESSURE_KPA
+}
+
+/// Tyre pressure monitor thresholds (gauge kPa).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TirePressureMonitor {
+    pub nominal_kpa: f32,
+    pub low_warn_kpa: f32,
+    pub low_critical_kpa: f32,
+    pub high_warn_kpa: f32,
+}
+
+impl TirePressureMonitor {
+    /// Thresholds for a placard pressure: warn 25% below nominal (EU TPMS),
+    /// critical at half of nominal, high warning 25% above.
+    pub fn for_nominal(nominal_kpa: f32) -> Self {
+        Self {
+            nominal_kpa,
+            low_warn_kpa: 0.75 * nominal_kpa,
+            low_critical_kpa: 0.5 * nominal_kpa,
+            high_warn_kpa: 1.25 * nominal_kpa,
+        }
+    }
+}
+
+impl Default for TirePressureMonitor {
+    fn default() -> Self {
+        Self::for_nominal(220.0)
+    }
+}
+
+/// Dashboard state of one tyre's pressure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TpmsStatus {
+    #[default]
+    Ok,
+    LowWarn,
+    LowCritical,
+    HighWarn,
+}
+
+/// Status for `current_kpa`; a threshold is crossed when the reading is at or
+/// beyond it. A non-finite reading (failed sensor) is `LowCritical`.
+pub fn tpms_status(system: &TirePressureMonitor, current_kpa: f32) -> TpmsStatus {
+    if !current_kpa.is_finite() || current_kpa <= system.low_critical_kpa {
+        TpmsStatus::LowCritical
+    } else if current_kpa <= system.low_warn_kpa {
+        TpmsStatus::LowWarn
+    } else if current_kpa >= system.high_warn_kpa {
+        TpmsStatus::HighWarn
+    } else {
+        TpmsStatus::Ok
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hot_pressure_rises_with_temperature_and_stays_positive() {
+        let params = TireSimParameters::default();
+        let cold = tire_hot_pressure_kpa(180.0, 20.0, 20.0, &params);
+        assert!((cold - 180.0).abs() < 1.0e-3);
+        assert!(tire_hot_pressure_kpa(180.0, 20.0, 90.0, &params) > cold);
+        let frozen = tire_hot_pressure_kpa(180.0, 20.0, -1.0e10, &params);
+        assert!(frozen + ATMOSPHERIC_PRESSURE_KPA > 0.0);
+        assert!(tire_hot_pressure_kpa(180.0, 20.0, f32::NAN, &params).is_finite());
+    }
+
+    #[test]
+    fn tpms_reports_each_band() {
+        let tpms = TirePressureMonitor::default();
+        assert_eq!(tpms.low_warn_kpa, 165.0);
+        assert_eq!(tpms_status(&tpms, 220.0), TpmsStatus::Ok);
+        assert_eq!(tpms_status(&tpms, 166.0), TpmsStatus::Ok);
+        assert_eq!(tpms_status(&tpms, 165.0), TpmsStatus::LowWarn);
+        assert_eq!(tpms_status(&tpms, 100.0), TpmsStatus::LowCritical);
+        assert_eq!(tpms_status(&tpms, 280.0), TpmsStatus::HighWarn);
+        assert_eq!(tpms_status(&tpms, f32::NAN), TpmsStatus::LowCritical);
+    }
+}
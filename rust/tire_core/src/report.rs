@@ -0,0 +1,389 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::contact::ContactAggregate;
+use crate::thermal::CELSIUS_TO_KELVIN;
+use crate::tire_state::TireState;
+use crate::wear::NEW_TREAD_DEPTH_MM;
+
+/// Slip at the contact patch: `ratio` longitudinal (dimensionless), `angle_rad` lateral.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SlipVector {
+    pub ratio: f32,
+    pub angle_rad: f32,
+}
+
+/// Display-ready per-wheel values for the Godot inspector and telemetry.
+/// Every field is final: the GDScript side prints them without further maths.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WheelForceReport {
+    pub longitudinal_n: f32,
+    pub lateral_n: f32,
+    pub vertical_n: f32,
+    pub grip_used_fraction: f32,
+    pub slip_ratio: f32,
+    pub slip_angle_deg: f32,
+    pub tire_wear_pct: f32,
+    pub surface_temp_c: f32,
+}
+
+fn finite_or_zero(v: f32) -> f32 {
+    if v.is_finite() {
+        v
+    } else {
+        0.0
+    }
+}
+
+/// Fills a `WheelForceReport` from already-computed results, with `aggregate`
+/// in the wheel frame (x forward, y lateral). The longitudinal and lateral
+/// forces are the x and y of its `tangential_force` and the vertical force is
+/// the size of its `normal_force`. `grip_used_fraction` is the tangential force
+/// over `aggregate.grip` times the vertical force (0 when unloaded, may exceed
+/// 1 when the force model overshoots the friction limit).
+pub fn build_wheel_force_report(
+    aggregate: ContactAggregate,
+    state: TireState,
+    slip: SlipVector,
+) -> WheelForceReport {
+    let tangential = aggregate.tangential_force;
+    let vertical = aggregate.normal_force.length();
+    let limit = aggregate.grip.max(0.0) * vertical;
+    let grip_used_fraction = if limit > 1.0e-6 {
+        tangential.length() / limit
+    } else {
+        0.0
+    };
+    WheelForceReport {
+        longitudinal_n: finite_or_zero(tangential.x),
+        lateral_n: finite_or_zero(tangential.y),
+        vertical_n: finite_or_zero(vertical),
+        grip_used_fraction: finite_or_zero(grip_used_fraction),
+        slip_ratio: finite_or_zero(slip.ratio),
+        slip_angle_deg: finite_or_zero(slip.angle_rad.to_degrees()),
+        tire_wear_pct: finite_or_zero(state.wear.clamp(0.0, 1.0) * 100.0),
+        surface_temp_c: finite_or_zero(state.surface_temp_c),
+    }
+}
+
+/// Physical quantity a `Unit` measures; only units of the same quantity convert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Quantity {
+    Pressure,
+    Temperature,
+    Depth,
+    Speed,
+    Force,
+    Distance,
+}
+
+/// Every unit the crate presents. The simulation works in the first unit of
+/// each quantity (kPa, °C, mm, m/s, N, km).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Unit {
+    Kilopascal,
+    Psi,
+    Bar,
+    Pascal,
+    Megapascal,
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+    Millimetre,
+    Inch,
+    MetrePerSecond,
+    KilometrePerHour,
+    MilePerHour,
+    Newton,
+    PoundForce,
+    Kilometre,
+    Mile,
+}
+
+impl Unit {
+    pub const ALL: [Unit; 17] = [
+        Unit::Kilopascal,
+        Unit::Psi,
+        Unit::Bar,
+        Unit::Pascal,
+        Unit::Megapascal,
+        Unit::Celsius,
+        Unit::Fahrenheit,
+        Unit::Kelvin,
+        Unit::Millimetre,
+        Unit::Inch,
+        Unit::MetrePerSecond,
+        Unit::KilometrePerHour,
+        Unit::MilePerHour,
+        Unit::Newton,
+        Unit::PoundForce,
+        Unit::Kilometre,
+        Unit::Mile,
+    ];
+
+    /// The single conversion table: `(quantity, scale, offset)` with
+    /// `simulation_value = value * scale + offset`.
+    fn definition(self) -> (Quantity, f32, f32) {
+        match self {
+            Unit::Kilopascal => (Quantity::Pressure, 1.0, 0.0),
+            Unit::Psi => (Quantity::Pressure, 6.894_757, 0.0),
+            Unit::Bar => (Quantity::Pressure, 100.0, 0.0),
+            Unit::Pascal => (Quantity::Pressure, 1.0e-3, 0.0),
+            Unit::Megapascal => (Quantity::Pressure, 1.0e3, 0.0),
+            Unit::Celsius => (Quantity::Temperature, 1.0, 0.0),
+            Unit::Fahrenheit => (Quantity::Temperature, 5.0 / 9.0, -32.0 * 5.0 / 9.0),
+            Unit::Kelvin => (Quantity::Temperature, 1.0, -CELSIUS_TO_KELVIN),
+            Unit::Millimetre => (Quantity::Depth, 1.0, 0.0),
+            Unit::Inch => (Quantity::Depth, 25.4, 0.0),
+            Unit::MetrePerSecond => (Quantity::Speed, 1.0, 0.0),
+            Unit::KilometrePerHour => (Quantity::Speed, 1.0 / 3.6, 0.0),
+            Unit::MilePerHour => (Quantity::Speed, 0.447_04, 0.0),
+            Unit::Newton => (Quantity::Force, 1.0, 0.0),
+            Unit::PoundForce => (Quantity::Force, 4.448_222, 0.0),
+            Unit::Kilometre => (Quantity::Distance, 1.0, 0.0),
+            Unit::Mile => (Quantity::Distance, 1.609_344, 0.0),
+        }
+    }
+
+    pub fn quantity(self) -> Quantity {
+        self.definition().0
+    }
+
+    /// Display suffix, e.g. `"psi"`.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Unit::Kilopascal => "kPa",
+            Unit::Psi => "psi",
+            Unit::Bar => "bar",
+            Unit::Pascal => "Pa",
+            Unit::Megapascal => "MPa",
+            Unit::Celsius => "°C",
+            Unit::Fahrenheit => "°F",
+            Unit::Kelvin => "K",
+            Unit::Millimetre => "mm",
+            Unit::Inch => "in",
+            Unit::MetrePerSecond => "m/s",
+            Unit::KilometrePerHour => "km/h",
+            Unit::MilePerHour => "mph",
+            Unit::Newton => "N",
+            Unit::PoundForce => "lbf",
+            Unit::Kilometre => "km",
+            Unit::Mile => "mi",
+        }
+    }
+}
+
+/// `value` in `from` expressed in `to`; `None` when the units measure
+/// different quantities. Non-finite input reads as 0.
+pub fn tire_convert(value: f32, from: Unit, to: Unit) -> Option<f32> {
+    let (q_from, scale_from, offset_from) = from.definition();
+    let (q_to, scale_to, offset_to) = to.definition();
+    if q_from != q_to {
+        return None;
+    }
+    if from == to {
+        return Some(finite_or_zero(value));
+    }
+    let base = finite_or_zero(value) * scale_from + offset_from;
+    Some(finite_or_zero((base - offset_to) / scale_to))
+}
+
+/// Player-facing unit preference for the HUD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UnitSystem {
+    /// kPa, °C, mm.
+    #[default]
+    Metric,
+    /// psi, °F, in.
+    Imperial,
+}
+
+impl UnitSystem {
+    pub fn pressure_unit(self) -> Unit {
+        match self {
+            UnitSystem::Metric => Unit::Kilopascal,
+            UnitSystem::Imperial => Unit::Psi,
+        }
+    }
+
+    pub fn temperature_unit(self) -> Unit {
+        match self {
+            UnitSystem::Metric => Unit::Celsius,
+            UnitSystem::Imperial => Unit::Fahrenheit,
+        }
+    }
+
+    pub fn depth_unit(self) -> Unit {
+        match self {
+            UnitSystem::Metric => Unit::Millimetre,
+            UnitSystem::Imperial => Unit::Inch,
+        }
+    }
+}
+
+/// HUD values already converted to `units`; the `*_unit` fields name what
+/// each number is in, so the label can never disagree with the value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TireDisplayState {
+    pub units: UnitSystem,
+    pub pressure: f32,
+    pub pressure_unit: Unit,
+    pub surface_temp: f32,
+    pub core_temp: f32,
+    pub temperature_unit: Unit,
+    pub wear_pct: f32,
+    pub tread_depth: f32,
+    pub depth_unit: Unit,
+}
+
+/// Formats a tyre for the HUD. The tread depth is what `state.wear` leaves of
+/// `NEW_TREAD_DEPTH_MM`; `pressure_kpa` is the gauge pressure to show.
+pub fn tire_format_state(
+    state: &TireState,
+    pressure_kpa: f32,
+    units: UnitSystem,
+) -> TireDisplayState {
+    let (pressure_unit, temperature_unit, depth_unit) = (
+        units.pressure_unit(),
+        units.temperature_unit(),
+        units.depth_unit(),
+    );
+    let convert = |v: f32, from: Unit, to: Unit| tire_convert(v, from, to).unwrap_or(0.0);
+    let wear = finite_or_zero(state.wear).clamp(0.0, 1.0);
+    TireDisplayState {
+        units,
+        pressure: convert(pressure_kpa, Unit::Kilopascal, pressure_unit),
+        pressure_unit,
+        surface_temp: convert(state.surface_temp_c, Unit::Celsius, temperature_unit),
+        core_temp: convert(state.core_temp_c, Unit::Celsius, temperature_unit),
+        temperature_unit,
+        wear_pct: wear * 100.0,
+        tread_depth: convert(
+            NEW_TREAD_DEPTH_MM * (1.0 - wear),
+            Unit::Millimetre,
+            depth_unit,
+        ),
+        depth_unit,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contact::{aggregate_contacts, ContactPoint};
+    use crate::Vec3;
+
+    #[test]
+    fn report_converts_units_and_grip_usage() {
+        let up = Vec3 {
+            x: 0.0,
+            y: 0.0,
+            z: 1.0,
+        };
+        let points = [ContactPoint {
+            normal: up,
+            force: Vec3 {
+                x: 3000.0,
+                y: -4000.0,
+                z: 5000.0,
+            },
+            area: 0.02,
+            grip: 1.25,
+            ..ContactPoint::default()
+        }];
+        let aggregate = aggregate_contacts(&points, Vec3::default());
+        let state = TireState {
+            surface_temp_c: 92.0,
+            wear: 0.25,
+            ..TireState::default()
+        };
+        let slip = SlipVector {
+            ratio: 0.08,
+            angle_rad: std::f32::consts::PI / 36.0,
+        };
+        let report = build_wheel_force_report(aggregate, state, slip);
+        assert_eq!(report.longitudinal_n, 3000.0);
+        assert_eq!(report.lateral_n, -4000.0);
+        assert_eq!(report.vertical_n, 5000.0);
+        assert!((report.grip_used_fraction - 0.8).abs() < 1.0e-5);
+        assert!((report.slip_angle_deg - 5.0).abs() < 1.0e-4);
+        assert_eq!(report.tire_wear_pct, 25.0);
+        assert_eq!(report.surface_temp_c, 92.0);
+
+        let airborne = build_wheel_force_report(ContactAggregate::default(), state, slip);
+        assert_eq!(airborne.grip_used_fraction, 0.0);
+    }
+
+    #[test]
+    fn every_unit_pair_round_trips() {
+        for from in Unit::ALL {
+            for to in Unit::ALL {
+                let converted = tire_convert(123.4, from, to);
+                if from.quantity() != to.quantity() {
+                    assert_eq!(converted, None, "{from:?} -> {to:?}");
+                    continue;
+                }
+                let back = tire_convert(converted.unwrap(), to, from).unwrap();
+                assert!((back - 123.4).abs() < 1.0e-3, "{from:?} -> {to:?}: {back}");
+            }
+            assert!(!from.symbol().is_empty());
+        }
+        let close = |v: Option<f32>, expected: f32| (v.unwrap() - expected).abs() < 1.0e-3;
+        assert!(close(
+            tire_convert(32.0, Unit::Fahrenheit, Unit::Celsius),
+            0.0
+        ));
+        assert!(close(
+            tire_convert(100.0, Unit::Celsius, Unit::Fahrenheit),
+            212.0
+        ));
+        assert!(close(
+            tire_convert(0.0, Unit::Celsius, Unit::Kelvin),
+            273.15
+        ));
+        assert!(close(tire_convert(1.0, Unit::Bar, Unit::Psi), 14.503_77));
+        assert!(close(
+            tire_convert(2.5e5, Unit::Pascal, Unit::Megapascal),
+            0.25
+        ));
+        assert!(close(
+            tire_convert(100.0, Unit::KilometrePerHour, Unit::MilePerHour),
+            62.137_12
+        ));
+        assert!(close(tire_convert(1.0, Unit::Inch, Unit::Millimetre), 25.4));
+        assert_eq!(
+            tire_convert(f32::NAN, Unit::Psi, Unit::Kilopascal),
+            Some(0.0)
+        );
+    }
+
+    #[test]
+    fn display_state_matches_the_simulation() {
+        let state = TireState {
+            surface_temp_c: 90.0,
+            core_temp_c: 80.0,
+            wear: 0.25,
+        };
+        let metric = tire_format_state(&state, 220.0, UnitSystem::Metric);
+        assert_eq!(metric.pressure, 220.0);
+        assert_eq!(metric.surface_temp, 90.0);
+        assert_eq!(metric.wear_pct, 25.0);
+        assert_eq!(metric.tread_depth, 6.0);
+        assert_eq!(metric.depth_unit, Unit::Millimetre);
+
+        let imperial = tire_format_state(&state, 220.0, UnitSystem::Imperial);
+        assert_eq!(imperial.pressure_unit, Unit::Psi);
+        assert!((imperial.pressure - 31.908).abs() < 1.0e-2);
+        assert!((imperial.surface_temp - 194.0).abs() < 1.0e-3);
+        assert!((imperial.core_temp - 176.0).abs() < 1.0e-3);
+        assert_eq!(imperial.wear_pct, 25.0);
+        let back = tire_convert(imperial.tread_depth, imperial.depth_unit, Unit::Millimetre);
+        assert!((back.unwrap() - 6.0).abs() < 1.0e-5);
+    }
+}
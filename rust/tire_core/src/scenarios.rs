@@ -0,0 +1,481 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::contract::{solve_core, ContactSampleRaw, CoreInput, WheelState, CORE_STIFFNESS};
+use crate::disturbance::{disturbance_sample, DisturbanceProfile};
+use crate::report::{tire_convert, Unit};
+use crate::tire_state::{TireSimParameters, TireState};
+use crate::vehicle::{bicycle_model_step, BicycleModelParams, BicycleState, GRAVITY};
+use crate::wear::{tire_wear_thermal_step, LapSample};
+
+const STOPPED_SPEED: f32 = 0.05;
+/// Default `thermal_settle_s` (s).
+pub const SCENARIO_THERMAL_SETTLE_S: f32 = 60.0;
+/// Fraction of the settled yaw rate that ends the `StepSteer` response time.
+const STEP_STEER_RESPONSE_FRACTION: f32 = 0.9;
+/// Tyres sharing the straight-line rigs' equivalent wheel load.
+const WHEEL_COUNT: f32 = 4.0;
+
+/// The 100 km/h mark of the braking and standing-start rigs (m/s).
+fn kph_100() -> f32 {
+    tire_convert(100.0, Unit::KilometrePerHour, Unit::MetrePerSecond).unwrap_or(0.0)
+}
+
+/// Canned test-rig procedures. The straight-line ones run `solve_core` in a
+/// fixed-step loop on a single equivalent wheel carrying the whole vehicle
+/// mass; `Crosswind` runs the bicycle model straight ahead at constant speed
+/// under the disturbance profile; `StepSteer` steps the bicycle model's
+/// steering at constant speed and holds it for `thermal_settle_s`;
+/// `SteadyState` holds one tyre at `steady_state_sample` for
+/// `thermal_settle_s`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Scenario {
+    SlipSweep,
+    BrakingDry,
+    BrakingWet,
+    StandingStart,
+    Crosswind,
+    StepSteer,
+    SteadyState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScenarioDefinition {
+    pub vehicle_mass_kg: f32,
+    pub wheel_radius_m: f32,
+    pub wheel_inertia: f32,
+    pub mu_dry: f32,
+    pub mu_wet: f32,
+    pub brake_torque_nm: f32,
+    pub drive_torque_nm: f32,
+    pub max_slip: f32,
+    pub sweep_steps: u32,
+    pub fixed_step_s: f32,
+    pub max_duration_s: f32,
+    pub bicycle: BicycleModelParams,
+    pub crosswind_speed_m_per_s: f32,
+    pub crosswind_duration_s: f32,
+    /// Aerodynamic centre of pressure ahead of the centre of mass (m).
+    pub center_of_pressure_m: f32,
+    pub step_steer_speed_m_per_s: f32,
+    pub step_steer_angle_rad: f32,
+    /// Thermal model of the heated tyre; each rig replaces `friction` with its
+    /// mu, and the tyre starts at `ambient_temp_c`.
+    pub tire: TireSimParameters,
+    /// Operating point held by `SteadyState` (`duration_s` is not used).
+    pub steady_state_sample: LapSample,
+    /// Running time of `StepSteer` and `SteadyState` before the temperatures
+    /// are read (s).
+    pub thermal_settle_s: f32,
+}
+
+impl Default for ScenarioDefinition {
+    fn default() -> Self {
+        Self {
+            vehicle_mass_kg: 1400.0,
+            wheel_radius_m: 0.33,
+            wheel_inertia: 4.8,
+            mu_dry: 1.0,
+            mu_wet: 0.4,
+            brake_torque_nm: 6000.0,
+            drive_torque_nm: 2500.0,
+            max_slip: 0.5,
+            sweep_steps: 51,
+            fixed_step_s: 1.0 / 1000.0,
+            max_duration_s: 60.0,
+            bicycle: BicycleModelParams::default(),
+            crosswind_speed_m_per_s: 30.0,
+            crosswind_duration_s: 20.0,
+            center_of_pressure_m: 0.3,
+            step_steer_speed_m_per_s: 20.0,
+            step_steer_angle_rad: 0.03,
+            tire: TireSimParameters::default(),
+            steady_state_sample: LapSample {
+                load: 4000.0,
+                slip_ratio: 0.03,
+                slip_angle: 0.04,
+                speed: 30.0,
+                duration_s: 0.0,
+            },
+            thermal_settle_s: SCENARIO_THERMAL_SETTLE_S,
+        }
+    }
+}
+
+/// Fields that do not apply to a scenario stay at zero. `peak_grip` is the
+/// largest `|fx| / fz` seen; `stopping_distance_m` and `time_to_100_s` are left
+/// at zero if the run hits `max_duration_s` first. `peak_yaw_rate` and
+/// `max_lateral_offset_m` (drift from the starting line) measure crosswind
+/// stability; `peak_yaw_rate` and `yaw_response_time_s` (to 90% of the settled
+/// yaw rate) the step-steer transient. `surface_temp_c` and `core_temp_c` come
+/// from `tire_wear_thermal_step` run alongside: for one of the four tyres at
+/// the end of the straight-line runs, and after `thermal_settle_s` of
+/// cornering (front tyre) for `StepSteer` or at the held operating point for
+/// `SteadyState`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScenarioMetrics {
+    pub peak_grip: f32,
+    pub slip_at_peak: f32,
+    pub stopping_distance_m: f32,
+    pub time_to_100_s: f32,
+    pub peak_yaw_rate: f32,
+    pub max_lateral_offset_m: f32,
+    pub yaw_response_time_s: f32,
+    pub surface_temp_c: f32,
+    pub core_temp_c: f32,
+}
+
+/// Longitudinal force from the core for one slip value, capped at `mu * fz`.
+/// `slip_x` follows the contact convention `(v - omega * r) / |v|`.
+fn longitudinal_force(slip_x: f32, fz: f32, radius: f32, mu: f32) -> (f32, f32) {
+    let input = CoreInput {
+        wheel: WheelState {
+            tire_radius: radius,
+            ..WheelState::default()
+        },
+        samples: vec![ContactSampleRaw {
+            penetration: fz / CORE_STIFFNESS,
+            confidence: 1.0,
+            slip_x,
+            normal_local: [0.0, 1.0, 0.0],
+            ..ContactSampleRaw::default()
+        }],
+        ..CoreInput::default()
+    };
+    let out = solve_core(&input);
+    let cap = mu.max(0.0) * out.fz;
+    (out.fx.clamp(-cap, cap), out.fz)
+}
+
+/// Tyre at the definition's ambient temperature, before any rig heats it.
+fn ambient_tire(def: &ScenarioDefinition) -> TireState {
+    TireState {
+        surface_temp_c: def.tire.ambient_temp_c,
+        core_temp_c: def.tire.ambient_temp_c,
+        wear: 0.0,
+    }
+}
+
+/// One fixed step of the real thermal/wear model at the rig's operating point.
+fn heat_tire(tire: &mut TireState, sample: LapSample, def: &ScenarioDefinition, mu: f32, dt: f32) {
+    let params = TireSimParameters {
+        friction: mu,
+        ..def.tire
+    };
+    *tire = tire_wear_thermal_step(*tire, &sample, Some(&params), dt);
+}
+
+fn slip_sweep(def: &ScenarioDefinition) -> ScenarioMetrics {
+    let fz = def.vehicle_mass_kg * GRAVITY;
+    let steps = def.sweep_steps.max(2);
+    let mut metrics = ScenarioMetrics::default();
+    for i in 0..steps {
+        let slip = def.max_slip * i as f32 / (steps - 1) as f32;
+        let (fx, fz_out) = longitudinal_force(slip, fz, def.wheel_radius_m, def.mu_dry);
+        if fz_out <= 0.0 {
+            continue;
+        }
+        let grip = fx.abs() / fz_out;
+        if grip > metrics.peak_grip {
+            metrics.peak_grip = grip;
+            metrics.slip_at_peak = slip;
+        }
+    }
+    metrics
+}
+
+/// Straight-line run from `initial_speed` under a constant wheel torque
+/// (negative = friction brake, which cannot spin the wheel backwards).
+fn straight_line(
+    def: &ScenarioDefinition,
+    mu: f32,
+    initial_speed: f32,
+    wheel_torque: f32,
+) -> ScenarioMetrics {
+    let dt = def.fixed_step_s.max(1.0e-5);
+    let mass = def.vehicle_mass_kg.max(1.0);
+    let radius = def.wheel_radius_m.max(1.0e-3);
+    let inertia = def.wheel_inertia.max(1.0e-3);
+    let fz = mass * GRAVITY;
+    let braking = wheel_torque < 0.0;
+
+    let mut speed = initial_speed;
+    let mut omega = initial_speed / radius;
+    let mut distance = 0.0;
+    let mut tire = ambient_tire(def);
+    let mut metrics = ScenarioMetrics::default();
+    let steps = (def.max_duration_s / dt).ceil() as u32;
+    for step in 1..=steps {
+        let slip_x = (speed - omega * radius) / speed.abs().max(1.0);
+        let (fx, fz_out) = longitudinal_force(slip_x, fz, radius, mu);
+        if fz_out > 0.0 {
+            let grip = fx.abs() / fz_out;
+            if grip > metrics.peak_grip {
+                metrics.peak_grip = grip;
+                metrics.slip_at_peak = slip_x.abs();
+            }
+        }
+        // The equivalent wheel carries all four tyres' load; heat one of them.
+        let sample = LapSample {
+            load: fz_out / WHEEL_COUNT,
+            slip_ratio: slip_x,
+            speed,
+            ..LapSample::default()
+        };
+        heat_tire(&mut tire, sample, def, mu, dt);
+
+        omega += (wheel_torque - fx * radius) / inertia * dt;
+        if braking {
+            omega = omega.max(0.0);
+        }
+        speed += fx / mass * dt;
+        distance += speed.max(0.0) * dt;
+
+        if braking && speed <= STOPPED_SPEED {
+            metrics.stopping_distance_m = distance;
+            break;
+        }
+        if !braking && speed >= kph_100() {
+            metrics.time_to_100_s = step as f32 * dt;
+            break;
+        }
+    }
+    metrics.surface_temp_c = tire.surface_temp_c;
+    metrics.core_temp_c = tire.core_temp_c;
+    metrics
+}
+
+/// Steering stepped to `step_steer_angle_rad` at constant speed and held for
+/// `thermal_settle_s`, heating one front tyre with its share of the
+/// static front axle load at the model's front slip angle.
+fn step_steer(def: &ScenarioDefinition) -> ScenarioMetrics {
+    let dt = def.fixed_step_s.max(1.0e-5);
+    let speed = def.step_steer_speed_m_per_s.abs();
+    let params = &def.bicycle;
+    let wheelbase = (params.lf_m + params.lr_m).max(1.0e-3);
+    let front_load = 0.5 * params.mass_kg * GRAVITY * params.lr_m / wheelbase;
+
+    let mut state = BicycleState::default();
+    let mut tire = ambient_tire(def);
+    let mut yaw_rates = Vec::new();
+    let mut metrics = ScenarioMetrics::default();
+    let steps = (def.thermal_settle_s.max(0.0) / dt).ceil() as u32;
+    for _ in 0..steps {
+        let (alpha_front, _) =
+            bicycle_model_step(&mut state, def.step_steer_angle_rad, speed, params, dt);
+        let yaw_rate = state.yaw_rate_rad_per_s;
+        metrics.peak_yaw_rate = metrics.peak_yaw_rate.max(yaw_rate.abs());
+        yaw_rates.push(yaw_rate.abs());
+        let sample = LapSample {
+            load: front_load,
+            slip_angle: alpha_front,
+            speed,
+            ..LapSample::default()
+        };
+        heat_tire(&mut tire, sample, def, def.mu_dry, dt);
+    }
+
+    let settled = yaw_rates.last().copied().unwrap_or(0.0);
+    if settled > 0.0 {
+        let reached = yaw_rates
+            .iter()
+            .position(|&r| r >= STEP_STEER_RESPONSE_FRACTION * settled);
+        if let Some(i) = reached {
+            metrics.yaw_response_time_s = (i + 1) as f32 * dt;
+        }
+    }
+    metrics.surface_temp_c = tire.surface_temp_c;
+    metrics.core_temp_c = tire.core_temp_c;
+    metrics
+}
+
+/// One tyre held at `steady_state_sample` on `mu_dry` for `thermal_settle_s`.
+fn steady_state(def: &ScenarioDefinition) -> ScenarioMetrics {
+    let dt = def.fixed_step_s.max(1.0e-5);
+    let mut tire = ambient_tire(def);
+    let steps = (def.thermal_settle_s.max(0.0) / dt).ceil() as u32;
+    for _ in 0..steps {
+        heat_tire(&mut tire, def.steady_state_sample, def, def.mu_dry, dt);
+    }
+    ScenarioMetrics {
+        surface_temp_c: tire.surface_temp_c,
+        core_temp_c: tire.core_temp_c,
+        ..ScenarioMetrics::default()
+    }
+}
+
+/// Straight ahead with the wheel fixed while the disturbance pushes the car:
+/// wind force at `center_of_pressure_m`, crown as the gravity component
+/// `m g sin(crown)` along the road surface.
+fn crosswind(def: &ScenarioDefinition, profile: Option<&DisturbanceProfile>) -> ScenarioMetrics {
+    let Some(profile) = profile else {
+        return ScenarioMetrics::default();
+    };
+    let dt = def.fixed_step_s.max(1.0e-5);
+    let speed = def.crosswind_speed_m_per_s.abs();
+    let params = &def.bicycle;
+    let mass = params.mass_kg.max(1.0e-3);
+    let inertia = params.inertia_kg_m2.max(1.0e-3);
+
+    let mut state = BicycleState::default();
+    let mut heading = 0.0_f32;
+    let mut offset = 0.0_f32;
+    let mut metrics = ScenarioMetrics::default();
+    let steps = (def.crosswind_duration_s.max(0.0) / dt).ceil() as u32;
+    for step in 0..steps {
+        let t = step as f32 * dt;
+        let d = disturbance_sample(t, speed * t, profile);
+        let lateral = d.lateral_wind_force_n + mass * GRAVITY * d.road_crown_angle_rad.sin();
+        bicycle_model_step(&mut state, 0.0, speed, params, dt);
+        state.lateral_velocity_m_per_s += lateral / mass * dt;
+        state.yaw_rate_rad_per_s +=
+            d.lateral_wind_force_n * def.center_of_pressure_m / inertia * dt;
+
+        heading += state.yaw_rate_rad_per_s * dt;
+        offset += (speed * heading.sin() + state.lateral_velocity_m_per_s * heading.cos()) * dt;
+        metrics.peak_yaw_rate = metrics.peak_yaw_rate.max(state.yaw_rate_rad_per_s.abs());
+        metrics.max_lateral_offset_m = metrics.max_lateral_offset_m.max(offset.abs());
+    }
+    metrics
+}
+
+pub fn run_scenario(scenario: Scenario, definition: &ScenarioDefinition) -> ScenarioMetrics {
+    run_scenario_with_disturbance(scenario, definition, None)
+}
+
+/// `run_scenario` with an optional disturbance. Only `Crosswind` is affected
+/// (the single-wheel straight-line rigs have no lateral freedom); without a
+/// profile it reports zeros.
+pub fn run_scenario_with_disturbance(
+    scenario: Scenario,
+    definition: &ScenarioDefinition,
+    disturbance: Option<&DisturbanceProfile>,
+) -> ScenarioMetrics {
+    match scenario {
+        Scenario::SlipSweep => slip_sweep(definition),
+        Scenario::BrakingDry => straight_line(
+            definition,
+            definition.mu_dry,
+            kph_100(),
+            -definition.brake_torque_nm.abs(),
+        ),
+        Scenario::BrakingWet => straight_line(
+            definition,
+            definition.mu_wet,
+            kph_100(),
+            -definition.brake_torque_nm.abs(),
+        ),
+        Scenario::StandingStart => straight_line(
+            definition,
+            definition.mu_dry,
+            0.0,
+            definition.drive_torque_nm.abs(),
+        ),
+        Scenario::Crosswind => crosswind(definition, disturbance),
+        Scenario::StepSteer => step_steer(definition),
+        Scenario::SteadyState => steady_state(definition),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wet_braking_stops_longer_than_dry() {
+        let def = ScenarioDefinition::default();
+        let dry = run_scenario(Scenario::BrakingDry, &def);
+        let wet = run_scenario(Scenario::BrakingWet, &def);
+        assert!(dry.stopping_distance_m > 0.0);
+        assert!(wet.stopping_distance_m > dry.stopping_distance_m);
+        assert!(wet.peak_grip <= def.mu_wet + 1.0e-4);
+        let ambient = TireSimParameters::default().ambient_temp_c;
+        assert!(dry.surface_temp_c > ambient && dry.core_temp_c >= ambient);
+    }
+
+    #[test]
+    fn scenarios_are_deterministic_and_complete() {
+        let def = ScenarioDefinition::default();
+        let launch = run_scenario(Scenario::StandingStart, &def);
+        assert!(launch.time_to_100_s > 0.0 && launch.time_to_100_s < def.max_duration_s);
+        assert_eq!(launch, run_scenario(Scenario::StandingStart, &def));
+
+        let sweep = run_scenario(Scenario::SlipSweep, &def);
+        assert!(sweep.peak_grip > 0.0 && sweep.peak_grip <= def.mu_dry);
+        assert!(sweep.slip_at_peak > 0.0 && sweep.slip_at_peak <= def.max_slip);
+    }
+
+    #[test]
+    fn more_rear_grip_improves_crosswind_stability() {
+        let profile = DisturbanceProfile::default();
+        let base = ScenarioDefinition::default();
+        let stiff_rear = ScenarioDefinition {
+            bicycle: BicycleModelParams {
+                cr_n_per_rad: 1.6 * base.bicycle.cr_n_per_rad,
+                ..base.bicycle
+            },
+            ..base
+        };
+        let a = run_scenario_with_disturbance(Scenario::Crosswind, &base, Some(&profile));
+        let b = run_scenario_with_disturbance(Scenario::Crosswind, &stiff_rear, Some(&profile));
+        assert!(a.peak_yaw_rate > 0.0);
+        assert!(b.peak_yaw_rate < 0.9 * a.peak_yaw_rate, "{b:?} vs {a:?}");
+        assert_eq!(
+            a,
+            run_scenario_with_disturbance(Scenario::Crosswind, &base, Some(&profile))
+        );
+        assert_eq!(
+            run_scenario(Scenario::Crosswind, &base),
+            ScenarioMetrics::default()
+        );
+    }
+
+    #[test]
+    fn step_steer_settles_and_heats_the_front_tyre() {
+        let def = ScenarioDefinition::default();
+        let metrics = run_scenario(Scenario::StepSteer, &def);
+        assert!(metrics.peak_yaw_rate > 0.0);
+        assert!(metrics.yaw_response_time_s > 0.0 && metrics.yaw_response_time_s < 2.0);
+        let ambient = TireSimParameters::default().ambient_temp_c;
+        assert!(metrics.surface_temp_c > ambient, "{metrics:?}");
+        assert!(metrics.core_temp_c > ambient, "{metrics:?}");
+        assert_eq!(metrics, run_scenario(Scenario::StepSteer, &def));
+
+        // More steering: more slip, hotter tyres.
+        let sharper = ScenarioDefinition {
+            step_steer_angle_rad: 2.0 * def.step_steer_angle_rad,
+            ..def
+        };
+        let hot = run_scenario(Scenario::StepSteer, &sharper);
+        assert!(hot.surface_temp_c > metrics.surface_temp_c);
+        assert!(hot.peak_yaw_rate > metrics.peak_yaw_rate);
+    }
+
+    #[test]
+    fn steady_state_settles_towards_the_held_operating_point() {
+        let def = ScenarioDefinition::default();
+        let metrics = run_scenario(Scenario::SteadyState, &def);
+        assert!(
+            metrics.surface_temp_c > def.tire.ambient_temp_c,
+            "{metrics:?}"
+        );
+        assert!(metrics.core_temp_c > def.tire.ambient_temp_c, "{metrics:?}");
+        assert_eq!(metrics.peak_grip, 0.0);
+
+        let longer = ScenarioDefinition {
+            thermal_settle_s: 10.0 * def.thermal_settle_s,
+            ..def
+        };
+        let settled = run_scenario(Scenario::SteadyState, &longer);
+        assert!(settled.core_temp_c > metrics.core_temp_c);
+        let cold = ScenarioDefinition {
+            thermal_settle_s: 0.0,
+            ..def
+        };
+        let idle = run_scenario(Scenario::SteadyState, &cold);
+        assert_eq!(idle.surface_temp_c, def.tire.ambient_temp_c);
+    }
+}
@@ -0,0 +1,364 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::pacejka::{pacejka_fx, pacejka_fy, peak_slip_ratio, PacejkaCoeffs};
+use crate::scenarios::{run_scenario, Scenario, ScenarioDefinition};
+use crate::tire_state::{TireCompound, TireDefinition, TireSimParameters, TireState};
+use crate::wear::{tire_fast_forward, wear_per_km, LapSample, FAST_FORWARD_MAX_STEP_S};
+
+/// Grip lost per squared fractional deviation from the nominal pressure.
+pub const PRESSURE_GRIP_SENSITIVITY: f32 = 0.8;
+/// Camber (deg) at which the lateral grip peaks.
+pub const OPTIMAL_CAMBER_DEG: f32 = -2.5;
+/// Lateral grip lost per squared degree away from `OPTIMAL_CAMBER_DEG`.
+pub const CAMBER_LATERAL_SENSITIVITY: f32 = 0.006;
+/// Longitudinal grip lost per squared degree of camber.
+pub const CAMBER_LONGITUDINAL_SENSITIVITY: f32 = 0.004;
+/// Grip gained by the softest compound over the medium (lost by the hardest).
+pub const HARDNESS_GRIP_SPREAD: f32 = 0.08;
+/// Exponent of the carcass heating factor `(nominal / pressure)^x` applied to
+/// the friction of the settle run: a softer carcass flexes more and turns more
+/// of the sliding work into heat. Below 1 so the heating moves less than the
+/// pressure ratio itself.
+pub const PRESSURE_HEAT_EXPONENT: f32 = 0.5;
+/// Running time after which the thermal model is taken as settled (s).
+pub const SETUP_STEADY_STATE_S: f32 = 1800.0;
+
+/// Set-up knob swept by `tire_setup_sensitivity`. The discriminant is the
+/// stable id for UI code; iterate `SetupParameter::ALL` to build controls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SetupParameter {
+    /// Cold gauge pressure (kPa).
+    Pressure = 0,
+    /// Static camber (deg, negative = top in).
+    Camber = 1,
+    /// Static toe (deg); either sign scrubs the tyre.
+    Toe = 2,
+    /// Compound hardness, 0 = soft, 0.5 = medium, 1 = hard.
+    CompoundHardness = 3,
+}
+
+/// Valid sweep range of a parameter, in its own unit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SetupParameterRange {
+    pub min: f32,
+    pub max: f32,
+}
+
+impl SetupParameter {
+    pub const ALL: [SetupParameter; 4] = [
+        SetupParameter::Pressure,
+        SetupParameter::Camber,
+        SetupParameter::Toe,
+        SetupParameter::CompoundHardness,
+    ];
+
+    pub fn id(self) -> u32 {
+        self as u32
+    }
+
+    /// `None` for an id outside `ALL`.
+    pub fn from_id(id: u32) -> Option<SetupParameter> {
+        Self::ALL.get(id as usize).copied()
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            SetupParameter::Pressure => "pressure_kpa",
+            SetupParameter::Camber => "camber_deg",
+            SetupParameter::Toe => "toe_deg",
+            SetupParameter::CompoundHardness => "compound_hardness",
+        }
+    }
+
+    pub fn range(self) -> SetupParameterRange {
+        let (min, max) = match self {
+            SetupParameter::Pressure => (120.0, 350.0),
+            SetupParameter::Camber => (-6.0, 3.0),
+            SetupParameter::Toe => (-1.0, 1.0),
+            SetupParameter::CompoundHardness => (0.0, 1.0),
+        };
+        SetupParameterRange { min, max }
+    }
+}
+
+/// Baseline set-up and the steady operating point the metrics are evaluated
+/// at. A sweep overrides one of the first four fields per sample.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SetupConditions {
+    pub pressure_kpa: f32,
+    pub camber_deg: f32,
+    pub toe_deg: f32,
+    pub compound_hardness: f32,
+    pub load_n: f32,
+    pub speed_m_per_s: f32,
+    pub slip_ratio: f32,
+    pub slip_angle_rad: f32,
+    pub ambient_temp_c: f32,
+}
+
+impl Default for SetupConditions {
+    fn default() -> Self {
+        Self {
+            pressure_kpa: 220.0,
+            camber_deg: -2.0,
+            toe_deg: 0.0,
+            compound_hardness: 0.5,
+            load_n: 4000.0,
+            speed_m_per_s: 30.0,
+            slip_ratio: 0.03,
+            slip_angle_rad: 0.04,
+            ambient_temp_c: 25.0,
+        }
+    }
+}
+
+impl SetupConditions {
+    fn with(mut self, parameter: SetupParameter, value: f32) -> Self {
+        match parameter {
+            SetupParameter::Pressure => self.pressure_kpa = value,
+            SetupParameter::Camber => self.camber_deg = value,
+            SetupParameter::Toe => self.toe_deg = value,
+            SetupParameter::CompoundHardness => self.compound_hardness = value,
+        }
+        self
+    }
+}
+
+/// Predicted behaviour at one sweep sample. Grips are peak force over load;
+/// temperatures are the settled values at the operating point.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SetupMetrics {
+    pub parameter_value: f32,
+    pub peak_lateral_grip: f32,
+    pub peak_longitudinal_grip: f32,
+    pub surface_temp_c: f32,
+    pub core_temp_c: f32,
+    pub wear_per_km: f32,
+}
+
+/// Compound for `hardness` in `[0, 1]`, interpolating soft, medium and hard.
+pub fn compound_at_hardness(hardness: f32) -> TireCompound {
+    let h = if hardness.is_finite() {
+        hardness.clamp(0.0, 1.0)
+    } else {
+        0.5
+    };
+    let (a, b, t) = if h < 0.5 {
+        (TireCompound::SOFT, TireCompound::MEDIUM, h * 2.0)
+    } else {
+        (TireCompound::MEDIUM, TireCompound::HARD, h * 2.0 - 1.0)
+    };
+    let lerp = |x: f32, y: f32| x + (y - x) * t;
+    TireCompound {
+        optimal_temp_min: lerp(a.optimal_temp_min, b.optimal_temp_min),
+        optimal_temp_max: lerp(a.optimal_temp_max, b.optimal_temp_max),
+        thermal_mass_j_per_c: lerp(a.thermal_mass_j_per_c, b.thermal_mass_j_per_c),
+        wear_rate_scale: lerp(a.wear_rate_scale, b.wear_rate_scale),
+        worn_wear: lerp(a.worn_wear, b.worn_wear),
+    }
+}
+
+fn evaluate(definition: &TireDefinition, c: &SetupConditions, value: f32) -> SetupMetrics {
+    let load = c.load_n.max(1.0);
+    let ratio = c.pressure_kpa.max(1.0) / definition.nominal_pressure_kpa.max(1.0);
+    let pressure = (1.0 - PRESSURE_GRIP_SENSITIVITY * (ratio - 1.0).powi(2)).max(0.5);
+    let hardness = c.compound_hardness.clamp(0.0, 1.0);
+    let compound = compound_at_hardness(hardness);
+    let compound_grip = 1.0 + HARDNESS_GRIP_SPREAD * (1.0 - 2.0 * hardness);
+    let camber_lat =
+        (1.0 - CAMBER_LATERAL_SENSITIVITY * (c.camber_deg - OPTIMAL_CAMBER_DEG).powi(2)).max(0.5);
+    let camber_long = (1.0 - CAMBER_LONGITUDINAL_SENSITIVITY * c.camber_deg.powi(2)).max(0.5);
+
+    let lateral = PacejkaCoeffs {
+        mu: pressure * compound_grip * camber_lat,
+        ..PacejkaCoeffs::default()
+    };
+    let alpha_peak = peak_slip_ratio(&lateral, load, 1.0e-4);
+    let peak_lateral_grip = pacejka_fy(&lateral, alpha_peak, load) / load;
+    let longitudinal = PacejkaCoeffs {
+        mu: pressure * compound_grip * camber_long,
+        ..PacejkaCoeffs::default()
+    };
+    let kappa_peak = peak_slip_ratio(&longitudinal, load, 1.0e-4);
+    let peak_longitudinal_grip = pacejka_fx(&longitudinal, kappa_peak, load) / load;
+
+    // Toe adds scrub to the slip angle; an under-inflated carcass turns more
+    // of the sliding work into heat (`PRESSURE_HEAT_EXPONENT`).
+    let sample = LapSample {
+        load,
+        slip_ratio: c.slip_ratio,
+        slip_angle: c.slip_angle_rad.abs() + c.toe_deg.to_radians().abs(),
+        speed: c.speed_m_per_s,
+        duration_s: 1.0,
+    };
+    let carcass_heat = ratio.powf(-PRESSURE_HEAT_EXPONENT);
+    let params = TireSimParameters {
+        friction: peak_lateral_grip * carcass_heat,
+        ambient_temp_c: c.ambient_temp_c,
+        ..TireSimParameters::for_compound(compound)
+    };
+    // The regression rig's steady-state run, stepped as coarsely as
+    // `tire_fast_forward` would.
+    let rig = ScenarioDefinition {
+        mu_dry: params.friction,
+        tire: params,
+        steady_state_sample: sample,
+        thermal_settle_s: SETUP_STEADY_STATE_S,
+        fixed_step_s: FAST_FORWARD_MAX_STEP_S,
+        ..ScenarioDefinition::default()
+    };
+    let settled = run_scenario(Scenario::SteadyState, &rig);
+    let worn = tire_fast_forward(
+        TireState {
+            surface_temp_c: settled.surface_temp_c,
+            core_temp_c: settled.core_temp_c,
+            wear: 0.0,
+        },
+        &[sample],
+        1,
+        Some(&params),
+    );
+    let wear_rate = worn.wear;
+
+    SetupMetrics {
+        parameter_value: value,
+        peak_lateral_grip,
+        peak_longitudinal_grip,
+        surface_temp_c: settled.surface_temp_c,
+        core_temp_c: settled.core_temp_c,
+        wear_per_km: wear_per_km(wear_rate, c.speed_m_per_s),
+    }
+}
+
+/// Sweeps `parameter` evenly from `sweep_min` to `sweep_max` (clamped to its
+/// `range`, non-finite bounds taking the range ends) with every other field
+/// held at `baseline`, writing one `SetupMetrics` per slot of `out`. A single
+/// slot evaluates `sweep_min`. Grips are the Magic Formula peaks with the
+/// set-up factors applied to `mu`; temperatures come from the
+/// `Scenario::SteadyState` rig held at the operating point for
+/// `SETUP_STEADY_STATE_S`. Returns the samples written.
+pub fn tire_setup_sensitivity(
+    definition: &TireDefinition,
+    baseline: &SetupConditions,
+    parameter: SetupParameter,
+    sweep_min: f32,
+    sweep_max: f32,
+    out: &mut [SetupMetrics],
+) -> usize {
+    let range = parameter.range();
+    let bound = |v: f32, fallback: f32| {
+        if v.is_finite() {
+            v.clamp(range.min, range.max)
+        } else {
+            fallback
+        }
+    };
+    let lo = bound(sweep_min, range.min);
+    let hi = bound(sweep_max, range.max);
+    let last = out.len().saturating_sub(1).max(1) as f32;
+    for (i, slot) in out.iter_mut().enumerate() {
+        let value = lo + (hi - lo) * i as f32 / last;
+        *slot = evaluate(definition, &baseline.with(parameter, value), value);
+    }
+    out.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sweep(parameter: SetupParameter, min: f32, max: f32) -> [SetupMetrics; 5] {
+        let mut out = [SetupMetrics::default(); 5];
+        let n = tire_setup_sensitivity(
+            &TireDefinition::default(),
+            &SetupConditions::default(),
+            parameter,
+            min,
+            max,
+            &mut out,
+        );
+        assert_eq!(n, 5);
+        out
+    }
+
+    #[test]
+    fn parameters_are_enumerable_by_id() {
+        for (i, p) in SetupParameter::ALL.iter().enumerate() {
+            assert_eq!(p.id(), i as u32);
+            assert_eq!(SetupParameter::from_id(i as u32), Some(*p));
+            assert!(p.range().min < p.range().max);
+            assert!(!p.name().is_empty());
+        }
+        assert_eq!(SetupParameter::from_id(4), None);
+    }
+
+    #[test]
+    fn sweeps_show_the_expected_trends() {
+        let pressure = sweep(SetupParameter::Pressure, 160.0, 280.0);
+        assert_eq!(pressure[0].parameter_value, 160.0);
+        assert_eq!(pressure[4].parameter_value, 280.0);
+        // Grip peaks at the nominal 220 kPa, the middle sample.
+        assert!(pressure[2].peak_lateral_grip > pressure[0].peak_lateral_grip);
+        assert!(pressure[2].peak_lateral_grip > pressure[4].peak_lateral_grip);
+        assert!(pressure[0].surface_temp_c > pressure[4].surface_temp_c);
+        assert!(pressure[2].peak_longitudinal_grip > 0.5);
+
+        let camber = sweep(SetupParameter::Camber, -4.5, -0.5);
+        assert!(camber[2].peak_lateral_grip > camber[4].peak_lateral_grip);
+        assert!(camber[4].peak_longitudinal_grip >= camber[0].peak_longitudinal_grip);
+
+        let toe = sweep(SetupParameter::Toe, 0.0, 1.0);
+        assert!(toe[4].surface_temp_c > toe[0].surface_temp_c);
+        assert!(toe[4].wear_per_km > toe[0].wear_per_km);
+
+        let hardness = sweep(SetupParameter::CompoundHardness, 0.0, 1.0);
+        assert!(hardness[0].peak_lateral_grip > hardness[4].peak_lateral_grip);
+        assert!(hardness[0].wear_per_km > hardness[4].wear_per_km);
+        assert!(hardness[0].surface_temp_c > hardness[4].surface_temp_c);
+
+        // Out-of-range bounds are clamped.
+        let clamped = sweep(SetupParameter::Toe, -10.0, f32::NAN);
+        assert_eq!(clamped[0].parameter_value, -1.0);
+        assert_eq!(clamped[4].parameter_value, 1.0);
+    }
+
+    #[test]
+    fn settled_temperatures_match_the_steady_state_rig() {
+        // Baseline: nominal pressure, medium compound, no toe.
+        let c = SetupConditions::default();
+        let mut out = [SetupMetrics::default(); 1];
+        tire_setup_sensitivity(
+            &TireDefinition::default(),
+            &c,
+            SetupParameter::Toe,
+            0.0,
+            0.0,
+            &mut out,
+        );
+        let rig = ScenarioDefinition {
+            mu_dry: out[0].peak_lateral_grip,
+            tire: TireSimParameters {
+                ambient_temp_c: c.ambient_temp_c,
+                ..TireSimParameters::for_compound(TireCompound::MEDIUM)
+            },
+            steady_state_sample: LapSample {
+                load: c.load_n,
+                slip_ratio: c.slip_ratio,
+                slip_angle: c.slip_angle_rad,
+                speed: c.speed_m_per_s,
+                duration_s: 0.0,
+            },
+            thermal_settle_s: SETUP_STEADY_STATE_S,
+            fixed_step_s: FAST_FORWARD_MAX_STEP_S,
+            ..ScenarioDefinition::default()
+        };
+        let settled = run_scenario(Scenario::SteadyState, &rig);
+        assert!((out[0].surface_temp_c - settled.surface_temp_c).abs() < 1.0e-3);
+        assert!((out[0].core_temp_c - settled.core_temp_c).abs() < 1.0e-3);
+    }
+}
@@ -0,0 +1,192 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::contract::{solve_core, CoreInput, CoreOutput};
+use crate::timestep::resolve_delta;
+use crate::Vec2;
+
+/// Persistent patch memory for the low-speed hold mode. `displacement` is the
+/// accumulated patch travel (x longitudinal, y lateral) since the hold engaged.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PatchStictionState {
+    pub displacement: Vec2,
+    pub hold_weight: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StictionParams {
+    pub speed_threshold: f32,
+    pub omega_threshold: f32,
+    pub stiffness: f32,
+    pub damping: f32,
+    pub mu: f32,
+}
+
+impl Default for StictionParams {
+    fn default() -> Self {
+        Self {
+            speed_threshold: 0.3,
+            omega_threshold: 1.0,
+            stiffness: 60000.0,
+            damping: 2500.0,
+            mu: 1.0,
+        }
+    }
+}
+
+/// Blend weight of the hold mode: 1 below half the thresholds, 0 above them.
+pub fn stiction_weight(hub_speed: f32, wheel_omega: f32, params: StictionParams) -> f32 {
+    let speed_ratio = hub_speed.abs() / params.speed_threshold.max(1.0e-6);
+    let omega_ratio = wheel_omega.abs() / params.omega_threshold.max(1.0e-6);
+    let ratio = speed_ratio.max(omega_ratio);
+    if !ratio.is_finite() {
+        return 0.0;
+    }
+    let t = ((ratio - 0.5) / 0.5).clamp(0.0, 1.0);
+    1.0 - t * t * (3.0 - 2.0 * t)
+}
+
+/// `solve_core` plus a static-friction hold for parked or creeping wheels.
+///
+/// While the hub and wheel are slow, a spring-damper on the accumulated patch
+/// displacement holds the contact point in place (capped at `mu * fz`, the
+/// displacement sliding back to the cap so breakaway does not store energy).
+/// The hold force is blended with the slip force by `stiction_weight`, and the
+/// state resets once the wheel is rolling.
+pub fn solve_core_with_stiction(
+    input: &CoreInput,
+    state: PatchStictionState,
+    params: StictionParams,
+    delta: f32,
+) -> (CoreOutput, PatchStictionState) {
+    let (out, state, _) = solve_core_with_stiction_ex(input, state, params, delta);
+    (out, state)
+}
+
+/// `solve_core_with_stiction` under the crate delta policy (see `timestep`),
+/// with the status bits. A skipped `delta` returns the plain `solve_core`
+/// output and the state unchanged. The hold slides at breakaway, so a negative
+/// `delta` is always skipped.
+pub fn solve_core_with_stiction_ex(
+    input: &CoreInput,
+    state: PatchStictionState,
+    params: StictionParams,
+    delta: f32,
+) -> (CoreOutput, PatchStictionState, u32) {
+    let mut out = solve_core(input);
+    let (delta, status) = resolve_delta(delta, false);
+    if delta <= 0.0 {
+        return (out, state, status);
+    }
+
+    let wheel = input.wheel;
+    let slide_velocity = Vec2 {
+        x: wheel.velocity_local_z - wheel.omega * wheel.tire_radius,
+        y: wheel.velocity_local_x,
+    };
+    let hub_speed = Vec2 {
+        x: wheel.velocity_local_z,
+        y: wheel.velocity_local_x,
+    }
+    .length();
+    let weight = stiction_weight(hub_speed, wheel.omega, params);
+    if weight <= 0.0 || out.fz <= 0.0 {
+        return (out, PatchStictionState::default(), status);
+    }
+
+    let mut displacement = Vec2 {
+        x: state.displacement.x + slide_velocity.x * delta,
+        y: state.displacement.y + slide_velocity.y * delta,
+    };
+    let mut hold = Vec2 {
+        x: -params.stiffness * displacement.x - params.damping * slide_velocity.x,
+        y: -params.stiffness * displacement.y - params.damping * slide_velocity.y,
+    };
+    let cap = params.mu.max(0.0) * out.fz;
+    let magnitude = hold.length();
+    if magnitude > cap {
+        let dir = hold.normalized();
+        hold = Vec2 {
+            x: dir.x * cap,
+            y: dir.y * cap,
+        };
+        if params.stiffness > 0.0 {
+            displacement = Vec2 {
+                x: -hold.x / params.stiffness,
+                y: -hold.y / params.stiffness,
+            };
+        }
+    }
+
+    out.fx = out.fx + (hold.x - out.fx) * weight;
+    out.fy = out.fy + (hold.y - out.fy) * weight;
+    out.mz = out.fy * out.center_of_pressure_local[0];
+
+    (
+        out,
+        PatchStictionState {
+            displacement,
+            hold_weight: weight,
+        },
+        status,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::{ContactSampleRaw, WheelState};
+
+    fn parked_on_slope(creep: f32) -> CoreInput {
+        CoreInput {
+            wheel: WheelState {
+                velocity_local_z: creep,
+                tire_radius: 0.33,
+                ..WheelState::default()
+            },
+            samples: vec![ContactSampleRaw {
+                penetration: 0.02,
+                confidence: 1.0,
+                normal_local: [0.0, 1.0, 0.0],
+                ..ContactSampleRaw::default()
+            }],
+            ..CoreInput::default()
+        }
+    }
+
+    #[test]
+    fn stiction_resists_creep_and_resets_when_rolling() {
+        let mut state = PatchStictionState::default();
+        let mut out = CoreOutput::default();
+        for _ in 0..30 {
+            let step = solve_core_with_stiction(
+                &parked_on_slope(0.02),
+                state,
+                StictionParams::default(),
+                1.0 / 60.0,
+            );
+            out = step.0;
+            state = step.1;
+        }
+        assert!(out.fx < 0.0);
+        assert!(out.fx.abs() <= out.fz * StictionParams::default().mu + 1.0e-3);
+        assert_eq!(state.hold_weight, 1.0);
+
+        let mut rolling = parked_on_slope(5.0);
+        rolling.wheel.omega = 5.0 / 0.33;
+        let (_, reset) =
+            solve_core_with_stiction(&rolling, state, StictionParams::default(), 1.0 / 60.0);
+        assert_eq!(reset, PatchStictionState::default());
+    }
+
+    #[test]
+    fn stiction_weight_is_smooth() {
+        let params = StictionParams::default();
+        assert_eq!(stiction_weight(0.0, 0.0, params), 1.0);
+        let mid = stiction_weight(0.75 * params.speed_threshold, 0.0, params);
+        assert!(mid > 0.0 && mid < 1.0);
+        assert_eq!(stiction_weight(params.speed_threshold, 0.0, params), 0.0);
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::contact::ContactPoint;
+
+/// Per-material surface properties looked up by contact material id.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SurfaceParams {
+    pub grip: f32,
+    pub roughness: f32,
+}
+
+impl Default for SurfaceParams {
+    fn default() -> Self {
+        Self {
+            grip: 1.0,
+            roughness: 0.0,
+        }
+    }
+}
+
+/// Live material table shared by every vehicle. The game thread edits it;
+/// physics threads read `SurfaceSnapshot`s taken from it. Storage is
+/// copy-on-write: a snapshot shares the table until the next `set`, which
+/// copies it only while snapshots are still holding the old one.
+///
+/// Editing takes `&mut self`, so a registry shared between threads goes behind
+/// the caller's `RwLock`; a snapshot taken under the read lock can then be
+/// used on any thread without further locking and never sees a half-applied
+/// batch of edits made under one write lock.
+#[derive(Debug, Clone, Default)]
+pub struct SurfaceRegistry {
+    materials: Arc<BTreeMap<u32, SurfaceParams>>,
+    generation: u64,
+}
+
+/// Immutable view of a registry at one generation. It owns its table, so it
+/// stays valid after the registry is dropped and is released when dropped
+/// itself.
+#[derive(Debug, Clone, Default)]
+pub struct SurfaceSnapshot {
+    materials: Arc<BTreeMap<u32, SurfaceParams>>,
+    generation: u64,
+}
+
+impl SurfaceRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts or replaces `material_id` and bumps the generation.
+    pub fn set(&mut self, material_id: u32, params: SurfaceParams) {
+        Arc::make_mut(&mut self.materials).insert(material_id, params);
+        self.generation += 1;
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// O(1): shares the current table.
+    pub fn snapshot(&self) -> SurfaceSnapshot {
+        SurfaceSnapshot {
+            materials: Arc::clone(&self.materials),
+            generation: self.generation,
+        }
+    }
+}
+
+impl SurfaceSnapshot {
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Parameters for `material_id`; unknown ids get `SurfaceParams::default()`.
+    pub fn get(&self, material_id: u32) -> SurfaceParams {
+        self.materials
+            .get(&material_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Fills each point's `grip` from its material before `aggregate_contacts`.
+    /// `material_ids` pairs with `points`; extra entries on either side are ignored.
+    pub fn resolve_grip(&self, points: &mut [ContactPoint], material_ids: &[u32]) {
+        for (point, &id) in points.iter_mut().zip(material_ids) {
+            point.grip = self.get(id).grip;
+        }
+    }
+
+    pub fn shares_storage_with(&self, other: &SurfaceSnapshot) -> bool {
+        Arc::ptr_eq(&self.materials, &other.materials)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ASPHALT: u32 = 1;
+    const GRAVEL: u32 = 2;
+
+    #[test]
+    fn snapshots_are_isolated_from_later_edits() {
+        let mut registry = SurfaceRegistry::new();
+        registry.set(
+            ASPHALT,
+            SurfaceParams {
+                grip: 0.9,
+                roughness: 0.1,
+            },
+        );
+        let before = registry.snapshot();
+        let again = registry.snapshot();
+        assert!(before.shares_storage_with(&again));
+
+        // Track rubbering in while a physics step holds `before`.
+        registry.set(
+            ASPHALT,
+            SurfaceParams {
+                grip: 1.05,
+                roughness: 0.1,
+            },
+        );
+        let after = registry.snapshot();
+        assert_eq!(before.get(ASPHALT).grip, 0.9);
+        assert_eq!(after.get(ASPHALT).grip, 1.05);
+        assert!(after.generation() > before.generation());
+        assert!(!after.shares_storage_with(&before));
+
+        drop(registry);
+        assert_eq!(after.get(ASPHALT).grip, 1.05);
+        assert_eq!(after.get(GRAVEL), SurfaceParams::default());
+    }
+
+    #[test]
+    fn snapshot_resolves_contact_grip() {
+        let mut registry = SurfaceRegistry::new();
+        registry.set(
+            GRAVEL,
+            SurfaceParams {
+                grip: 0.6,
+                roughness: 0.8,
+            },
+        );
+        let snapshot = registry.snapshot();
+        let mut points = [ContactPoint::default(); 2];
+        snapshot.resolve_grip(&mut points, &[GRAVEL, ASPHALT]);
+        assert_eq!(points[0].grip, 0.6);
+        assert_eq!(points[1].grip, 1.0);
+    }
+
+    #[test]
+    fn snapshots_taken_during_concurrent_edits_are_never_torn() {
+        use std::sync::RwLock;
+        let registry = Arc::new(RwLock::new(SurfaceRegistry::new()));
+        let writer = {
+            let registry = Arc::clone(&registry);
+            std::thread::spawn(move || {
+                for i in 1..=500 {
+                    let params = SurfaceParams {
+                        grip: i as f32,
+                        roughness: 0.0,
+                    };
+                    // One batch: both materials move together.
+                    let mut guard = registry.write().unwrap();
+                    guard.set(ASPHALT, params);
+                    guard.set(GRAVEL, params);
+                }
+            })
+        };
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let registry = Arc::clone(&registry);
+                std::thread::spawn(move || {
+                    let mut last = 0;
+                    for _ in 0..500 {
+                        let snapshot = registry.read().unwrap().snapshot();
+                        assert_eq!(snapshot.get(ASPHALT), snapshot.get(GRAVEL));
+                        assert_eq!(snapshot.generation() % 2, 0);
+                        assert!(snapshot.generation() >= last);
+                        last = snapshot.generation();
+                        let mut points = [ContactPoint::default(); 2];
+                        snapshot.resolve_grip(&mut points, &[ASPHALT, GRAVEL]);
+                        assert_eq!(points[0].grip, points[1].grip);
+                    }
+                })
+            })
+            .collect();
+        writer.join().unwrap();
+        for reader in readers {
+            reader.join().unwrap();
+        }
+        assert_eq!(registry.read().unwrap().generation(), 1000);
+    }
+}
@@ -0,0 +1,273 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::vehicle::GRAVITY;
+
+/// Bump and droop stops. Travel is measured from the free spring length,
+/// positive in compression: the bump stop engages above `bump_travel_m` and the
+/// droop stop below `-droop_travel_m`. Stop force grows as
+/// `stiffness * penetration^exponent` while loading and is scaled by
+/// `energy_return` while unloading, so each impact dissipates
+/// `1 - energy_return` of the energy stored in the stop.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TravelStopParams {
+    pub bump_travel_m: f32,
+    pub droop_travel_m: f32,
+    pub stiffness: f32,
+    pub exponent: f32,
+    pub energy_return: f32,
+}
+
+impl Default for TravelStopParams {
+    fn default() -> Self {
+        Self {
+            bump_travel_m: 0.12,
+            droop_travel_m: 0.08,
+            stiffness: 4.0e6,
+            exponent: 2.0,
+            energy_return: 0.4,
+        }
+    }
+}
+
+/// Stop force along the strut, positive pushing the ends apart.
+pub fn travel_stop_force(travel_m: f32, travel_velocity: f32, params: TravelStopParams) -> f32 {
+    let stiffness = params.stiffness.max(0.0);
+    let exponent = params.exponent.max(1.0);
+    let unloading = params.energy_return.clamp(0.0, 1.0);
+
+    let bump = travel_m - params.bump_travel_m;
+    if bump > 0.0 {
+        let scale = if travel_velocity > 0.0 {
+            1.0
+        } else {
+            unloading
+        };
+        return stiffness * bump.powf(exponent) * scale;
+    }
+    let droop = -params.droop_travel_m - travel_m;
+    if droop > 0.0 {
+        let scale = if travel_velocity < 0.0 {
+            1.0
+        } else {
+            unloading
+        };
+        return -stiffness * droop.powf(exponent) * scale;
+    }
+    0.0
+}
+
+/// Camber against suspension travel (positive in compression, as for
+/// `TravelStopParams`). `travel_m` must be ascending.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CamberGainCurve {
+    pub travel_m: [f32; 5],
+    pub camber_deg: [f32; 5],
+}
+
+impl Default for CamberGainCurve {
+    /// Double-wishbone style gain: more negative camber in bump, progressively.
+    fn default() -> Self {
+        Self {
+            travel_m: [-0.08, -0.04, 0.0, 0.04, 0.08],
+            camber_deg: [0.6, -0.1, -0.8, -1.7, -2.9],
+        }
+    }
+}
+
+/// Piecewise-linear camber (degrees) at `displacement_m`, held at the end
+/// values outside the table. Non-finite travel reads as 0.
+pub fn camber_at_travel(curve: &CamberGainCurve, displacement_m: f32) -> f32 {
+    let x = if displacement_m.is_finite() {
+        displacement_m
+    } else {
+        0.0
+    };
+    let (t, c) = (&curve.travel_m, &curve.camber_deg);
+    if x <= t[0] {
+        return c[0];
+    }
+    for i in 1..t.len() {
+        if x <= t[i] {
+            let span = t[i] - t[i - 1];
+            if span <= 0.0 {
+                return c[i];
+            }
+            return c[i - 1] + (c[i] - c[i - 1]) * (x - t[i - 1]) / span;
+        }
+    }
+    c[c.len() - 1]
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct QuarterCarParams {
+    pub sprung_mass_kg: f32,
+    pub unsprung_mass_kg: f32,
+    pub spring_rate: f32,
+    pub damping: f32,
+    pub tire_stiffness: f32,
+    pub tire_radius_m: f32,
+    pub strut_free_length_m: f32,
+    pub stops: TravelStopParams,
+    pub max_substep_s: f32,
+}
+
+impl Default for QuarterCarParams {
+    fn default() -> Self {
+        Self {
+            sprung_mass_kg: 300.0,
+            unsprung_mass_kg: 35.0,
+            spring_rate: 30000.0,
+            damping: 2500.0,
+            tire_stiffness: 200000.0,
+            tire_radius_m: 0.3,
+            strut_free_length_m: 0.45,
+            stops: TravelStopParams::default(),
+            max_substep_s: 1.0 / 1000.0,
+        }
+    }
+}
+
+/// Heights are measured from the ground: the sprung mount point and the wheel centre.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct QuarterCarState {
+    pub sprung_height_m: f32,
+    pub sprung_velocity: f32,
+    pub unsprung_height_m: f32,
+    pub unsprung_velocity: f32,
+}
+
+impl QuarterCarState {
+    pub fn travel_m(&self, params: &QuarterCarParams) -> f32 {
+        params.strut_free_length_m - (self.sprung_height_m - self.unsprung_height_m)
+    }
+}
+
+/// Quarter-car step with spring, damper and travel stops. `delta` is split into
+/// equal sub-steps no longer than `max_substep_s`, so the stiff stop region
+/// integrates the same way at any caller rate.
+pub fn suspension_step(
+    state: QuarterCarState,
+    params: QuarterCarParams,
+    delta: f32,
+) -> QuarterCarState {
+    if !delta.is_finite() || delta <= 0.0 {
+        return state;
+    }
+    let max_substep = params.max_substep_s.max(1.0e-5);
+    let substeps = (delta / max_substep).ceil().clamp(1.0, 10000.0) as u32;
+    let h = delta / substeps as f32;
+    let ms = params.sprung_mass_kg.max(1.0e-3);
+    let mu = params.unsprung_mass_kg.max(1.0e-3);
+
+    let mut s = state;
+    for _ in 0..substeps {
+        let travel = s.travel_m(&params);
+        let travel_velocity = s.unsprung_velocity - s.sprung_velocity;
+        let strut = params.spring_rate * travel
+            + params.damping * travel_velocity
+            + travel_stop_force(travel, travel_velocity, params.stops);
+        let tire = params.tire_stiffness * (params.tire_radius_m - s.unsprung_height_m).max(0.0);
+
+        s.sprung_velocity += (strut / ms - GRAVITY) * h;
+        s.unsprung_velocity += ((tire - strut) / mu - GRAVITY) * h;
+        s.sprung_height_m += s.sprung_velocity * h;
+        s.unsprung_height_m += s.unsprung_velocity * h;
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rebound_height(rate_hz: f32, params: QuarterCarParams) -> f32 {
+        let drop = 0.8;
+        let mut state = QuarterCarState {
+            sprung_height_m: params.tire_radius_m + params.strut_free_length_m + drop,
+            unsprung_height_m: params.tire_radius_m + drop,
+            ..QuarterCarState::default()
+        };
+        let dt = 1.0 / rate_hz;
+        let mut bottomed = false;
+        let mut peak = f32::MIN;
+        for _ in 0..(3.0 * rate_hz) as usize {
+            let next = suspension_step(state, params, dt);
+            if next.travel_m(&params) > params.stops.bump_travel_m {
+                bottomed = true;
+            }
+            if bottomed && next.sprung_velocity < 0.0 && state.sprung_velocity >= 0.0 {
+                peak = peak.max(next.sprung_height_m);
+                break;
+            }
+            state = next;
+        }
+        assert!(bottomed, "drop should reach the bump stop");
+        assert!(peak > 0.0, "sprung mass should rebound");
+        peak
+    }
+
+    #[test]
+    fn bump_stop_rebound_is_rate_independent() {
+        let params = QuarterCarParams::default();
+        let reference = rebound_height(240.0, params);
+        for rate in [30.0, 60.0] {
+            let h = rebound_height(rate, params);
+            assert!(
+                (h - reference).abs() < 0.02,
+                "{rate} Hz: {h} vs {reference}"
+            );
+        }
+    }
+
+    #[test]
+    fn energy_return_controls_rebound() {
+        let soft = QuarterCarParams {
+            stops: TravelStopParams {
+                energy_return: 0.1,
+                ..TravelStopParams::default()
+            },
+            ..QuarterCarParams::default()
+        };
+        let lively = QuarterCarParams {
+            stops: TravelStopParams {
+                energy_return: 0.9,
+                ..TravelStopParams::default()
+            },
+            ..QuarterCarParams::default()
+        };
+        assert!(rebound_height(60.0, lively) > rebound_height(60.0, soft));
+    }
+
+    #[test]
+    fn droop_stop_pulls_back_towards_travel_range() {
+        let params = TravelStopParams::default();
+        assert!(travel_stop_force(-params.droop_travel_m - 0.01, -0.5, params) < 0.0);
+        assert_eq!(travel_stop_force(0.0, 0.0, params), 0.0);
+        let loading = travel_stop_force(params.bump_travel_m + 0.01, 1.0, params);
+        let unloading = travel_stop_force(params.bump_travel_m + 0.01, -1.0, params);
+        assert!(loading > unloading && unloading > 0.0);
+    }
+
+    #[test]
+    fn camber_curve_interpolates_monotonically_and_clamps() {
+        let curve = CamberGainCurve::default();
+        let mut previous = camber_at_travel(&curve, -0.1);
+        for i in 1..=200 {
+            let camber = camber_at_travel(&curve, -0.1 + 0.001 * i as f32);
+            assert!(camber <= previous);
+            previous = camber;
+        }
+        for (&t, &c) in curve.travel_m.iter().zip(&curve.camber_deg) {
+            assert!((camber_at_travel(&curve, t) - c).abs() < 1.0e-6);
+        }
+        assert!((camber_at_travel(&curve, 0.02) - -1.25).abs() < 1.0e-5);
+        assert_eq!(camber_at_travel(&curve, -1.0), curve.camber_deg[0]);
+        assert_eq!(camber_at_travel(&curve, 1.0), curve.camber_deg[4]);
+        assert_eq!(camber_at_travel(&curve, f32::NAN), curve.camber_deg[2]);
+    }
+}
@@ -0,0 +1,483 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::timestep::{resolve_delta, STEP_STATUS_DELTA_SKIPPED};
+use crate::tire_state::{TireCompound, TireSimParameters, TireState};
+
+/// Conductive heat flux through the tread between the core and surface nodes,
+/// per square metre of tread (W/m²). Positive when the core is hotter (heat
+/// flows outwards to the surface), negative when the surface is hotter.
+pub fn core_surface_heat_flux_w(
+    surface_c: f32,
+    core_c: f32,
+    conductivity_w_per_m_k: f32,
+    thickness_m: f32,
+) -> f32 {
+    if thickness_m <= 1.0e-6 || conductivity_w_per_m_k <= 0.0 {
+        return 0.0;
+    }
+    let flux = conductivity_w_per_m_k * (core_c - surface_c) / thickness_m;
+    if flux.is_finite() {
+        flux
+    } else {
+        0.0
+    }
+}
+
+/// Exchanges heat between the surface and core nodes over `delta` through a
+/// total conductance `conductance_w_per_k` (flux × area / ΔT). Uses the exact
+/// two-node solution, so the pair relaxes towards its heat-capacity-weighted
+/// mean without overshoot at any step size and total energy is conserved.
+/// Returns `(surface_c, core_c)`.
+pub fn couple_core_surface(
+    surface_c: f32,
+    core_c: f32,
+    conductance_w_per_k: f32,
+    surface_heat_capacity_j_per_k: f32,
+    core_heat_capacity_j_per_k: f32,
+    delta: f32,
+) -> (f32, f32) {
+    let (surface, core, _) = couple_core_surface_ex(
+        surface_c,
+        core_c,
+        conductance_w_per_k,
+        surface_heat_capacity_j_per_k,
+        core_heat_capacity_j_per_k,
+        delta,
+        false,
+    );
+    (surface, core)
+}
+
+/// `couple_core_surface` under the crate delta policy (see `timestep`): returns
+/// the status bits alongside `(surface_c, core_c)`, and with `allow_reverse` a
+/// negative `delta` runs the exchange backwards (the exact solution inverts).
+pub fn couple_core_surface_ex(
+    surface_c: f32,
+    core_c: f32,
+    conductance_w_per_k: f32,
+    surface_heat_capacity_j_per_k: f32,
+    core_heat_capacity_j_per_k: f32,
+    delta: f32,
+    allow_reverse: bool,
+) -> (f32, f32, u32) {
+    let (delta, status) = resolve_delta(delta, allow_reverse);
+    if status & STEP_STATUS_DELTA_SKIPPED != 0 || conductance_w_per_k <= 0.0 {
+        return (surface_c, core_c, status);
+    }
+    let cs = surface_heat_capacity_j_per_k.max(1.0e-3);
+    let cc = core_heat_capacity_j_per_k.max(1.0e-3);
+    let mean = (surface_c * cs + core_c * cc) / (cs + cc);
+    let rate = conductance_w_per_k * (1.0 / cs + 1.0 / cc);
+    let gap = (core_c - surface_c) * (-rate * delta).exp();
+    let (surface, core) = (mean - gap * cc / (cs + cc), mean + gap * cs / (cs + cc));
+    if surface.is_finite() && core.is_finite() {
+        (surface, core, status)
+    } else {
+        (surface_c, core_c, STEP_STATUS_DELTA_SKIPPED)
+    }
+}
+
+pub(crate) const CELSIUS_TO_KELVIN: f32 = 273.15;
+
+/// Radiant heat exchanged over `delta` between two facing surfaces (dual tyres,
+/// or a tyre and a hot body such as a brake duct exit), in joules
+/// `(into_a, into_b)`. `coupling_coefficient` is `emissivity * sigma * area`
+/// (W/K⁴) and `view_factor` the fraction of `a` that sees `b`. The pair always
+/// sums to zero and vanishes at equal temperatures; for a fixed-temperature body
+/// pass its temperature as `temperature_b` and ignore the second value. Divide
+/// each by `delta` and pass it as the `heat_input_w` of that tyre's
+/// `tire_wear_thermal_step_with_heat`.
+pub fn tire_radiative_coupling(
+    temperature_a: f32,
+    temperature_b: f32,
+    view_factor: f32,
+    coupling_coefficient: f32,
+    delta: f32,
+) -> (f32, f32) {
+    if !delta.is_finite() || delta <= 0.0 {
+        return (0.0, 0.0);
+    }
+    let ta = (temperature_a + CELSIUS_TO_KELVIN).max(0.0);
+    let tb = (temperature_b + CELSIUS_TO_KELVIN).max(0.0);
+    let factor = view_factor.clamp(0.0, 1.0) * coupling_coefficient.max(0.0);
+    let into_a = factor * (tb.powi(4) - ta.powi(4)) * delta;
+    if into_a.is_finite() {
+        (into_a, -into_a)
+    } else {
+        (0.0, 0.0)
+    }
+}
+
+/// Tyre blanket: heats surface and core at the same rate
+/// (`power_w / thermal_mass_j_per_c`), never past `blanket_temp_c`. Nodes already
+/// above the blanket temperature are left alone.
+pub fn tire_blanket_step(
+    state: &mut TireState,
+    blanket_temp_c: f32,
+    power_w: f32,
+    thermal_mass_j_per_c: f32,
+    delta: f32,
+) {
+    if !delta.is_finite() || delta <= 0.0 || thermal_mass_j_per_c <= 0.0 {
+        return;
+    }
+    let rise = power_w.max(0.0) * delta / thermal_mass_j_per_c;
+    if !rise.is_finite() {
+        return;
+    }
+    for temp in [&mut state.surface_temp_c, &mut state.core_temp_c] {
+        if *temp < blanket_temp_c {
+            *temp = (*temp + rise).min(blanket_temp_c);
+        }
+    }
+}
+
+/// Road surface patch under the racing line, heated by tyre friction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SurfaceThermal {
+    pub temperature_c: f32,
+    pub thermal_mass_j_per_m2_k: f32,
+}
+
+impl Default for SurfaceThermal {
+    fn default() -> Self {
+        Self {
+            temperature_c: 25.0,
+            thermal_mass_j_per_m2_k: 20000.0,
+        }
+    }
+}
+
+/// Heats the road patch with the tyre's friction flux and cools it towards
+/// `ambient_c` at `cooling_rate` (W/m²K), integrated exactly so large steps
+/// never overshoot the equilibrium `ambient_c + flux / cooling_rate`. Feed
+/// `surface.temperature_c` back as the sink temperature of the tyre step
+/// (`TireSimParameters::ambient_temp_c`) to close the rubber-to-track loop.
+pub fn surface_thermal_step(
+    surface: &mut SurfaceThermal,
+    tire_heat_flux_w_per_m2: f32,
+    ambient_c: f32,
+    cooling_rate: f32,
+    delta: f32,
+) {
+    if !delta.is_finite() || delta <= 0.0 || !tire_heat_flux_w_per_m2.is_finite() {
+        return;
+    }
+    let mass = surface.thermal_mass_j_per_m2_k.max(1.0e-3);
+    let cooling = cooling_rate.max(0.0);
+    let next = if cooling > 1.0e-6 {
+        let equilibrium = ambient_c + tire_heat_flux_w_per_m2 / cooling;
+        equilibrium + (surface.temperature_c - equilibrium) * (-cooling / mass * delta).exp()
+    } else {
+        surface.temperature_c + tire_heat_flux_w_per_m2 / mass * delta
+    };
+    if next.is_finite() {
+        surface.temperature_c = next;
+    }
+}
+
+/// Simulated tyre temperature probe: a first-order lag on the true temperature
+/// plus bounded noise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TemperatureSensor {
+    /// Lagged, noise-free temperature the probe has settled to.
+    pub measured_c: f32,
+    /// `measured_c` plus this step's noise; what the probe reports.
+    pub reading_c: f32,
+    pub time_constant_s: f32,
+    pub noise_amplitude_c: f32,
+}
+
+impl Default for TemperatureSensor {
+    fn default() -> Self {
+        Self {
+            measured_c: 25.0,
+            reading_c: 25.0,
+            time_constant_s: 0.5,
+            noise_amplitude_c: 0.5,
+        }
+    }
+}
+
+/// Moves `measured_c` towards `actual_temp_c` by the exact lag factor
+/// `1 - exp(-delta / time_constant_s)` and sets `reading_c` to it plus
+/// `rng_value * noise_amplitude_c` (`rng_value` from the caller, clamped to
+/// `[-1, 1]`, so runs are reproducible). The noise is not fed back into the
+/// lag, so the reading stays within the amplitude of the lagged value at any
+/// tick rate. A time constant of 0 tracks instantly.
+pub fn sensor_step(sensor: &mut TemperatureSensor, actual_temp_c: f32, rng_value: f32, delta: f32) {
+    if !delta.is_finite() || delta <= 0.0 || !actual_temp_c.is_finite() {
+        return;
+    }
+    let tau = sensor.time_constant_s.max(0.0);
+    let blend = if tau > 0.0 {
+        1.0 - (-delta / tau).exp()
+    } else {
+        1.0
+    };
+    let noise = if rng_value.is_finite() {
+        rng_value.clamp(-1.0, 1.0) * sensor.noise_amplitude_c.max(0.0)
+    } else {
+        0.0
+    };
+    let next = sensor.measured_c + (actual_temp_c - sensor.measured_c) * blend;
+    if next.is_finite() {
+        sensor.measured_c = next;
+        sensor.reading_c = next + noise;
+    }
+}
+
+/// Laps of warm-up until the colder tyre node reaches the compound's
+/// `optimal_temp_min`, from a lumped model: each lap adds `lap_heat_joules` to
+/// `thermal_mass_j_per_c` and loses `convection_per_speed * lap_distance_m *
+/// (T - ambient)` (the high-speed limit of the tyre convection, which no longer
+/// depends on lap time). Solved exactly per lap, so the result is fractional.
+/// Returns 0 when already there and `f32::INFINITY` when the equilibrium
+/// `ambient + Q / G` never reaches the window. `params = None` uses the defaults.
+pub fn laps_to_operating_temp(
+    state: &TireState,
+    compound: TireCompound,
+    lap_heat_joules: f32,
+    lap_distance_m: f32,
+    params: Option<&TireSimParameters>,
+) -> f32 {
+    let temp = state.surface_temp_c.min(state.core_temp_c);
+    let target = compound.optimal_temp_min;
+    if temp >= target {
+        return 0.0;
+    }
+    let defaults = TireSimParameters::default();
+    let params = params.unwrap_or(&defaults);
+    let heat = if lap_heat_joules.is_finite() {
+        lap_heat_joules.max(0.0)
+    } else {
+        0.0
+    };
+    let mass = compound.thermal_mass_j_per_c.max(1.0e-3);
+    let loss = params.convection_per_speed.max(0.0) * lap_distance_m.max(0.0);
+    let laps = if loss <= 1.0e-6 {
+        (target - temp) * mass / heat
+    } else {
+        let equilibrium = params.ambient_temp_c + heat / loss;
+        if equilibrium <= target {
+            return f32::INFINITY;
+        }
+        -(mass / loss) * ((equilibrium - target) / (equilibrium - temp)).ln()
+    };
+    if laps.is_finite() {
+        laps.max(0.0)
+    } else {
+        f32::INFINITY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tire_state::{is_at_operating_temp, TireCompound};
+
+    #[test]
+    fn heat_flux_sign_follows_temperature_gradient() {
+        assert!(core_surface_heat_flux_w(60.0, 90.0, 0.25, 0.01) > 0.0);
+        assert!(core_surface_heat_flux_w(110.0, 90.0, 0.25, 0.01) < 0.0);
+        assert_eq!(core_surface_heat_flux_w(90.0, 90.0, 0.25, 0.01), 0.0);
+    }
+
+    #[test]
+    fn coupling_conserves_energy_and_never_overshoots() {
+        let (cs, cc) = (2000.0, 6000.0);
+        let (surface, core) = couple_core_surface(120.0, 60.0, 50.0, cs, cc, 100.0);
+        let before = 120.0 * cs + 60.0 * cc;
+        let after = surface * cs + core * cc;
+        assert!((before - after).abs() / before < 1.0e-4);
+        assert!(surface >= core);
+        assert!(surface < 120.0 && core > 60.0);
+    }
+
+    #[test]
+    fn blanket_warms_tire_into_window_without_overshoot() {
+        let compound = TireCompound::SOFT;
+        let mut state = TireState::default();
+        assert!(!is_at_operating_temp(&state, compound));
+        for _ in 0..120 {
+            tire_blanket_step(
+                &mut state,
+                90.0,
+                2000.0,
+                compound.thermal_mass_j_per_c,
+                10.0,
+            );
+        }
+        assert_eq!(state.surface_temp_c, 90.0);
+        assert_eq!(state.core_temp_c, 90.0);
+        assert!(is_at_operating_temp(&state, compound));
+    }
+
+    #[test]
+    fn radiative_coupling_warms_the_colder_tire_and_conserves_energy() {
+        let coupling = 0.9 * 5.67e-8 * 0.3;
+        let (inner, outer) = tire_radiative_coupling(95.0, 40.0, 0.6, coupling, 1.0);
+        assert!(inner < 0.0 && outer > 0.0);
+        assert_eq!(inner + outer, 0.0);
+
+        let (cold_inner, hot_outer) = tire_radiative_coupling(40.0, 95.0, 0.6, coupling, 1.0);
+        assert!(cold_inner > 0.0 && hot_outer < 0.0);
+        assert_eq!(
+            tire_radiative_coupling(80.0, 80.0, 0.6, coupling, 1.0),
+            (0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn road_surface_heats_towards_equilibrium_and_warms_the_tyre() {
+        use crate::wear::{tire_wear_thermal_step, LapSample};
+
+        let mut road = SurfaceThermal::default();
+        for _ in 0..600 {
+            surface_thermal_step(&mut road, 4000.0, 25.0, 50.0, 1.0);
+        }
+        assert!(road.temperature_c > 25.0 && road.temperature_c <= 105.0 + 1.0e-3);
+
+        let mut jump = SurfaceThermal::default();
+        surface_thermal_step(&mut jump, 4000.0, 25.0, 50.0, 1.0e6);
+        assert!((jump.temperature_c - 105.0).abs() < 1.0e-3);
+
+        let sample = LapSample {
+            speed: 60.0,
+            duration_s: 1.0,
+            ..LapSample::default()
+        };
+        let cold = TireSimParameters::default();
+        let warm = TireSimParameters {
+            ambient_temp_c: road.temperature_c,
+            ..cold
+        };
+        let tire = TireState::default();
+        let on_cold = tire_wear_thermal_step(tire, &sample, Some(&cold), 10.0);
+        let on_warm = tire_wear_thermal_step(tire, &sample, Some(&warm), 10.0);
+        assert!(on_warm.surface_temp_c > on_cold.surface_temp_c);
+    }
+
+    #[test]
+    fn laps_to_operating_temp_matches_lap_by_lap_model() {
+        let compound = TireCompound::MEDIUM;
+        let params = TireSimParameters::default();
+        let cold = TireState::default();
+        let (heat, distance) = (400_000.0, 1000.0);
+        let laps = laps_to_operating_temp(&cold, compound, heat, distance, None);
+        assert!(laps > 0.0 && laps.is_finite());
+
+        // Lap-by-lap exact update crosses the target between floor and ceil.
+        let loss = params.convection_per_speed * distance;
+        let equilibrium = params.ambient_temp_c + heat / loss;
+        let decay = (-loss / compound.thermal_mass_j_per_c).exp();
+        let mut temp = cold.core_temp_c;
+        let mut whole = 0;
+        while temp < compound.optimal_temp_min {
+            temp = equilibrium + (temp - equilibrium) * decay;
+            whole += 1;
+        }
+        assert_eq!(whole, laps.ceil() as i32);
+
+        let hot = TireState {
+            surface_temp_c: 100.0,
+            core_temp_c: 98.0,
+            wear: 0.0,
+        };
+        assert_eq!(
+            laps_to_operating_temp(&hot, compound, heat, distance, None),
+            0.0
+        );
+        assert_eq!(
+            laps_to_operating_temp(&cold, compound, 150_000.0, distance, None),
+            f32::INFINITY
+        );
+    }
+
+    #[test]
+    fn noiseless_sensor_converges_with_its_time_constant() {
+        let mut sensor = TemperatureSensor {
+            noise_amplitude_c: 0.0,
+            ..TemperatureSensor::default()
+        };
+        let dt = 1.0 / 60.0;
+        for _ in 0..30 {
+            sensor_step(&mut sensor, 95.0, 1.0, dt);
+        }
+        // One time constant: 1 - 1/e of the step.
+        let expected = 95.0 - 70.0 * (-1.0_f32).exp();
+        assert!((sensor.measured_c - expected).abs() < 1.0e-3);
+        for _ in 0..600 {
+            sensor_step(&mut sensor, 95.0, -1.0, dt);
+        }
+        assert!((sensor.measured_c - 95.0).abs() < 1.0e-3);
+    }
+
+    #[test]
+    fn sensor_noise_stays_within_its_amplitude_at_any_rate() {
+        for dt in [1.0 / 60.0, 1.0 / 1000.0] {
+            let mut sensor = TemperatureSensor {
+                measured_c: 80.0,
+                ..TemperatureSensor::default()
+            };
+            let mut seed = 7_u32;
+            for _ in 0..2000 {
+                seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                let rng = (seed >> 8) as f32 / (1 << 24) as f32 * 2.0 - 1.0;
+                sensor_step(&mut sensor, 80.0, rng, dt);
+                let error = (sensor.reading_c - sensor.measured_c).abs();
+                assert!(error <= sensor.noise_amplitude_c + 1.0e-4, "dt {dt}");
+                assert_eq!(sensor.measured_c, 80.0, "dt {dt}");
+            }
+        }
+    }
+
+    #[test]
+    fn radiated_heat_feeds_the_thermal_step() {
+        use crate::wear::{tire_wear_thermal_step, tire_wear_thermal_step_with_heat, LapSample};
+
+        let coupling = 0.9 * 5.67e-8 * 0.3;
+        let sample = LapSample {
+            speed: 20.0,
+            ..LapSample::default()
+        };
+        let mut inner = TireState {
+            surface_temp_c: 95.0,
+            ..TireState::default()
+        };
+        let mut outer = TireState {
+            surface_temp_c: 40.0,
+            ..TireState::default()
+        };
+        let (mut plain_inner, mut plain_outer) = (inner, outer);
+        let dt = 0.1;
+        for _ in 0..100 {
+            let (into_inner, into_outer) = tire_radiative_coupling(
+                inner.surface_temp_c,
+                outer.surface_temp_c,
+                0.6,
+                coupling,
+                dt,
+            );
+            inner = tire_wear_thermal_step_with_heat(inner, &sample, None, into_inner / dt, dt);
+            outer = tire_wear_thermal_step_with_heat(outer, &sample, None, into_outer / dt, dt);
+            plain_inner = tire_wear_thermal_step(plain_inner, &sample, None, dt);
+            plain_outer = tire_wear_thermal_step(plain_outer, &sample, None, dt);
+        }
+        assert!(outer.surface_temp_c > plain_outer.surface_temp_c);
+        assert!(inner.surface_temp_c < plain_inner.surface_temp_c);
+        // External heat does not wear the tyre, and zero input is the plain step.
+        assert_eq!(outer.wear, plain_outer.wear);
+        assert_eq!(
+            tire_wear_thermal_step_with_heat(inner, &sample, None, 0.0, dt),
+            tire_wear_thermal_step(inner, &sample, None, dt)
+        );
+        assert_eq!(
+            tire_wear_thermal_step_with_heat(inner, &sample, None, f32::NAN, dt),
+            tire_wear_thermal_step(inner, &sample, None, dt)
+        );
+    }
+}
@@ -0,0 +1,331 @@
+//! Crate-wide `delta` policy for step functions.
+//!
+//! - A finite `delta > 0` integrates forward.
+//! - A zero, negative or non-finite `delta` returns the input state unchanged.
+//! - The `_ex` variants report what happened as a status bitmask. When they are
+//!   called with `allow_reverse`, a finite negative `delta` integrates the
+//!   reversible quantities (temperatures) backwards.
+//! - Wear and consumed mass never run backwards.
+//! - Rates are per second of simulated time; no step function assumes a tick
+//!   rate. For slow motion or fast forward pass `scaled_delta(delta, time_scale)`
+//!   as the step's `delta`. Per-step inputs (distance rolled, sliding energy)
+//!   must cover the same scaled interval.
+
+pub const STEP_STATUS_OK: u32 = 0;
+/// `delta` was zero, negative (without `allow_reverse`) or non-finite; the state is unchanged.
+pub const STEP_STATUS_DELTA_SKIPPED: u32 = 1 << 0;
+/// A negative `delta` was integrated backwards.
+pub const STEP_STATUS_REVERSED: u32 = 1 << 1;
+/// An output temperature was clamped to the configured physical bounds.
+pub const STEP_STATUS_TEMPERATURE_CLAMPED: u32 = 1 << 2;
+
+/// Resolves `delta` under the policy: returns the delta to integrate (0 when
+/// skipped) and the status bits.
+pub fn resolve_delta(delta: f32, allow_reverse: bool) -> (f32, u32) {
+    if !delta.is_finite() || delta == 0.0 {
+        return (0.0, STEP_STATUS_DELTA_SKIPPED);
+    }
+    if delta > 0.0 {
+        (delta, STEP_STATUS_OK)
+    } else if allow_reverse {
+        (delta, STEP_STATUS_REVERSED)
+    } else {
+        (0.0, STEP_STATUS_DELTA_SKIPPED)
+    }
+}
+
+/// Simulated time for a wall-clock `delta` at `time_scale` (0.1 = slow motion,
+/// 10 = fast forward). A non-finite or negative scale yields 0, which every
+/// step function skips.
+pub fn scaled_delta(delta: f32, time_scale: f32) -> f32 {
+    if !time_scale.is_finite() || time_scale < 0.0 {
+        return 0.0;
+    }
+    delta * time_scale
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contamination::{tire_step_contamination, ContaminationState};
+    use crate::contract::{ContactSampleRaw, CoreInput, WheelState};
+    use crate::drivetrain::{
+        driveline_windup_step, driveline_windup_step_ex, motor_step, MotorInput, MotorParams,
+        MotorState, WindupParams, WindupState,
+    };
+    use crate::estimator::{tire_estimator_step, EstimatorParams, EstimatorState};
+    use crate::nvh::{rumble_strip_vibration, VibrationState};
+    use crate::stiction::{
+        solve_core_with_stiction, solve_core_with_stiction_ex, PatchStictionState, StictionParams,
+    };
+    use crate::suspension::{suspension_step, QuarterCarParams, QuarterCarState};
+    use crate::thermal::{
+        couple_core_surface, couple_core_surface_ex, surface_thermal_step, tire_blanket_step,
+        SurfaceThermal,
+    };
+    use crate::tire_state::TireState;
+    use crate::transients::{
+        tire_step_relaxation, RelaxationParams, RelaxationState, TransientLimits,
+        ENERGY_CLAMP_REFERENCE_DT,
+    };
+    use crate::vehicle::{
+        consumable_mass_step, ConsumableDraw, ConsumableMassState, ConsumableParams,
+    };
+    use crate::wear::{tire_wear_thermal_step, LapSample};
+    use crate::Vec2;
+
+    const BAD_DELTAS: [f32; 4] = [0.0, -1.0 / 60.0, f32::NAN, f32::NEG_INFINITY];
+
+    #[test]
+    fn step_functions_leave_state_unchanged_for_bad_delta() {
+        for delta in BAD_DELTAS {
+            let coupled = couple_core_surface(110.0, 70.0, 40.0, 2000.0, 6000.0, delta);
+            assert_eq!(coupled, (110.0, 70.0));
+
+            let mut tire = TireState::default();
+            tire_blanket_step(&mut tire, 90.0, 2000.0, 9000.0, delta);
+            assert_eq!(tire, TireState::default());
+
+            let mut vibration = VibrationState {
+                amplitude: 1.0,
+                frequency_hz: 10.0,
+            };
+            rumble_strip_vibration(20.0, 0.3, 0.5, 4.0, &mut vibration, delta);
+            assert_eq!(vibration.amplitude, 1.0);
+
+            let quarter = QuarterCarState {
+                sprung_height_m: 0.7,
+                unsprung_height_m: 0.3,
+                ..QuarterCarState::default()
+            };
+            let params = QuarterCarParams::default();
+            assert_eq!(suspension_step(quarter, params, delta), quarter);
+
+            let fuel = ConsumableMassState {
+                remaining_kg: 40.0,
+                ran_dry: false,
+            };
+            let draw = ConsumableDraw::MassFlowKgPerS(0.01);
+            let stepped = consumable_mass_step(fuel, draw, delta, ConsumableParams::default());
+            assert_eq!(stepped, fuel);
+
+            let input = MotorInput {
+                throttle: 1.0,
+                rpm: 3000.0,
+                delta,
+            };
+            let motor = motor_step(MotorState::default(), input, MotorParams::default());
+            assert_eq!(
+                motor.new_winding_temperature,
+                MotorState::default().winding_temperature
+            );
+
+            let wound = WindupState { twist_rad: 0.01 };
+            let windup = driveline_windup_step(wound, 91.0, 90.0, &WindupParams::default(), delta);
+            assert_eq!(windup.new_state, wound);
+            assert_eq!(windup.coupling_torque, 20.0);
+
+            let held = PatchStictionState {
+                displacement: Vec2 { x: 0.002, y: 0.0 },
+                hold_weight: 1.0,
+            };
+            let creeping = creeping_wheel();
+            let (_, stiction) =
+                solve_core_with_stiction(&creeping, held, StictionParams::default(), delta);
+            assert_eq!(stiction, held);
+        }
+    }
+
+    fn creeping_wheel() -> CoreInput {
+        CoreInput {
+            wheel: WheelState {
+                velocity_local_z: 0.05,
+                tire_radius: 0.33,
+                ..WheelState::default()
+            },
+            samples: vec![ContactSampleRaw {
+                penetration: 0.02,
+                confidence: 1.0,
+                normal_local: [0.0, 1.0, 0.0],
+                ..ContactSampleRaw::default()
+            }],
+            ..CoreInput::default()
+        }
+    }
+
+    #[test]
+    fn ex_variant_reports_status_and_reverses_only_on_request() {
+        for delta in BAD_DELTAS {
+            let (s, c, status) =
+                couple_core_surface_ex(110.0, 70.0, 40.0, 2000.0, 6000.0, delta, false);
+            assert_eq!((s, c), (110.0, 70.0));
+            assert_eq!(status, STEP_STATUS_DELTA_SKIPPED);
+
+            let params = WindupParams::default();
+            let (_, status) =
+                driveline_windup_step_ex(WindupState::default(), 91.0, 90.0, &params, delta);
+            assert_eq!(status, STEP_STATUS_DELTA_SKIPPED);
+            let stiction = StictionParams::default();
+            let (_, _, status) = solve_core_with_stiction_ex(
+                &creeping_wheel(),
+                PatchStictionState::default(),
+                stiction,
+                delta,
+            );
+            assert_eq!(status, STEP_STATUS_DELTA_SKIPPED);
+        }
+        let (windup, status) = driveline_windup_step_ex(
+            WindupState::default(),
+            91.0,
+            90.0,
+            &WindupParams::default(),
+            1.0 / 60.0,
+        );
+        assert_eq!(status, STEP_STATUS_OK);
+        assert!(windup.new_state.twist_rad > 0.0);
+        let (_, held, status) = solve_core_with_stiction_ex(
+            &creeping_wheel(),
+            PatchStictionState::default(),
+            StictionParams::default(),
+            1.0 / 60.0,
+        );
+        assert_eq!(status, STEP_STATUS_OK);
+        assert!(held.hold_weight > 0.0);
+
+        let (s, c, status) = couple_core_surface_ex(110.0, 70.0, 40.0, 2000.0, 6000.0, 5.0, false);
+        assert_eq!(status, STEP_STATUS_OK);
+        let (rs, rc, status) = couple_core_surface_ex(s, c, 40.0, 2000.0, 6000.0, -5.0, true);
+        assert_eq!(status, STEP_STATUS_REVERSED);
+        assert!((rs - 110.0).abs() < 1.0e-2 && (rc - 70.0).abs() < 1.0e-2);
+    }
+
+    /// (steps, delta): 60 Hz, 0.1x slow motion and 10x fast forward.
+    const SCALE_CASES: [(usize, f32); 3] = [(10, 1.0 / 60.0), (100, 1.0 / 600.0), (6, 1.0 / 6.0)];
+
+    fn close(a: f32, b: f32, tol: f32) -> bool {
+        (a - b).abs() <= tol * a.abs().max(b.abs()).max(1.0)
+    }
+
+    #[test]
+    fn n_steps_of_delta_match_one_step_of_n_delta() {
+        let sample = LapSample {
+            load: 4000.0,
+            slip_ratio: 0.08,
+            slip_angle: 0.05,
+            speed: 45.0,
+            duration_s: 1.0,
+        };
+        for (n, d) in SCALE_CASES {
+            let total = n as f32 * d;
+
+            // Wear and thermal.
+            let warm = TireState {
+                surface_temp_c: 70.0,
+                core_temp_c: 50.0,
+                wear: 0.2,
+            };
+            let mut tire = warm;
+            for _ in 0..n {
+                tire = tire_wear_thermal_step(tire, &sample, None, d);
+            }
+            let once = tire_wear_thermal_step(warm, &sample, None, total);
+            assert!(
+                close(tire.surface_temp_c, once.surface_temp_c, 1.0e-3),
+                "{n}x{d}"
+            );
+            assert!(close(tire.core_temp_c, once.core_temp_c, 1.0e-3));
+            assert!(close(tire.wear, once.wear, 1.0e-4));
+
+            let (mut s, mut c) = (110.0, 70.0);
+            for _ in 0..n {
+                (s, c) = couple_core_surface(s, c, 40.0, 2000.0, 6000.0, d);
+            }
+            let (s1, c1) = couple_core_surface(110.0, 70.0, 40.0, 2000.0, 6000.0, total);
+            assert!(close(s, s1, 1.0e-4) && close(c, c1, 1.0e-4));
+
+            let mut road = SurfaceThermal::default();
+            for _ in 0..n {
+                surface_thermal_step(&mut road, 4000.0, 25.0, 50.0, d);
+            }
+            let mut road_once = SurfaceThermal::default();
+            surface_thermal_step(&mut road_once, 4000.0, 25.0, 50.0, total);
+            assert!(close(road.temperature_c, road_once.temperature_c, 1.0e-4));
+
+            // Relaxation lengths: both slips close on constant targets, slowly
+            // enough at 2 m/s that the longest case has not fully settled.
+            let relax = RelaxationParams::default();
+            let lagging = RelaxationState {
+                slip_ratio: 0.0,
+                slip_angle: -0.02,
+            };
+            let mut slips = lagging;
+            for _ in 0..n {
+                slips = tire_step_relaxation(slips, 0.1, 0.06, 2.0, 3000.0, &relax, d);
+            }
+            let slips_once = tire_step_relaxation(lagging, 0.1, 0.06, 2.0, 3000.0, &relax, total);
+            assert!(
+                close(slips.slip_ratio, slips_once.slip_ratio, 1.0e-4),
+                "{n}x{d}"
+            );
+            assert!(close(slips.slip_angle, slips_once.slip_angle, 1.0e-4));
+
+            // Estimator: the fit and peak-grip decay.
+            let params = EstimatorParams::default();
+            let primed = EstimatorState {
+                estimated_mu: 1.2,
+                ..EstimatorState::default()
+            };
+            let mut est = primed;
+            for _ in 0..n {
+                est = tire_estimator_step(est, 2000.0, 0.03, 4000.0, d, params);
+            }
+            let est_once = tire_estimator_step(primed, 2000.0, 0.03, 4000.0, total, params);
+            assert!(close(est.estimated_mu, est_once.estimated_mu, 1.0e-4));
+            assert!(close(est.information, est_once.information, 1.0e-3));
+            assert!(close(
+                est.estimated_stiffness,
+                est_once.estimated_stiffness,
+                1.0e-3
+            ));
+
+            // Contamination: per-step distance covers the same interval.
+            let wet = ContaminationState {
+                water_film_on_tread: 0.9,
+                dust_film: 0.1,
+            };
+            let mut film = wet;
+            for _ in 0..n {
+                film = tire_step_contamination(film, 0.2, 0.4, 100.0 * d, 30.0 * d, d);
+            }
+            let film_once =
+                tire_step_contamination(wet, 0.2, 0.4, 100.0 * total, 30.0 * total, total);
+            assert!(close(
+                film.water_film_on_tread,
+                film_once.water_film_on_tread,
+                1.0e-3
+            ));
+            assert!(close(film.dust_film, film_once.dust_film, 1.0e-3));
+
+            // Kerb vibration.
+            let mut vib = VibrationState::default();
+            for _ in 0..n {
+                rumble_strip_vibration(30.0, 0.5, 0.2, 8.0, &mut vib, d);
+            }
+            let mut vib_once = VibrationState::default();
+            rumble_strip_vibration(30.0, 0.5, 0.2, 8.0, &mut vib_once, total);
+            assert!(close(vib.amplitude, vib_once.amplitude, 1.0e-4));
+        }
+    }
+
+    #[test]
+    fn scaled_delta_rejects_bad_scale() {
+        assert_eq!(scaled_delta(1.0 / 60.0, 10.0), 10.0 / 60.0);
+        assert_eq!(scaled_delta(1.0 / 60.0, f32::NAN), 0.0);
+        assert_eq!(scaled_delta(1.0 / 60.0, -1.0), 0.0);
+
+        let limits = TransientLimits::default();
+        let per_tick = limits.energy_limit_for(ENERGY_CLAMP_REFERENCE_DT);
+        assert!((per_tick - limits.max_energy_delta_per_tick).abs() < 1.0e-3);
+        assert!((limits.energy_limit_for(10.0 / 60.0) - 10.0 * per_tick).abs() < 1.0e-1);
+    }
+}
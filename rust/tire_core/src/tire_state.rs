@@ -0,0 +1,655 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::failure::FailureState;
+
+/// Persistent per-tire thermal and wear state (see `TireState` in
+/// `docs/ARCHITECTURE_CURRENT.md`). `wear` runs from 0 (new) to 1 (worn out).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TireState {
+    pub surface_temp_c: f32,
+    pub core_temp_c: f32,
+    pub wear: f32,
+}
+
+impl Default for TireState {
+    fn default() -> Self {
+        Self {
+            surface_temp_c: 25.0,
+            core_temp_c: 25.0,
+            wear: 0.0,
+        }
+    }
+}
+
+/// Compound-level parameters shared by the thermal and wear helpers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TireCompound {
+    pub optimal_temp_min: f32,
+    pub optimal_temp_max: f32,
+    pub thermal_mass_j_per_c: f32,
+    /// Abrasion rate relative to the medium compound.
+    pub wear_rate_scale: f32,
+    /// Wear past which `classify_tire` calls the tyre worn (grip falls away).
+    pub worn_wear: f32,
+}
+
+impl TireCompound {
+    pub const SOFT: Self = Self {
+        optimal_temp_min: 85.0,
+        optimal_temp_max: 110.0,
+        thermal_mass_j_per_c: 9000.0,
+        wear_rate_scale: 1.4,
+        worn_wear: 0.6,
+    };
+    pub const MEDIUM: Self = Self {
+        optimal_temp_min: 95.0,
+        optimal_temp_max: 120.0,
+        thermal_mass_j_per_c: 10000.0,
+        wear_rate_scale: 1.0,
+        worn_wear: 0.7,
+    };
+    pub const HARD: Self = Self {
+        optimal_temp_min: 105.0,
+        optimal_temp_max: 130.0,
+        thermal_mass_j_per_c: 11000.0,
+        wear_rate_scale: 0.7,
+        worn_wear: 0.8,
+    };
+}
+
+impl Default for TireCompound {
+    fn default() -> Self {
+        Self::MEDIUM
+    }
+}
+
+/// Static tyre geometry and carcass stiffness, shared by helpers that need the
+/// physical size of the tyre. Stiffnesses are quoted at `nominal_pressure_kpa`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TireDefinition {
+    pub radius_m: f32,
+    pub width_m: f32,
+    pub sidewall_height_m: f32,
+    pub vertical_stiffness: f32,
+    pub lateral_stiffness: f32,
+    pub longitudinal_stiffness: f32,
+    pub nominal_pressure_kpa: f32,
+}
+
+impl Default for TireDefinition {
+    fn default() -> Self {
+        Self {
+            radius_m: 0.33,
+            width_m: 0.225,
+            sidewall_height_m: 0.11,
+            vertical_stiffness: 200000.0,
+            lateral_stiffness: 150000.0,
+            longitudinal_stiffness: 300000.0,
+            nominal_pressure_kpa: 220.0,
+        }
+    }
+}
+
+/// True when both surface and core sit inside the compound's operating window.
+pub fn is_at_operating_temp(state: &TireState, compound: TireCompound) -> bool {
+    let window = compound.optimal_temp_min..=compound.optimal_temp_max;
+    window.contains(&state.surface_temp_c) && window.contains(&state.core_temp_c)
+}
+
+/// What the driver should do to bring the tyre into its window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WarmupAction {
+    #[default]
+    Ok,
+    /// Surface is the coldest node: scrub it with lateral slip.
+    Weave,
+    /// Core is the coldest node: drag the brakes to soak heat through the wheel.
+    BrakeDrag,
+    /// A node is above the window: back off to let it cool.
+    EaseOff,
+}
+
+/// Distance of each node from the compound's operating window and a pressure
+/// correction. Temperature deltas are `0` inside the window, positive when the
+/// node needs heat and negative when it is too hot; `pressure_delta` is
+/// `target - current` in kPa.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WarmupAdvice {
+    pub window_delta_surface: f32,
+    pub window_delta_core: f32,
+    pub pressure_delta: f32,
+    pub recommended_action: WarmupAction,
+}
+
+fn window_delta(temp_c: f32, compound: TireCompound) -> f32 {
+    if !temp_c.is_finite() {
+        return 0.0;
+    }
+    if temp_c < compound.optimal_temp_min {
+        compound.optimal_temp_min - temp_c
+    } else if temp_c > compound.optimal_temp_max {
+        compound.optimal_temp_max - temp_c
+    } else {
+        0.0
+    }
+}
+
+/// Warm-up guidance for AI and tutor UIs. The action follows the node furthest
+/// from `compound`'s window, so it adapts when the compound changes. The
+/// compound carries no pressure target; pass the hot target (e.g.
+/// `TireDefinition::nominal_pressure_kpa`) as `target_pressure_kpa`.
+pub fn tire_warmup_advice(
+    surface_temp_c: f32,
+    core_temp_c: f32,
+    pressure_kpa: f32,
+    target_pressure_kpa: f32,
+    compound: TireCompound,
+) -> WarmupAdvice {
+    let surface = window_delta(surface_temp_c, compound);
+    let core = window_delta(core_temp_c, compound);
+    let pressure_delta = target_pressure_kpa - pressure_kpa;
+    let (furthest, is_surface) = if surface.abs() >= core.abs() {
+        (surface, true)
+    } else {
+        (core, false)
+    };
+    let recommended_action = if furthest < 0.0 {
+        WarmupAction::EaseOff
+    } else if furthest == 0.0 {
+        WarmupAction::Ok
+    } else if is_surface {
+        WarmupAction::Weave
+    } else {
+        WarmupAction::BrakeDrag
+    };
+    WarmupAdvice {
+        window_delta_surface: surface,
+        window_delta_core: core,
+        pressure_delta: if pressure_delta.is_finite() {
+            pressure_delta
+        } else {
+            0.0
+        },
+        recommended_action,
+    }
+}
+
+/// Named compound of a tyre set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CompoundId {
+    Soft,
+    #[default]
+    Medium,
+    Hard,
+}
+
+impl CompoundId {
+    pub fn compound(self) -> TireCompound {
+        match self {
+            CompoundId::Soft => TireCompound::SOFT,
+            CompoundId::Medium => TireCompound::MEDIUM,
+            CompoundId::Hard => TireCompound::HARD,
+        }
+    }
+}
+
+/// Preparation of a set fitted fresh.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ScrubLevel {
+    #[default]
+    New,
+    /// Run in for a lap beforehand: one heat cycle and `SCRUBBED_WEAR_OFFSET`.
+    Scrubbed,
+}
+
+/// Wear a scrubbed set starts with.
+pub const SCRUBBED_WEAR_OFFSET: f32 = 0.01;
+
+/// Conditions that belong to the car and track, not to a tyre set, and so
+/// survive a set change.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TireEnvironment {
+    pub ambient_temp_c: f32,
+    pub track_temp_c: f32,
+}
+
+impl Default for TireEnvironment {
+    fn default() -> Self {
+        Self {
+            ambient_temp_c: 25.0,
+            track_temp_c: 30.0,
+        }
+    }
+}
+
+/// Everything about a fitted tyre: the set's own history plus the environment
+/// it runs in.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TireFullState {
+    pub compound: CompoundId,
+    pub tire: TireState,
+    pub heat_cycles: u32,
+    pub flat_spot: FlatSpot,
+    pub environment: TireEnvironment,
+}
+
+/// A set taken off the car: what it carries to its next fitting.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StoredSetRecord {
+    pub compound: CompoundId,
+    pub wear: f32,
+    pub heat_cycles: u32,
+    pub flat_spot: FlatSpot,
+}
+
+/// A fresh set at ambient temperature; a scrubbed set has one heat cycle and
+/// `SCRUBBED_WEAR_OFFSET` wear already.
+pub fn tire_fit_new_set(
+    environment: TireEnvironment,
+    compound: CompoundId,
+    scrub: ScrubLevel,
+) -> TireFullState {
+    let (wear, heat_cycles) = match scrub {
+        ScrubLevel::New => (0.0, 0),
+        ScrubLevel::Scrubbed => (SCRUBBED_WEAR_OFFSET, 1),
+    };
+    tire_refit_used_set(
+        &StoredSetRecord {
+            compound,
+            wear,
+            heat_cycles,
+            flat_spot: FlatSpot::default(),
+        },
+        environment,
+    )
+}
+
+/// Keeps the set's wear, ageing and flat spot; temperatures are dropped since
+/// they will have settled to ambient by the next fitting.
+pub fn tire_store_used_set(state: &TireFullState) -> StoredSetRecord {
+    StoredSetRecord {
+        compound: state.compound,
+        wear: state.tire.wear,
+        heat_cycles: state.heat_cycles,
+        flat_spot: state.flat_spot,
+    }
+}
+
+/// Refits a stored set in `environment`, both temperature nodes at ambient.
+pub fn tire_refit_used_set(
+    record: &StoredSetRecord,
+    environment: TireEnvironment,
+) -> TireFullState {
+    TireFullState {
+        compound: record.compound,
+        tire: TireState {
+            surface_temp_c: environment.ambient_temp_c,
+            core_temp_c: environment.ambient_temp_c,
+            wear: record.wear,
+        },
+        heat_cycles: record.heat_cycles,
+        flat_spot: record.flat_spot,
+        environment,
+    }
+}
+
+/// Categorical tyre condition for AI strategy. `as u8` gives the stable id
+/// used across language boundaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(u8)]
+pub enum TireCategory {
+    Cold = 0,
+    #[default]
+    Optimal = 1,
+    Overheated = 2,
+    Worn = 3,
+    WornAndOverheated = 4,
+    Burst = 5,
+}
+
+/// Width of the membership ramps centred on each temperature window edge.
+pub const CLASSIFY_TEMP_RAMP_C: f32 = 10.0;
+/// Width of the worn ramp centred on `TireCompound::worn_wear`.
+pub const CLASSIFY_WEAR_RAMP: f32 = 0.1;
+
+/// Fuzzy memberships in `[0, 1]` behind `classify_tire`. Temperature reads the
+/// mean of surface and core; each ramp crosses 0.5 exactly at its threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TireCategoryMembership {
+    pub cold: f32,
+    pub optimal: f32,
+    pub overheated: f32,
+    pub worn: f32,
+}
+
+fn ramp(value: f32, threshold: f32, width: f32) -> f32 {
+    let m = ((value - threshold) / width + 0.5).clamp(0.0, 1.0);
+    if m.is_finite() {
+        m
+    } else {
+        0.0
+    }
+}
+
+pub fn tire_category_membership(
+    state: &TireState,
+    compound: TireCompound,
+) -> TireCategoryMembership {
+    let temp = 0.5 * (state.surface_temp_c + state.core_temp_c);
+    let cold = ramp(compound.optimal_temp_min, temp, CLASSIFY_TEMP_RAMP_C);
+    let overheated = ramp(temp, compound.optimal_temp_max, CLASSIFY_TEMP_RAMP_C);
+    TireCategoryMembership {
+        cold,
+        optimal: 1.0 - cold.max(overheated),
+        overheated,
+        worn: ramp(state.wear, compound.worn_wear, CLASSIFY_WEAR_RAMP),
+    }
+}
+
+/// Strongest category, from `tire_category_membership`: a membership above
+/// 0.5 holds, so the window edges and `worn_wear` themselves still count as
+/// optimal and unworn. Wear of 1 (worn through to the cords) is `Burst`;
+/// worn and cold reads `Worn`. A non-finite temperature or wear means the
+/// state can no longer be trusted, and fails safe to `Burst` as well.
+pub fn classify_tire(state: &TireState, compound: TireCompound) -> TireCategory {
+    let finite =
+        state.surface_temp_c.is_finite() && state.core_temp_c.is_finite() && state.wear.is_finite();
+    if !finite || state.wear >= 1.0 {
+        return TireCategory::Burst;
+    }
+    let m = tire_category_membership(state, compound);
+    match (m.worn > 0.5, m.overheated > 0.5, m.cold > 0.5) {
+        (true, true, _) => TireCategory::WornAndOverheated,
+        (true, false, _) => TireCategory::Worn,
+        (false, true, _) => TireCategory::Overheated,
+        (false, false, true) => TireCategory::Cold,
+        (false, false, false) => TireCategory::Optimal,
+    }
+}
+
+/// `classify_tire`, also reporting `Burst` for a blown-out carcass.
+pub fn classify_tire_with_failure(
+    state: &TireState,
+    compound: TireCompound,
+    failure: &FailureState,
+) -> TireCategory {
+    if failure.is_blown_out() {
+        TireCategory::Burst
+    } else {
+        classify_tire(state, compound)
+    }
+}
+
+/// Default share of slip power heating the tread surface node.
+pub const DEFAULT_SURFACE_HEAT_FRACTION: f32 = 0.6;
+/// Default share of slip power heating the core directly; by default the core
+/// is heated only through the surface.
+pub const DEFAULT_CORE_HEAT_FRACTION: f32 = 0.0;
+/// Default share of slip power that never enters the tyre: it heats the road
+/// and leaves with the abraded rubber. The three default fractions sum to 1.
+pub const DEFAULT_ROAD_HEAT_FRACTION: f32 = 0.4;
+
+/// Every tuning constant of `tire_wear_thermal_step`; `default()` holds the
+/// stock values. Heat capacities are per tyre (J/°C), convection grows linearly
+/// with speed, and the heat fractions split slip power between the surface and
+/// core nodes; the remainder (`DEFAULT_ROAD_HEAT_FRACTION` by default) goes
+/// into the road and the abraded rubber. Surface and core exchange heat by
+/// conduction through `tread_thickness_m` of rubber over `tread_area_m2`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TireSimParameters {
+    pub friction: f32,
+    pub max_slip_angle_rad: f32,
+    pub surface_heat_fraction: f32,
+    pub core_heat_fraction: f32,
+    pub surface_heat_capacity: f32,
+    pub core_heat_capacity: f32,
+    pub tread_conductivity_w_per_m_k: f32,
+    pub tread_thickness_m: f32,
+    pub tread_area_m2: f32,
+    pub convection_base: f32,
+    pub convection_per_speed: f32,
+    pub ambient_temp_c: f32,
+    pub wear_per_joule: f32,
+    /// Physical bounds for every output temperature (°C).
+    pub temperature_min_c: f32,
+    pub temperature_max_c: f32,
+}
+
+impl TireSimParameters {
+    /// Stock parameters for `compound`: the surface and core heat capacities
+    /// rescaled so they sum to its `thermal_mass_j_per_c`, and `wear_per_joule`
+    /// scaled by its `wear_rate_scale`. The medium compound gives the stock
+    /// values.
+    pub fn for_compound(compound: TireCompound) -> Self {
+        let stock = Self::default();
+        let scale = compound.thermal_mass_j_per_c.max(0.0)
+            / (stock.surface_heat_capacity + stock.core_heat_capacity);
+        Self {
+            surface_heat_capacity: stock.surface_heat_capacity * scale,
+            core_heat_capacity: stock.core_heat_capacity * scale,
+            wear_per_joule: stock.wear_per_joule * compound.wear_rate_scale.max(0.0),
+            ..stock
+        }
+    }
+
+    /// Clamps `temp_c` to the configured bounds (non-finite goes to the lower
+    /// bound); the flag reports whether it moved.
+    pub fn clamp_temperature(&self, temp_c: f32) -> (f32, bool) {
+        let lo = self.temperature_min_c;
+        let hi = self.temperature_max_c.max(lo);
+        if !temp_c.is_finite() {
+            return (lo, true);
+        }
+        let clamped = temp_c.clamp(lo, hi);
+        (clamped, clamped != temp_c)
+    }
+}
+
+impl Default for TireSimParameters {
+    fn default() -> Self {
+        Self {
+            friction: 1.0,
+            max_slip_angle_rad: 1.4,
+            surface_heat_fraction: DEFAULT_SURFACE_HEAT_FRACTION,
+            core_heat_fraction: DEFAULT_CORE_HEAT_FRACTION,
+            surface_heat_capacity: 2500.0,
+            core_heat_capacity: 7500.0,
+            tread_conductivity_w_per_m_k: 0.25,
+            tread_thickness_m: 0.0025,
+            tread_area_m2: 0.4,
+            convection_base: 10.0,
+            convection_per_speed: 3.0,
+            ambient_temp_c: 25.0,
+            wear_per_joule: 5.0e-9,
+            temperature_min_c: -60.0,
+            temperature_max_c: 400.0,
+        }
+    }
+}
+
+/// Flat spot left by a locked wheel: depth and position around the tyre
+/// (wheel-local radians).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FlatSpot {
+    pub depth_mm: f32,
+    pub angle_rad: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warmup_advice_targets_the_furthest_node() {
+        let soft = TireCompound::SOFT;
+        let cold_surface = tire_warmup_advice(40.0, 80.0, 180.0, 220.0, soft);
+        assert_eq!(cold_surface.window_delta_surface, 45.0);
+        assert_eq!(cold_surface.pressure_delta, 40.0);
+        assert_eq!(cold_surface.recommended_action, WarmupAction::Weave);
+
+        let cold_core = tire_warmup_advice(80.0, 40.0, 220.0, 220.0, soft);
+        assert_eq!(cold_core.recommended_action, WarmupAction::BrakeDrag);
+
+        let hot = tire_warmup_advice(125.0, 90.0, 240.0, 220.0, soft);
+        assert_eq!(hot.window_delta_surface, -15.0);
+        assert_eq!(hot.recommended_action, WarmupAction::EaseOff);
+
+        let ready = tire_warmup_advice(95.0, 100.0, 220.0, 220.0, soft);
+        assert_eq!(ready.recommended_action, WarmupAction::Ok);
+    }
+
+    #[test]
+    fn warmup_advice_adapts_to_compound_window() {
+        let soft = tire_warmup_advice(100.0, 100.0, 220.0, 220.0, TireCompound::SOFT);
+        let hard = tire_warmup_advice(100.0, 100.0, 220.0, 220.0, TireCompound::HARD);
+        assert_eq!(soft.recommended_action, WarmupAction::Ok);
+        assert_eq!(hard.window_delta_core, 5.0);
+        assert_eq!(hard.recommended_action, WarmupAction::Weave);
+    }
+
+    #[test]
+    fn stored_set_round_trips_and_refits_at_ambient() {
+        let hot = TireEnvironment {
+            ambient_temp_c: 32.0,
+            track_temp_c: 45.0,
+        };
+        let mut running = tire_fit_new_set(hot, CompoundId::Soft, ScrubLevel::New);
+        assert_eq!(running.tire.wear, 0.0);
+        assert_eq!(running.tire.core_temp_c, 32.0);
+        running.tire = TireState {
+            surface_temp_c: 98.0,
+            core_temp_c: 92.0,
+            wear: 0.35,
+        };
+        running.heat_cycles = 3;
+        running.flat_spot = FlatSpot {
+            depth_mm: 0.8,
+            angle_rad: 1.2,
+        };
+
+        let record = tire_store_used_set(&running);
+        let cooler = TireEnvironment {
+            ambient_temp_c: 18.0,
+            track_temp_c: 22.0,
+        };
+        let refitted = tire_refit_used_set(&record, cooler);
+        assert_eq!(refitted.tire.wear, 0.35);
+        assert_eq!(refitted.heat_cycles, 3);
+        assert_eq!(refitted.flat_spot, running.flat_spot);
+        assert_eq!(refitted.compound, CompoundId::Soft);
+        assert_eq!(refitted.environment, cooler);
+        assert_eq!(refitted.tire.surface_temp_c, 18.0);
+        assert_eq!(tire_store_used_set(&refitted), record);
+    }
+
+    #[test]
+    fn scrubbed_set_starts_with_one_cycle_and_wear_offset() {
+        let env = TireEnvironment::default();
+        let scrubbed = tire_fit_new_set(env, CompoundId::Hard, ScrubLevel::Scrubbed);
+        assert_eq!(scrubbed.heat_cycles, 1);
+        assert_eq!(scrubbed.tire.wear, SCRUBBED_WEAR_OFFSET);
+        assert_eq!(scrubbed.flat_spot, FlatSpot::default());
+        assert_eq!(scrubbed.compound.compound(), TireCompound::HARD);
+    }
+
+    #[test]
+    fn classification_covers_every_category_at_its_boundaries() {
+        let c = TireCompound::MEDIUM;
+        let at = |temp: f32, wear: f32| {
+            classify_tire(
+                &TireState {
+                    surface_temp_c: temp,
+                    core_temp_c: temp,
+                    wear,
+                },
+                c,
+            )
+        };
+        let below = |x: f32| x - 0.01;
+        let above = |x: f32| x + 0.01;
+        let (min, max, worn) = (c.optimal_temp_min, c.optimal_temp_max, c.worn_wear);
+
+        assert_eq!(at(below(min), 0.0), TireCategory::Cold);
+        assert_eq!(at(min, 0.0), TireCategory::Optimal);
+        assert_eq!(at(max, 0.0), TireCategory::Optimal);
+        assert_eq!(at(above(max), 0.0), TireCategory::Overheated);
+        assert_eq!(at(100.0, worn), TireCategory::Optimal);
+        assert_eq!(at(100.0, above(worn)), TireCategory::Worn);
+        assert_eq!(at(below(min), above(worn)), TireCategory::Worn);
+        assert_eq!(at(above(max), above(worn)), TireCategory::WornAndOverheated);
+        assert_eq!(at(max, above(worn)), TireCategory::Worn);
+        assert_eq!(at(100.0, below(1.0)), TireCategory::Worn);
+        assert_eq!(at(100.0, 1.0), TireCategory::Burst);
+        assert_eq!(at(f32::NAN, 0.0), TireCategory::Burst);
+        assert_eq!(at(100.0, f32::NAN), TireCategory::Burst);
+        assert_eq!(at(f32::INFINITY, 0.0), TireCategory::Burst);
+
+        // Windows and wear limits follow the compound.
+        let hot_for_soft = TireState {
+            surface_temp_c: 115.0,
+            core_temp_c: 115.0,
+            wear: 0.65,
+        };
+        assert_eq!(
+            classify_tire(&hot_for_soft, TireCompound::SOFT),
+            TireCategory::WornAndOverheated
+        );
+        assert_eq!(
+            classify_tire(&hot_for_soft, TireCompound::HARD),
+            TireCategory::Optimal
+        );
+
+        let m = tire_category_membership(&hot_for_soft, TireCompound::MEDIUM);
+        assert_eq!(m.cold, 0.0);
+        assert!(m.optimal > 0.5 && m.overheated < 0.5 && m.worn == 0.0);
+
+        let blown = FailureState {
+            sidewall_damage: 1.0,
+        };
+        let fresh = TireState {
+            surface_temp_c: 100.0,
+            core_temp_c: 100.0,
+            wear: 0.0,
+        };
+        assert_eq!(
+            classify_tire_with_failure(&fresh, c, &blown),
+            TireCategory::Burst
+        );
+        assert_eq!(
+            classify_tire_with_failure(&fresh, c, &FailureState::default()),
+            TireCategory::Optimal
+        );
+        assert_eq!(TireCategory::Burst as u8, 5);
+    }
+
+    #[test]
+    fn compound_parameters_follow_thermal_mass_and_wear_rate() {
+        let stock = TireSimParameters::default();
+        let medium = TireSimParameters::for_compound(TireCompound::MEDIUM);
+        assert!((medium.surface_heat_capacity - stock.surface_heat_capacity).abs() < 1.0e-3);
+        assert!((medium.core_heat_capacity - stock.core_heat_capacity).abs() < 1.0e-3);
+        assert_eq!(medium.wear_per_joule, stock.wear_per_joule);
+        for compound in [TireCompound::SOFT, TireCompound::MEDIUM, TireCompound::HARD] {
+            let params = TireSimParameters::for_compound(compound);
+            let total = params.surface_heat_capacity + params.core_heat_capacity;
+            assert!((total - compound.thermal_mass_j_per_c).abs() < 1.0e-2);
+            let scale = params.wear_per_joule / stock.wear_per_joule;
+            assert!((scale - compound.wear_rate_scale).abs() < 1.0e-5);
+        }
+    }
+}
@@ -17,6 +17,21 @@ impl Default for TransientLimits {
     }
 }
 
+/// Tick length `max_energy_delta_per_tick` is tuned at.
+pub const ENERGY_CLAMP_REFERENCE_DT: f32 = 1.0 / 60.0;
+
+impl TransientLimits {
+    /// `max_energy_delta_per_tick` rescaled to a tick of `dt`, for
+    /// `clamp_energy_tick` under slow motion or fast forward. Keeps the power
+    /// limit, rather than the per-tick energy, constant.
+    pub fn energy_limit_for(&self, dt: f32) -> f32 {
+        if !dt.is_finite() || dt <= 0.0 {
+            return 0.0;
+        }
+        self.max_energy_delta_per_tick * dt / ENERGY_CLAMP_REFERENCE_DT
+    }
+}
+
 pub fn apply_slew(previous: f32, target: f32, dt: f32, limits: TransientLimits) -> f32 {
     let max_delta = (limits.slew_per_second * dt.max(0.0)).max(0.0);
     let delta = (target - previous).clamp(-max_delta, max_delta);
@@ -40,3 +55,159 @@ pub fn clamp_energy_tick(
     let scale = max_energy_delta / delta_e.max(1.0e-6);
     [force[0] * scale, force[1] * scale, force[2] * scale]
 }
+
+/// Relaxation lengths at `reference_load_n`. The lengths scale with the
+/// instantaneous load, floored at `min_length_fraction` of these, and the
+/// resulting time constant `length / speed` is clamped to
+/// `[min_time_constant_s, max_time_constant_s]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RelaxationParams {
+    pub lateral_length_m: f32,
+    pub longitudinal_length_m: f32,
+    pub reference_load_n: f32,
+    pub min_length_fraction: f32,
+    pub min_time_constant_s: f32,
+    pub max_time_constant_s: f32,
+}
+
+impl Default for RelaxationParams {
+    fn default() -> Self {
+        Self {
+            lateral_length_m: 0.6,
+            longitudinal_length_m: 0.25,
+            reference_load_n: 4000.0,
+            min_length_fraction: 0.1,
+            min_time_constant_s: 0.002,
+            max_time_constant_s: 0.25,
+        }
+    }
+}
+
+/// Lagged slips the force model reads instead of the kinematic ones.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RelaxationState {
+    pub slip_ratio: f32,
+    pub slip_angle: f32,
+}
+
+fn relaxation_blend(
+    length_m: f32,
+    load_scale: f32,
+    speed: f32,
+    params: &RelaxationParams,
+    delta: f32,
+) -> f32 {
+    let lo = params.min_time_constant_s.max(1.0e-6);
+    let hi = params.max_time_constant_s.max(lo);
+    let tau = (length_m.max(0.0) * load_scale / speed.max(1.0e-6)).clamp(lo, hi);
+    1.0 - (-delta / tau).exp()
+}
+
+/// First-order relaxation of both slips towards their kinematic values over a
+/// distance that shrinks with `normal_load`, so a nearly airborne tyre tracks
+/// its slip instead of landing with a stale one. Exact exponential update;
+/// invalid `delta` or non-finite targets leave the state alone.
+pub fn tire_step_relaxation(
+    state: RelaxationState,
+    slip_ratio: f32,
+    slip_angle: f32,
+    speed_m_per_s: f32,
+    normal_load: f32,
+    params: &RelaxationParams,
+    delta: f32,
+) -> RelaxationState {
+    if !delta.is_finite() || delta <= 0.0 || !slip_ratio.is_finite() || !slip_angle.is_finite() {
+        return state;
+    }
+    let speed = if speed_m_per_s.is_finite() {
+        speed_m_per_s.abs()
+    } else {
+        0.0
+    };
+    let load = if normal_load.is_finite() {
+        normal_load.max(0.0)
+    } else {
+        0.0
+    };
+    let floor = params.min_length_fraction.clamp(0.0, 1.0);
+    let load_scale = (load / params.reference_load_n.max(1.0)).max(floor);
+    let kx = relaxation_blend(
+        params.longitudinal_length_m,
+        load_scale,
+        speed,
+        params,
+        delta,
+    );
+    let ky = relaxation_blend(params.lateral_length_m, load_scale, speed, params, delta);
+    RelaxationState {
+        slip_ratio: state.slip_ratio + (slip_ratio - state.slip_ratio) * kx,
+        slip_angle: state.slip_angle + (slip_angle - state.slip_angle) * ky,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Peak lateral force after touchdown over the settled force, minus one,
+    /// for a crest: the load ramps off, the unloaded tyre drifts to a large
+    /// slip angle, then lands over 50 ms. `fixed` feeds the reference load
+    /// throughout, i.e. the old fixed-length filter.
+    fn landing_overshoot(fixed: bool) -> f32 {
+        let params = RelaxationParams::default();
+        let dt = 1.0 / 240.0;
+        let speed = 10.0;
+        let stiffness = 10.0;
+        let mut state = RelaxationState {
+            slip_ratio: 0.0,
+            slip_angle: 0.03,
+        };
+        let mut peak = 0.0_f32;
+        for i in 0..480 {
+            let t = i as f32 * dt;
+            let load = if t < 0.3 {
+                4000.0
+            } else if t < 0.4 {
+                4000.0 - 3900.0 * (t - 0.3) / 0.1
+            } else if t < 0.7 {
+                100.0
+            } else if t < 0.75 {
+                100.0 + 3900.0 * (t - 0.7) / 0.05
+            } else {
+                4000.0
+            };
+            let alpha = 0.03 + 0.17 * (1.0 - load / 4000.0).clamp(0.0, 1.0);
+            let filter_load = if fixed { params.reference_load_n } else { load };
+            state = tire_step_relaxation(state, 0.0, alpha, speed, filter_load, &params, dt);
+            if t >= 0.7 {
+                peak = peak.max(load * stiffness * state.slip_angle);
+            }
+        }
+        peak / (4000.0 * stiffness * 0.03) - 1.0
+    }
+
+    #[test]
+    fn load_dependent_relaxation_softens_the_landing_spike() {
+        let fixed = landing_overshoot(true);
+        let scaled = landing_overshoot(false);
+        assert!(scaled < 0.85 * fixed, "{scaled} vs {fixed}");
+    }
+
+    #[test]
+    fn relaxation_respects_time_constant_bounds() {
+        let params = RelaxationParams::default();
+        let start = RelaxationState::default();
+        // Standing still: clamped at the slowest time constant.
+        let slow = tire_step_relaxation(start, 0.1, 0.1, 0.0, 4000.0, &params, 0.25);
+        assert!((slow.slip_angle - 0.1 * (1.0 - (-1.0_f32).exp())).abs() < 1.0e-6);
+        // Airborne at speed: clamped at the fastest, effectively instant.
+        let fast = tire_step_relaxation(start, 0.1, 0.1, 300.0, 0.0, &params, 0.02);
+        assert!((fast.slip_angle - 0.1).abs() < 1.0e-4);
+        assert_eq!(
+            tire_step_relaxation(start, 0.1, 0.1, 10.0, 4000.0, &params, f32::NAN),
+            start
+        );
+    }
+}
@@ -0,0 +1,905 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::contact::ContactAggregate;
+use crate::{Vec2, Vec3};
+
+pub const GRAVITY: f32 = 9.81;
+
+/// Wheel order used by every per-wheel array in this module.
+pub const FRONT_LEFT: usize = 0;
+pub const FRONT_RIGHT: usize = 1;
+pub const REAR_LEFT: usize = 2;
+pub const REAR_RIGHT: usize = 3;
+
+/// Dry (empty tank / no consumable) mass layout. Positions are in the vehicle
+/// plane relative to the dry CG: `x` forward, `y` to the right.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VehicleMassProperties {
+    pub dry_mass_kg: f32,
+    pub wheelbase_m: f32,
+    pub track_width_m: f32,
+    pub cg_to_front_axle_m: f32,
+}
+
+impl Default for VehicleMassProperties {
+    fn default() -> Self {
+        Self {
+            dry_mass_kg: 1200.0,
+            wheelbase_m: 2.6,
+            track_width_m: 1.55,
+            cg_to_front_axle_m: 1.15,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConsumableParams {
+    pub capacity_kg: f32,
+    pub kg_per_joule: f32,
+    pub position_m: Vec2,
+}
+
+impl Default for ConsumableParams {
+    fn default() -> Self {
+        Self {
+            capacity_kg: 45.0,
+            kg_per_joule: 2.2e-8,
+            position_m: Vec2 { x: -1.1, y: 0.0 },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConsumableMassState {
+    pub remaining_kg: f32,
+    pub ran_dry: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ConsumableDraw {
+    PowerW(f32),
+    MassFlowKgPerS(f32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VehicleMassSummary {
+    pub total_mass_kg: f32,
+    pub cg_shift_m: Vec2,
+    pub wheel_loads_n: [f32; 4],
+}
+
+/// Drains the consumable by `draw` over `delta`. Remaining mass clamps at zero
+/// and `ran_dry` latches once it does.
+pub fn consumable_mass_step(
+    state: ConsumableMassState,
+    draw: ConsumableDraw,
+    delta: f32,
+    params: ConsumableParams,
+) -> ConsumableMassState {
+    if !delta.is_finite() || delta <= 0.0 {
+        return state;
+    }
+    let rate = match draw {
+        ConsumableDraw::PowerW(power) => power.max(0.0) * params.kg_per_joule.max(0.0),
+        ConsumableDraw::MassFlowKgPerS(flow) => flow.max(0.0),
+    };
+    let rate = if rate.is_finite() { rate } else { 0.0 };
+    let remaining_kg =
+        (state.remaining_kg.min(params.capacity_kg.max(0.0)) - rate * delta).max(0.0);
+    ConsumableMassState {
+        remaining_kg,
+        ran_dry: state.ran_dry || remaining_kg <= 0.0,
+    }
+}
+
+/// Static wheel loads for the dry mass plus the remaining consumable.
+pub fn static_wheel_loads(
+    mass: VehicleMassProperties,
+    consumable: ConsumableParams,
+    state: ConsumableMassState,
+) -> VehicleMassSummary {
+    let dry = mass.dry_mass_kg.max(0.0);
+    let fuel = state.remaining_kg.max(0.0);
+    let total_mass_kg = dry + fuel;
+    if total_mass_kg <= 0.0 || mass.wheelbase_m <= 0.0 {
+        return VehicleMassSummary::default();
+    }
+
+    let cg_shift_m = Vec2 {
+        x: consumable.position_m.x * fuel / total_mass_kg,
+        y: consumable.position_m.y * fuel / total_mass_kg,
+    };
+
+    let to_front = (mass.cg_to_front_axle_m - cg_shift_m.x).clamp(0.0, mass.wheelbase_m);
+    let front_share = 1.0 - to_front / mass.wheelbase_m;
+    let right_share = if mass.track_width_m > 0.0 {
+        (0.5 + cg_shift_m.y / mass.track_width_m).clamp(0.0, 1.0)
+    } else {
+        0.5
+    };
+
+    let weight = total_mass_kg * GRAVITY;
+    let front = weight * front_share;
+    let rear = weight - front;
+    let mut wheel_loads_n = [0.0; 4];
+    wheel_loads_n[FRONT_LEFT] = front * (1.0 - right_share);
+    wheel_loads_n[FRONT_RIGHT] = front * right_share;
+    wheel_loads_n[REAR_LEFT] = rear * (1.0 - right_share);
+    wheel_loads_n[REAR_RIGHT] = rear * right_share;
+
+    VehicleMassSummary {
+        total_mass_kg,
+        cg_shift_m,
+        wheel_loads_n,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HitchParams {
+    pub stiffness: f32,
+    pub damping: f32,
+    pub max_force: f32,
+    pub breakaway_force: f32,
+}
+
+impl Default for HitchParams {
+    fn default() -> Self {
+        Self {
+            stiffness: 200000.0,
+            damping: 8000.0,
+            max_force: 40000.0,
+            breakaway_force: 60000.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HitchForceResult {
+    pub force_on_trailer: Vec3,
+    pub force_on_tractor: Vec3,
+    pub broken_away: bool,
+}
+
+/// Spring-damper coupling between the two hitch points.
+/// `relative_position_error` is tractor hitch minus trailer hitch; the force on
+/// the trailer pulls it towards the tractor and the tractor gets the opposite.
+/// A spring-damper demand reaching `breakaway_force` (when > 0, an overflowing
+/// demand included) drops the coupling and sets `broken_away`; otherwise the
+/// force is clamped to `max_force`.
+pub fn hitch_force(
+    tractor_hitch_point_velocity: Vec3,
+    trailer_hitch_point_velocity: Vec3,
+    relative_position_error: Vec3,
+    params: HitchParams,
+) -> HitchForceResult {
+    let relative_velocity = tractor_hitch_point_velocity - trailer_hitch_point_velocity;
+    let mut force = relative_position_error * params.stiffness.max(0.0)
+        + relative_velocity * params.damping.max(0.0);
+
+    let magnitude = force.length();
+    if params.breakaway_force > 0.0
+        && (magnitude >= params.breakaway_force || magnitude == f32::INFINITY)
+    {
+        return HitchForceResult {
+            broken_away: true,
+            ..HitchForceResult::default()
+        };
+    }
+    if !magnitude.is_finite() {
+        // Huge errors overflow the spring term; fall back to the error direction.
+        let e = relative_position_error;
+        let scale = e.x.abs().max(e.y.abs()).max(e.z.abs());
+        force = if scale.is_finite() && scale > 0.0 {
+            (e * (1.0 / scale)).normalized() * params.max_force.max(0.0)
+        } else {
+            Vec3::default()
+        };
+    } else if magnitude > params.max_force.max(0.0) {
+        force = force.normalized() * params.max_force.max(0.0);
+    }
+
+    HitchForceResult {
+        force_on_trailer: force,
+        force_on_tractor: -force,
+        broken_away: false,
+    }
+}
+
+/// Signed yaw-rate mismatch (trailer minus tractor, rad/s). Its sign tells a
+/// sway controller which side to brake; its magnitude how hard.
+pub fn trailer_sway_metric(yaw_rate_trailer: f32, yaw_rate_tractor: f32) -> f32 {
+    let metric = yaw_rate_trailer - yaw_rate_tractor;
+    if metric.is_finite() {
+        metric
+    } else {
+        0.0
+    }
+}
+
+pub const MAX_ANTI_FRACTION: f32 = 1.5;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AntiGeometryParams {
+    pub anti_dive_fraction: f32,
+    pub anti_squat_fraction: f32,
+    pub cg_height: f32,
+    pub wheelbase: f32,
+}
+
+impl Default for AntiGeometryParams {
+    fn default() -> Self {
+        Self {
+            anti_dive_fraction: 0.3,
+            anti_squat_fraction: 0.4,
+            cg_height: 0.5,
+            wheelbase: 2.6,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AntiGeometryResult {
+    pub suspension_force_delta_per_wheel: [f32; 4],
+    pub link_force_delta_per_wheel: [f32; 4],
+    pub fractions_clamped: bool,
+}
+
+/// Splits the longitudinal load transfer into the part carried by the springs
+/// and the part reacted through the suspension links.
+///
+/// Longitudinal forces are per axle, positive forward (drive). The anti-dive
+/// fraction applies to the front axle force and the anti-squat fraction to the
+/// rear axle force, in both directions (so they also act as anti-lift).
+/// Fractions are clamped to `[0, MAX_ANTI_FRACTION]`; `fractions_clamped`
+/// reports when that happened.
+pub fn anti_geometry_forces(
+    longitudinal_force_front: f32,
+    longitudinal_force_rear: f32,
+    params: AntiGeometryParams,
+) -> AntiGeometryResult {
+    let clamp = |v: f32| {
+        if v.is_finite() {
+            v.clamp(0.0, MAX_ANTI_FRACTION)
+        } else {
+            0.0
+        }
+    };
+    let anti_dive = clamp(params.anti_dive_fraction);
+    let anti_squat = clamp(params.anti_squat_fraction);
+    let fractions_clamped =
+        anti_dive != params.anti_dive_fraction || anti_squat != params.anti_squat_fraction;
+
+    if params.wheelbase <= 1.0e-6 {
+        return AntiGeometryResult {
+            fractions_clamped,
+            ..AntiGeometryResult::default()
+        };
+    }
+    let ratio = params.cg_height / params.wheelbase;
+    let front_transfer = -(longitudinal_force_front + longitudinal_force_rear) * ratio;
+    let front_link = -longitudinal_force_front * ratio * anti_dive;
+    let rear_link = longitudinal_force_rear * ratio * anti_squat;
+    let front_spring = front_transfer - front_link;
+    let rear_spring = -front_transfer - rear_link;
+
+    let mut result = AntiGeometryResult {
+        fractions_clamped,
+        ..AntiGeometryResult::default()
+    };
+    for (wheel, (spring, link)) in [
+        (FRONT_LEFT, (front_spring, front_link)),
+        (FRONT_RIGHT, (front_spring, front_link)),
+        (REAR_LEFT, (rear_spring, rear_link)),
+        (REAR_RIGHT, (rear_spring, rear_link)),
+    ] {
+        result.suspension_force_delta_per_wheel[wheel] = 0.5 * spring;
+        result.link_force_delta_per_wheel[wheel] = 0.5 * link;
+    }
+    result
+}
+
+/// Sprung-body parameters for transient load transfer. The part of the
+/// transfer below the pitch/roll axis heights goes through the links instantly;
+/// the rest is carried by the springs and dampers and lags the body motion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VehicleConfig {
+    pub mass: VehicleMassProperties,
+    pub cg_height_m: f32,
+    pub pitch_axis_height_m: f32,
+    pub roll_axis_height_m: f32,
+    pub pitch_inertia_kg_m2: f32,
+    pub roll_inertia_kg_m2: f32,
+    pub pitch_stiffness_nm_per_rad: f32,
+    pub roll_stiffness_nm_per_rad: f32,
+    pub pitch_damping_nm_s_per_rad: f32,
+    pub roll_damping_nm_s_per_rad: f32,
+}
+
+impl Default for VehicleConfig {
+    fn default() -> Self {
+        Self {
+            mass: VehicleMassProperties::default(),
+            cg_height_m: 0.5,
+            pitch_axis_height_m: 0.1,
+            roll_axis_height_m: 0.08,
+            pitch_inertia_kg_m2: 1800.0,
+            roll_inertia_kg_m2: 500.0,
+            pitch_stiffness_nm_per_rad: 90000.0,
+            roll_stiffness_nm_per_rad: 70000.0,
+            pitch_damping_nm_s_per_rad: 9000.0,
+            roll_damping_nm_s_per_rad: 5000.0,
+        }
+    }
+}
+
+/// Sprung-body attitude. Pitch is positive nose-up, roll positive with the
+/// left side down (the attitude a right-hand turn produces).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VehicleInertiaState {
+    pub pitch_rate_rad_per_s: f32,
+    pub roll_rate_rad_per_s: f32,
+    pub pitch_rad: f32,
+    pub roll_rad: f32,
+}
+
+/// Longest internal step `vehicle_inertia_step` takes.
+pub const BODY_MAX_SUBSTEP_S: f32 = 0.005;
+
+/// Integrates body pitch and roll as damped rotational springs driven by the
+/// sprung moments `m * a * (cg_height - axis_height)`. Accelerations are in the
+/// vehicle frame: `long_accel` positive forward, `lat_accel` positive right.
+pub fn vehicle_inertia_step(
+    state: VehicleInertiaState,
+    config: &VehicleConfig,
+    long_accel: f32,
+    lat_accel: f32,
+    delta: f32,
+) -> VehicleInertiaState {
+    if !delta.is_finite() || delta <= 0.0 || !long_accel.is_finite() || !lat_accel.is_finite() {
+        return state;
+    }
+    let mass = config.mass.dry_mass_kg.max(0.0);
+    let pitch_moment = mass * long_accel * (config.cg_height_m - config.pitch_axis_height_m);
+    let roll_moment = mass * lat_accel * (config.cg_height_m - config.roll_axis_height_m);
+    let steps = (delta / BODY_MAX_SUBSTEP_S).ceil().clamp(1.0, 1000.0);
+    let dt = delta / steps;
+
+    let axis = |angle: &mut f32, rate: &mut f32, moment: f32, inertia: f32, k: f32, c: f32| {
+        let inertia = inertia.max(1.0e-3);
+        let (k, c) = (k.max(0.0), c.max(0.0));
+        for _ in 0..steps as u32 {
+            *rate += (moment - k * *angle - c * *rate) / inertia * dt;
+            *angle += *rate * dt;
+        }
+    };
+    let mut next = state;
+    axis(
+        &mut next.pitch_rad,
+        &mut next.pitch_rate_rad_per_s,
+        pitch_moment,
+        config.pitch_inertia_kg_m2,
+        config.pitch_stiffness_nm_per_rad,
+        config.pitch_damping_nm_s_per_rad,
+    );
+    axis(
+        &mut next.roll_rad,
+        &mut next.roll_rate_rad_per_s,
+        roll_moment,
+        config.roll_inertia_kg_m2,
+        config.roll_stiffness_nm_per_rad,
+        config.roll_damping_nm_s_per_rad,
+    );
+    next
+}
+
+/// Wheel loads (`FRONT_LEFT..REAR_RIGHT`) under acceleration with body inertia.
+/// The link share `m * a * axis_height` transfers at once; the sprung share is
+/// what the springs and dampers currently carry (`k * angle + c * rate`), so it
+/// lags, overshoots and rocks with the body. At steady state the total equals
+/// the quasi-static `m * a * cg_height` transfer. Roll transfer is split between
+/// the axles by static weight distribution; loads always sum to the weight.
+pub fn dynamic_load_transfer(
+    config: &VehicleConfig,
+    inertia: &VehicleInertiaState,
+    long_accel: f32,
+    lat_accel: f32,
+) -> [f32; 4] {
+    let mass = config.mass.dry_mass_kg.max(0.0);
+    let wheelbase = config.mass.wheelbase_m;
+    let track = config.mass.track_width_m;
+    if mass <= 0.0 || wheelbase <= 1.0e-6 || track <= 1.0e-6 {
+        return [0.0; 4];
+    }
+    let finite = |v: f32| if v.is_finite() { v } else { 0.0 };
+    let (long_accel, lat_accel) = (finite(long_accel), finite(lat_accel));
+
+    let weight = mass * GRAVITY;
+    let front_share = 1.0 - (config.mass.cg_to_front_axle_m / wheelbase).clamp(0.0, 1.0);
+    let pitch_moment = mass * long_accel * config.pitch_axis_height_m
+        + config.pitch_stiffness_nm_per_rad.max(0.0) * inertia.pitch_rad
+        + config.pitch_damping_nm_s_per_rad.max(0.0) * inertia.pitch_rate_rad_per_s;
+    let roll_moment = mass * lat_accel * config.roll_axis_height_m
+        + config.roll_stiffness_nm_per_rad.max(0.0) * inertia.roll_rad
+        + config.roll_damping_nm_s_per_rad.max(0.0) * inertia.roll_rate_rad_per_s;
+
+    let to_rear = finite(pitch_moment / wheelbase);
+    let to_left = finite(roll_moment / track);
+    let front = weight * front_share - to_rear;
+    let rear = weight * (1.0 - front_share) + to_rear;
+    let front_left = to_left * front_share;
+    let rear_left = to_left - front_left;
+
+    let mut loads = [0.0; 4];
+    loads[FRONT_LEFT] = 0.5 * front + front_left;
+    loads[FRONT_RIGHT] = 0.5 * front - front_left;
+    loads[REAR_LEFT] = 0.5 * rear + rear_left;
+    loads[REAR_RIGHT] = 0.5 * rear - rear_left;
+    loads
+}
+
+/// Linear single-track (bicycle) model with separate axle cornering stiffnesses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BicycleModelParams {
+    pub mass_kg: f32,
+    pub wheelbase_m: f32,
+    pub lf_m: f32,
+    pub lr_m: f32,
+    pub cf_n_per_rad: f32,
+    pub cr_n_per_rad: f32,
+    pub inertia_kg_m2: f32,
+}
+
+impl Default for BicycleModelParams {
+    fn default() -> Self {
+        Self {
+            mass_kg: 1400.0,
+            wheelbase_m: 2.6,
+            lf_m: 1.15,
+            lr_m: 1.45,
+            cf_n_per_rad: 80000.0,
+            cr_n_per_rad: 100000.0,
+            inertia_kg_m2: 2200.0,
+        }
+    }
+}
+
+/// Body-frame lateral velocity and yaw rate, ISO 8855 (`y` left, yaw positive
+/// counter-clockwise from above).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BicycleState {
+    pub lateral_velocity_m_per_s: f32,
+    pub yaw_rate_rad_per_s: f32,
+}
+
+/// Below this speed the linear model is singular; the state follows the
+/// kinematic (no-slip) solution instead.
+pub const BICYCLE_MIN_SPEED_M_PER_S: f32 = 1.0;
+/// Longest internal step `bicycle_model_step` takes.
+pub const BICYCLE_MAX_SUBSTEP_S: f32 = 0.002;
+
+fn bicycle_slip_angles(
+    state: &BicycleState,
+    steering_rad: f32,
+    speed: f32,
+    params: &BicycleModelParams,
+) -> (f32, f32) {
+    let front = (state.lateral_velocity_m_per_s + params.lf_m * state.yaw_rate_rad_per_s)
+        .atan2(speed)
+        - steering_rad;
+    let rear =
+        (state.lateral_velocity_m_per_s - params.lr_m * state.yaw_rate_rad_per_s).atan2(speed);
+    (front, rear)
+}
+
+/// Advances the bicycle model by `dt` and returns `(alpha_front, alpha_rear)` in
+/// ISO 8855 (positive steering to the left). Axle forces are `-C * alpha`.
+/// Invalid `dt` leaves the state alone and reports the current slip angles.
+pub fn bicycle_model_step(
+    state: &mut BicycleState,
+    delta_steering_rad: f32,
+    speed_m_per_s: f32,
+    params: &BicycleModelParams,
+    dt: f32,
+) -> (f32, f32) {
+    let steering = if delta_steering_rad.is_finite() {
+        delta_steering_rad
+    } else {
+        0.0
+    };
+    let speed = if speed_m_per_s.is_finite() {
+        speed_m_per_s.abs()
+    } else {
+        0.0
+    };
+    let dt_valid = dt.is_finite() && dt > 0.0;
+    if speed < BICYCLE_MIN_SPEED_M_PER_S {
+        if !dt_valid {
+            return (0.0, 0.0);
+        }
+        let wheelbase = params.wheelbase_m.max(1.0e-3);
+        state.yaw_rate_rad_per_s = speed * steering.tan() / wheelbase;
+        state.lateral_velocity_m_per_s = params.lr_m * state.yaw_rate_rad_per_s;
+        return (0.0, 0.0);
+    }
+    if !dt_valid {
+        return bicycle_slip_angles(state, steering, speed, params);
+    }
+
+    let mass = params.mass_kg.max(1.0e-3);
+    let inertia = params.inertia_kg_m2.max(1.0e-3);
+    let steps = (dt / BICYCLE_MAX_SUBSTEP_S).ceil().clamp(1.0, 10000.0);
+    let h = dt / steps;
+    for _ in 0..steps as u32 {
+        let (alpha_f, alpha_r) = bicycle_slip_angles(state, steering, speed, params);
+        let fy_front = -params.cf_n_per_rad * alpha_f;
+        let fy_rear = -params.cr_n_per_rad * alpha_r;
+        let lateral_accel =
+            (fy_front * steering.cos() + fy_rear) / mass - speed * state.yaw_rate_rad_per_s;
+        let yaw_accel = (params.lf_m * fy_front * steering.cos() - params.lr_m * fy_rear) / inertia;
+        state.lateral_velocity_m_per_s += lateral_accel * h;
+        state.yaw_rate_rad_per_s += yaw_accel * h;
+    }
+    if !state.lateral_velocity_m_per_s.is_finite() || !state.yaw_rate_rad_per_s.is_finite() {
+        *state = BicycleState::default();
+    }
+    bicycle_slip_angles(state, steering, speed, params)
+}
+
+/// Speed below which the body slip angle is reported as 0 (direction noise).
+pub const SIDESLIP_MIN_SPEED_M_PER_S: f32 = 1.0;
+
+/// Body slip angle: unsigned angle in `[0, pi]` between the velocity and the
+/// vehicle heading (both world space, any length). Sliding backwards reads as
+/// `pi`. Returns 0 below `SIDESLIP_MIN_SPEED_M_PER_S` or for a zero heading.
+pub fn vehicle_sideslip_angle_rad(velocity: Vec3, heading: Vec3) -> f32 {
+    let speed = velocity.length();
+    if !speed.is_finite() || speed < SIDESLIP_MIN_SPEED_M_PER_S {
+        return 0.0;
+    }
+    velocity.angle_between(heading)
+}
+
+/// True once the body slip angle exceeds `threshold_rad` (typically 20-30 deg).
+pub fn is_spinning_out(slip_angle_rad: f32, threshold_rad: f32) -> bool {
+    slip_angle_rad.is_finite() && slip_angle_rad.abs() > threshold_rad
+}
+
+/// Grip margin of the least-margin tyre: the minimum over the wheels of
+/// `max_forces[i] / |aggregates[i].total_force|`. Above 1 every tyre has grip
+/// in hand, 1 is the limit, below 1 a tyre is past it. Fails safe: 0 when any
+/// `max_forces` entry is zero, negative or non-finite, or any force is
+/// non-finite. Unloaded tyres have no limit to approach and are skipped;
+/// with all four unloaded the result is `f32::MAX`.
+pub fn stability_index(aggregates: &[ContactAggregate; 4], max_forces: &[f32; 4]) -> f32 {
+    let mut index = f32::MAX;
+    for (aggregate, &max_force) in aggregates.iter().zip(max_forces) {
+        let force = aggregate.total_force.length();
+        if !max_force.is_finite() || max_force <= 0.0 || !force.is_finite() {
+            return 0.0;
+        }
+        if force > 0.0 {
+            index = index.min(max_force / force);
+        }
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consumable_clamps_at_zero_and_flags_dry() {
+        let params = ConsumableParams::default();
+        let state = ConsumableMassState {
+            remaining_kg: 0.5,
+            ran_dry: false,
+        };
+        let out = consumable_mass_step(state, ConsumableDraw::MassFlowKgPerS(0.1), 10.0, params);
+        assert_eq!(out.remaining_kg, 0.0);
+        assert!(out.ran_dry);
+    }
+
+    #[test]
+    fn burning_rear_fuel_moves_load_forward() {
+        let mass = VehicleMassProperties::default();
+        let params = ConsumableParams::default();
+        let full = ConsumableMassState {
+            remaining_kg: params.capacity_kg,
+            ran_dry: false,
+        };
+        let empty = ConsumableMassState::default();
+
+        let full_loads = static_wheel_loads(mass, params, full);
+        let empty_loads = static_wheel_loads(mass, params, empty);
+        let sum: f32 = full_loads.wheel_loads_n.iter().sum();
+        assert!((sum - full_loads.total_mass_kg * GRAVITY).abs() < 1.0e-2);
+        assert!(full_loads.cg_shift_m.x < 0.0);
+
+        let rear_share = |s: &VehicleMassSummary| {
+            (s.wheel_loads_n[REAR_LEFT] + s.wheel_loads_n[REAR_RIGHT])
+                / s.wheel_loads_n.iter().sum::<f32>()
+        };
+        assert!(rear_share(&empty_loads) < rear_share(&full_loads));
+    }
+
+    #[test]
+    fn hitch_forces_are_equal_and_opposite() {
+        let out = hitch_force(
+            Vec3 {
+                x: 0.0,
+                y: 0.0,
+                z: 10.0,
+            },
+            Vec3 {
+                x: 0.0,
+                y: 0.0,
+                z: 9.9,
+            },
+            Vec3 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.01,
+            },
+            HitchParams::default(),
+        );
+        assert!(!out.broken_away);
+        assert!(out.force_on_trailer.z > 0.0);
+        assert_eq!(out.force_on_trailer + out.force_on_tractor, Vec3::default());
+    }
+
+    #[test]
+    fn hitch_force_stays_bounded_after_teleport() {
+        let params = HitchParams {
+            breakaway_force: 0.0,
+            ..HitchParams::default()
+        };
+        let out = hitch_force(
+            Vec3::default(),
+            Vec3::default(),
+            Vec3 {
+                x: 1.0e30,
+                y: -1.0e30,
+                z: 0.0,
+            },
+            params,
+        );
+        assert!(out.force_on_trailer.length().is_finite());
+        assert!(out.force_on_trailer.length() <= params.max_force * 1.001);
+        assert!(out.force_on_trailer.x > 0.0);
+
+        let strong = HitchParams {
+            max_force: 1.0e6,
+            ..HitchParams::default()
+        };
+        let broken = hitch_force(
+            Vec3::default(),
+            Vec3::default(),
+            Vec3 {
+                x: 5.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            strong,
+        );
+        assert!(broken.broken_away);
+        assert_eq!(broken.force_on_trailer, Vec3::default());
+    }
+
+    #[test]
+    fn hitch_breaks_with_default_params() {
+        let params = HitchParams::default();
+        let pull = |x: f32| {
+            hitch_force(
+                Vec3::default(),
+                Vec3::default(),
+                Vec3 { x, y: 0.0, z: 0.0 },
+                params,
+            )
+        };
+        // 0.25 m demands 50 kN: clamped to `max_force`, still coupled.
+        let held = pull(0.25);
+        assert!(!held.broken_away);
+        assert!((held.force_on_trailer.x - params.max_force).abs() < 1.0e-2);
+        // 0.3 m demands 60 kN, the breakaway force.
+        let broken = pull(0.3);
+        assert!(broken.broken_away);
+        assert_eq!(broken.force_on_trailer, Vec3::default());
+        assert!(pull(1.0e30).broken_away);
+    }
+
+    #[test]
+    fn full_anti_dive_keeps_front_springs_still_under_front_braking() {
+        let params = AntiGeometryParams {
+            anti_dive_fraction: 1.0,
+            ..AntiGeometryParams::default()
+        };
+        let out = anti_geometry_forces(-8000.0, 0.0, params);
+        assert!(out.suspension_force_delta_per_wheel[FRONT_LEFT].abs() < 1.0e-3);
+        assert!(out.link_force_delta_per_wheel[FRONT_LEFT] > 0.0);
+        assert!(!out.fractions_clamped);
+
+        let none = anti_geometry_forces(
+            -8000.0,
+            0.0,
+            AntiGeometryParams {
+                anti_dive_fraction: 0.0,
+                ..params
+            },
+        );
+        let transfer = 8000.0 * params.cg_height / params.wheelbase;
+        assert!(
+            (none.suspension_force_delta_per_wheel[FRONT_RIGHT] - 0.5 * transfer).abs() < 1.0e-2
+        );
+        assert!(
+            (none.suspension_force_delta_per_wheel[REAR_RIGHT] + 0.5 * transfer).abs() < 1.0e-2
+        );
+    }
+
+    #[test]
+    fn anti_fractions_are_clamped_and_flagged() {
+        let out = anti_geometry_forces(
+            0.0,
+            5000.0,
+            AntiGeometryParams {
+                anti_squat_fraction: 3.0,
+                ..AntiGeometryParams::default()
+            },
+        );
+        assert!(out.fractions_clamped);
+        let params = AntiGeometryParams::default();
+        let expected_link = 0.5 * 5000.0 * params.cg_height / params.wheelbase * MAX_ANTI_FRACTION;
+        assert!((out.link_force_delta_per_wheel[REAR_LEFT] - expected_link).abs() < 1.0e-2);
+    }
+
+    #[test]
+    fn dynamic_transfer_lags_then_settles_to_quasi_static() {
+        let config = VehicleConfig::default();
+        let lat = 9.0;
+        let mut state = VehicleInertiaState::default();
+        let left = |loads: [f32; 4]| loads[FRONT_LEFT] + loads[REAR_LEFT];
+        let weight = config.mass.dry_mass_kg * GRAVITY;
+
+        let instant = dynamic_load_transfer(&config, &state, 0.0, lat);
+        let quasi_static =
+            config.mass.dry_mass_kg * lat * config.cg_height_m / config.mass.track_width_m;
+        let instant_transfer = left(instant) - 0.5 * weight;
+        assert!(instant_transfer > 0.0 && instant_transfer < 0.5 * quasi_static);
+
+        let mut peak: f32 = 0.0;
+        for _ in 0..300 {
+            state = vehicle_inertia_step(state, &config, 0.0, lat, 0.01);
+            let loads = dynamic_load_transfer(&config, &state, 0.0, lat);
+            assert!((loads.iter().sum::<f32>() - weight).abs() < 1.0e-1);
+            peak = peak.max(left(loads) - 0.5 * weight);
+        }
+        let settled = left(dynamic_load_transfer(&config, &state, 0.0, lat)) - 0.5 * weight;
+        assert!((settled - quasi_static).abs() < 1.0);
+        assert!(peak > settled, "body should overshoot before settling");
+        assert!(state.roll_rad > 0.0);
+    }
+
+    #[test]
+    fn braking_pitches_nose_down_and_loads_the_front() {
+        let config = VehicleConfig::default();
+        let mut state = VehicleInertiaState::default();
+        for _ in 0..200 {
+            state = vehicle_inertia_step(state, &config, -8.0, 0.0, 0.01);
+        }
+        assert!(state.pitch_rad < 0.0);
+        let braking = dynamic_load_transfer(&config, &state, -8.0, 0.0);
+        let level = dynamic_load_transfer(&config, &VehicleInertiaState::default(), 0.0, 0.0);
+        assert!(braking[FRONT_LEFT] > level[FRONT_LEFT]);
+        assert_eq!(
+            vehicle_inertia_step(state, &config, -8.0, 0.0, f32::NAN),
+            state
+        );
+    }
+
+    #[test]
+    fn bicycle_model_settles_to_understeer_gradient_yaw_rate() {
+        let params = BicycleModelParams::default();
+        let (speed, steer) = (20.0, 0.03);
+        let mut state = BicycleState::default();
+        let mut slips = (0.0, 0.0);
+        for _ in 0..300 {
+            slips = bicycle_model_step(&mut state, steer, speed, &params, 0.01);
+        }
+        let gradient = params.mass_kg / params.wheelbase_m
+            * (params.lr_m / params.cf_n_per_rad - params.lf_m / params.cr_n_per_rad);
+        let expected = speed * steer / (params.wheelbase_m + gradient * speed * speed);
+        assert!(gradient > 0.0);
+        assert!((state.yaw_rate_rad_per_s - expected).abs() / expected < 0.02);
+        assert!(
+            slips.0 < slips.1,
+            "front slips more in understeer: {slips:?}"
+        );
+
+        let oversteer = BicycleModelParams {
+            cf_n_per_rad: params.cr_n_per_rad,
+            cr_n_per_rad: params.cf_n_per_rad,
+            ..params
+        };
+        let mut loose = BicycleState::default();
+        for _ in 0..300 {
+            bicycle_model_step(&mut loose, steer, speed, &oversteer, 0.01);
+        }
+        assert!(loose.yaw_rate_rad_per_s > state.yaw_rate_rad_per_s);
+
+        let before = state;
+        bicycle_model_step(&mut state, steer, speed, &params, f32::NAN);
+        assert_eq!(state, before);
+    }
+
+    #[test]
+    fn sideslip_flags_spin_past_threshold() {
+        let heading = Vec3 {
+            x: 0.0,
+            y: 0.0,
+            z: -1.0,
+        };
+        let threshold = 25.0_f32.to_radians();
+        let drift = Vec3 {
+            x: 5.0,
+            y: 0.0,
+            z: -20.0,
+        };
+        let slip = vehicle_sideslip_angle_rad(drift, heading);
+        assert!((slip - (5.0_f32 / 20.0).atan()).abs() < 1.0e-5);
+        assert!(!is_spinning_out(slip, threshold));
+
+        let spin = Vec3 {
+            x: 15.0,
+            y: 0.0,
+            z: -10.0,
+        };
+        assert!(is_spinning_out(
+            vehicle_sideslip_angle_rad(spin, heading),
+            threshold
+        ));
+        assert_eq!(vehicle_sideslip_angle_rad(spin * 0.01, heading), 0.0);
+        assert!(!is_spinning_out(f32::NAN, threshold));
+    }
+
+    #[test]
+    fn stability_index_reports_the_tightest_tyre() {
+        let loaded = |fx: f32| ContactAggregate {
+            count: 1,
+            total_force: Vec3 {
+                x: fx,
+                y: 4000.0,
+                z: 0.0,
+            },
+            ..ContactAggregate::default()
+        };
+        let aggregates = [loaded(0.0), loaded(3000.0), loaded(0.0), loaded(0.0)];
+        let limits = [8000.0; 4];
+        assert!((stability_index(&aggregates, &limits) - 8000.0 / 5000.0).abs() < 1.0e-6);
+        let tight = [8000.0, 4000.0, 8000.0, 8000.0];
+        assert!(stability_index(&aggregates, &tight) < 1.0);
+
+        let mut zero = limits;
+        zero[REAR_RIGHT] = 0.0;
+        assert_eq!(stability_index(&aggregates, &zero), 0.0);
+        let mut nan = limits;
+        nan[FRONT_LEFT] = f32::NAN;
+        assert_eq!(stability_index(&aggregates, &nan), 0.0);
+
+        let airborne = [ContactAggregate::default(); 4];
+        assert_eq!(stability_index(&airborne, &limits), f32::MAX);
+    }
+}
@@ -0,0 +1,1365 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::contact::ContactAggregate;
+use crate::thermal::{core_surface_heat_flux_w, couple_core_surface};
+use crate::timestep::{resolve_delta, STEP_STATUS_TEMPERATURE_CLAMPED};
+use crate::tire_state::{FlatSpot, TireSimParameters, TireState};
+
+/// Speed below which distance-based wear figures are not meaningful (m/s).
+const MIN_TRAVEL_SPEED: f32 = 0.1;
+
+/// Wear per kilometre travelled for a per-second wear rate. Returns 0 while
+/// (nearly) stationary, where distance-based figures have no meaning.
+pub fn wear_per_km(wear_rate_per_s: f32, speed_m_per_s: f32) -> f32 {
+    let speed = speed_m_per_s.abs();
+    if !speed.is_finite() || speed < MIN_TRAVEL_SPEED || !wear_rate_per_s.is_finite() {
+        return 0.0;
+    }
+    wear_rate_per_s.max(0.0) / speed * 1000.0
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WearGradient {
+    pub wear_per_km: f32,
+    pub estimated_distance_remaining_km: f32,
+}
+
+/// Wear per km and the distance left until `wear` reaches 1 at the current
+/// rate. With no measurable wear the distance is `f32::MAX` (never infinite).
+pub fn tire_wear_gradient(
+    wear_rate_per_s: f32,
+    speed_m_per_s: f32,
+    current_wear: f32,
+) -> WearGradient {
+    let per_km = wear_per_km(wear_rate_per_s, speed_m_per_s);
+    let remaining = 1.0 - current_wear.clamp(0.0, 1.0);
+    let estimated_distance_remaining_km = if per_km > 1.0e-9 {
+        (remaining / per_km).min(f32::MAX)
+    } else {
+        f32::MAX
+    };
+    WearGradient {
+        wear_per_km: per_km,
+        estimated_distance_remaining_km,
+    }
+}
+
+/// One operating point of a lap profile, held for `duration_s`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LapSample {
+    pub load: f32,
+    pub slip_ratio: f32,
+    pub slip_angle: f32,
+    pub speed: f32,
+    pub duration_s: f32,
+}
+
+impl LapSample {
+    /// Sub-step blend with the same semantics as `interpolate_contact_aggregates`:
+    /// `t` is clamped to `[0, 1]` (non-finite = 0) and the operating point is
+    /// lerped. `duration_s` is not interpolated; it keeps `self`'s value.
+    pub fn interpolate(self, other: LapSample, t: f32) -> LapSample {
+        let t = if t.is_finite() {
+            t.clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        LapSample {
+            load: lerp(self.load, other.load),
+            slip_ratio: lerp(self.slip_ratio, other.slip_ratio),
+            slip_angle: lerp(self.slip_angle, other.slip_angle),
+            speed: lerp(self.speed, other.speed),
+            duration_s: self.duration_s,
+        }
+    }
+}
+
+/// Frictional power dissipated in the patch: `friction * load * sliding speed`,
+/// with the slip angle entering through `tan` (clamped to `max_slip_angle_rad`).
+fn slip_power(sample: &LapSample, params: &TireSimParameters) -> f32 {
+    let speed = sample.speed.abs();
+    let max_angle = params.max_slip_angle_rad.clamp(0.0, 1.5);
+    let lateral = sample.slip_angle.clamp(-max_angle, max_angle).tan();
+    let sliding = speed * (sample.slip_ratio * sample.slip_ratio + lateral * lateral).sqrt();
+    let power = params.friction.max(0.0) * sample.load.max(0.0) * sliding;
+    if power.is_finite() {
+        power
+    } else {
+        0.0
+    }
+}
+
+/// Per-tick wear and temperature update. Slip power heats the surface, which
+/// convects to ambient and conducts into the core; wear grows with slip power
+/// and never decreases. Both heat paths use exact exponential updates, so the
+/// step stays stable at large `delta`. `params = None` uses the defaults.
+pub fn tire_wear_thermal_step(
+    state: TireState,
+    sample: &LapSample,
+    params: Option<&TireSimParameters>,
+    delta: f32,
+) -> TireState {
+    tire_wear_thermal_step_ex(state, sample, params, delta).0
+}
+
+/// `tire_wear_thermal_step` with a status bitmask (see `timestep`). Both output
+/// temperatures are clamped to `temperature_min_c..=temperature_max_c`, setting
+/// `STEP_STATUS_TEMPERATURE_CLAMPED`, so a mistuned parameter shows up as a
+/// flag instead of a runaway temperature.
+pub fn tire_wear_thermal_step_ex(
+    state: TireState,
+    sample: &LapSample,
+    params: Option<&TireSimParameters>,
+    delta: f32,
+) -> (TireState, u32) {
+    thermal_step_with_heat(state, sample, params, 0.0, delta)
+}
+
+/// `tire_wear_thermal_step` with `heat_input_w` (W, negative to cool) added
+/// straight to the surface node on top of the slip heating: heat from outside
+/// the tyre, such as `tire_radiative_coupling`'s joules divided by the same
+/// `delta`. It does not wear the tyre. A non-finite input is ignored.
+pub fn tire_wear_thermal_step_with_heat(
+    state: TireState,
+    sample: &LapSample,
+    params: Option<&TireSimParameters>,
+    heat_input_w: f32,
+    delta: f32,
+) -> TireState {
+    thermal_step_with_heat(state, sample, params, heat_input_w, delta).0
+}
+
+fn thermal_step_with_heat(
+    state: TireState,
+    sample: &LapSample,
+    params: Option<&TireSimParameters>,
+    heat_input_w: f32,
+    delta: f32,
+) -> (TireState, u32) {
+    let (delta, status) = resolve_delta(delta, false);
+    if delta <= 0.0 {
+        return (state, status);
+    }
+    let defaults = TireSimParameters::default();
+    let params = params.unwrap_or(&defaults);
+    let power = slip_power(sample, params);
+    let wear_rate = params.wear_per_joule.max(0.0) * power;
+    let heat_input_w = if heat_input_w.is_finite() {
+        heat_input_w
+    } else {
+        0.0
+    };
+    let (next, clamped) = integrate_heat_and_wear(
+        state,
+        sample.speed,
+        power,
+        heat_input_w,
+        wear_rate,
+        params,
+        delta,
+    );
+    (next, status | clamped)
+}
+
+/// Thermal and wear integration shared by the built-in step and
+/// `tire_wear_step_with_model`: `power` (W) heats the surface and core by the
+/// configured fractions, `heat_input_w` the surface on its own, and wear grows
+/// by `wear_rate * delta`. `delta` must already be resolved. Returns
+/// `STEP_STATUS_TEMPERATURE_CLAMPED` alongside the state when a bound was hit.
+fn integrate_heat_and_wear(
+    state: TireState,
+    speed: f32,
+    power: f32,
+    heat_input_w: f32,
+    wear_rate: f32,
+    params: &TireSimParameters,
+    delta: f32,
+) -> (TireState, u32) {
+    let mut status = 0;
+    let cs = params.surface_heat_capacity.max(1.0e-3);
+    let convection = (params.convection_base + params.convection_per_speed * speed.abs()).max(0.0);
+
+    let heat = power * params.surface_heat_fraction.clamp(0.0, 1.0) + heat_input_w;
+    let surface = if convection > 1.0e-6 {
+        let equilibrium = params.ambient_temp_c + heat / convection;
+        equilibrium + (state.surface_temp_c - equilibrium) * (-convection / cs * delta).exp()
+    } else {
+        state.surface_temp_c + heat / cs * delta
+    };
+    let core_heat = power * params.core_heat_fraction.clamp(0.0, 1.0);
+    let core = state.core_temp_c + core_heat / params.core_heat_capacity.max(1.0e-3) * delta;
+    // Conductance is the per-area flux of a 1 K core excess over the tread.
+    let conductance = core_surface_heat_flux_w(
+        0.0,
+        1.0,
+        params.tread_conductivity_w_per_m_k,
+        params.tread_thickness_m,
+    ) * params.tread_area_m2.max(0.0);
+    let (surface_temp_c, core_temp_c) = couple_core_surface(
+        surface,
+        core,
+        conductance,
+        cs,
+        params.core_heat_capacity,
+        delta,
+    );
+    let (surface_temp_c, surface_clamped) = params.clamp_temperature(surface_temp_c);
+    let (core_temp_c, core_clamped) = params.clamp_temperature(core_temp_c);
+    if surface_clamped || core_clamped {
+        status |= STEP_STATUS_TEMPERATURE_CLAMPED;
+    }
+    let wear = (state.wear + wear_rate * delta).clamp(state.wear, 1.0);
+
+    (
+        TireState {
+            surface_temp_c,
+            core_temp_c,
+            wear,
+        },
+        status,
+    )
+}
+
+/// One frame's input to `tire_wear_step`: the persistent tyre state
+/// (`current_*`) plus the frame's operating point and time step.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WearStepInput {
+    pub current_surface_temp_c: f32,
+    pub current_core_temp_c: f32,
+    pub current_tire_wear: f32,
+    pub sample: LapSample,
+    pub delta: f32,
+}
+
+impl WearStepInput {
+    /// Sub-step blend, the counterpart of `interpolate_contact_aggregates`:
+    /// `t` is clamped to `[0, 1]` (non-finite = 0), the tyre state is lerped
+    /// and `sample` blends through `LapSample::interpolate`. `delta` keeps
+    /// `self`'s value.
+    pub fn interpolate(self, other: WearStepInput, t: f32) -> WearStepInput {
+        let t = if t.is_finite() {
+            t.clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        WearStepInput {
+            current_surface_temp_c: lerp(self.current_surface_temp_c, other.current_surface_temp_c),
+            current_core_temp_c: lerp(self.current_core_temp_c, other.current_core_temp_c),
+            current_tire_wear: lerp(self.current_tire_wear, other.current_tire_wear),
+            sample: self.sample.interpolate(other.sample, t),
+            delta: self.delta,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WearStepOutput {
+    pub surface_temp_c: f32,
+    pub core_temp_c: f32,
+    pub tire_wear: f32,
+}
+
+impl WearStepOutput {
+    /// Next frame's input: state fields from `self`, everything else from
+    /// `prev_input`. Overwrite `sample` and `delta` afterwards if they change.
+    pub fn to_next_input(self, prev_input: WearStepInput) -> WearStepInput {
+        WearStepInput {
+            current_surface_temp_c: self.surface_temp_c,
+            current_core_temp_c: self.core_temp_c,
+            current_tire_wear: self.tire_wear,
+            ..prev_input
+        }
+    }
+}
+
+/// Element-wise sum, for accumulating outputs (e.g. before `average`). A summed
+/// `tire_wear` is total wear across tyres, not a wear level; divide it back
+/// down (or use `average`) before comparing it against a single tyre.
+impl std::ops::Add for WearStepOutput {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            surface_temp_c: self.surface_temp_c + rhs.surface_temp_c,
+            core_temp_c: self.core_temp_c + rhs.core_temp_c,
+            tire_wear: self.tire_wear + rhs.tire_wear,
+        }
+    }
+}
+
+impl WearStepOutput {
+    /// Field-wise mean (fleet or per-car average); default for an empty slice.
+    pub fn average(outputs: &[WearStepOutput]) -> WearStepOutput {
+        if outputs.is_empty() {
+            return WearStepOutput::default();
+        }
+        let sum = outputs
+            .iter()
+            .copied()
+            .fold(WearStepOutput::default(), |acc, o| acc + o);
+        let n = outputs.len() as f32;
+        WearStepOutput {
+            surface_temp_c: sum.surface_temp_c / n,
+            core_temp_c: sum.core_temp_c / n,
+            tire_wear: sum.tire_wear / n,
+        }
+    }
+}
+
+/// `tire_wear_thermal_step` over the input/output pair.
+pub fn tire_wear_step(input: &WearStepInput, params: Option<&TireSimParameters>) -> WearStepOutput {
+    let state = TireState {
+        surface_temp_c: input.current_surface_temp_c,
+        core_temp_c: input.current_core_temp_c,
+        wear: input.current_tire_wear,
+    };
+    let next = tire_wear_thermal_step(state, &input.sample, params, input.delta);
+    WearStepOutput {
+        surface_temp_c: next.surface_temp_c,
+        core_temp_c: next.core_temp_c,
+        tire_wear: next.wear,
+    }
+}
+
+/// Most sub-steps `tire_wear_step_substeps` will take.
+pub const WEAR_MAX_SUBSTEPS: u32 = 10000;
+
+/// `tire_wear_step` over `n` equal sub-steps of `input.delta / n` (clamped to
+/// `1..=WEAR_MAX_SUBSTEPS`), each fed the previous one's state. The surface
+/// convection is exact at any step, but the core coupling is split from it,
+/// so sub-stepping tightens the result at low frame rates.
+pub fn tire_wear_step_substeps(
+    input: &WearStepInput,
+    n: u32,
+    params: Option<&TireSimParameters>,
+) -> WearStepOutput {
+    let n = n.clamp(1, WEAR_MAX_SUBSTEPS);
+    let mut step = WearStepInput {
+        delta: input.delta / n as f32,
+        ..*input
+    };
+    let mut out = tire_wear_step(&step, params);
+    for _ in 1..n {
+        step = out.to_next_input(step);
+        out = tire_wear_step(&step, params);
+    }
+    out
+}
+
+/// Per-segment wear around the circumference, owned for serialization. The
+/// step functions take the `wear` slice, so the caller can equally keep it in
+/// its own buffer.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WearSegments {
+    /// Segment `i` covers wheel-local angles `[i, i + 1) * 2*pi / len`.
+    pub wear: Vec<f32>,
+}
+
+impl WearSegments {
+    pub fn new(segment_count: usize) -> Self {
+        Self {
+            wear: vec![0.0; segment_count],
+        }
+    }
+
+    /// Mean wear, the scalar `tire_wear` of the segmented step.
+    pub fn mean(&self) -> f32 {
+        mean_segment_wear(&self.wear)
+    }
+
+    /// Most worn segment, the one grip and failure checks should read.
+    pub fn worst(&self) -> f32 {
+        worst_segment_wear(&self.wear)
+    }
+}
+
+/// Mean of `segments`, 0 when there are none.
+pub fn mean_segment_wear(segments: &[f32]) -> f32 {
+    if segments.is_empty() {
+        return 0.0;
+    }
+    segments.iter().sum::<f32>() / segments.len() as f32
+}
+
+/// Largest entry of `segments`, 0 when there are none.
+pub fn worst_segment_wear(segments: &[f32]) -> f32 {
+    segments.iter().copied().fold(0.0, f32::max)
+}
+
+/// Segment of `segment_count` touching the road at wheel-local
+/// `contact_angle_rad` (non-finite reads as 0).
+pub fn contact_segment(contact_angle_rad: f32, segment_count: usize) -> usize {
+    let angle = if contact_angle_rad.is_finite() {
+        contact_angle_rad.rem_euclid(std::f32::consts::TAU)
+    } else {
+        0.0
+    };
+    let index = (angle / std::f32::consts::TAU * segment_count as f32) as usize;
+    index.min(segment_count.saturating_sub(1))
+}
+
+/// `tire_wear_step` with the tick's wear concentrated on the segment at
+/// `contact_angle_rad`: that segment takes `segments.len()` times the scalar
+/// increment (capped at 1), and `tire_wear` comes back as the segment mean.
+/// Temperatures are unaffected. An empty `segments` is exactly
+/// `tire_wear_step`.
+pub fn tire_wear_step_segmented(
+    input: &WearStepInput,
+    contact_angle_rad: f32,
+    segments: &mut [f32],
+    params: Option<&TireSimParameters>,
+) -> WearStepOutput {
+    let out = tire_wear_step(input, params);
+    if segments.is_empty() {
+        return out;
+    }
+    let increment = (out.tire_wear - input.current_tire_wear).max(0.0);
+    let count = segments.len();
+    let segment = &mut segments[contact_segment(contact_angle_rad, count)];
+    *segment = (*segment + increment * count as f32).clamp(*segment, 1.0);
+    WearStepOutput {
+        tire_wear: mean_segment_wear(segments),
+        ..out
+    }
+}
+
+/// Pluggable wear law for `tire_wear_step_with_model`, e.g. a lookup table
+/// fitted to test data. Both rates are per second at the input's operating
+/// point; the thermal integration and the wear bookkeeping stay in the crate.
+pub trait WearModel {
+    /// Tread wear rate (fraction of the tread per second).
+    fn wear_rate(&self, input: &WearStepInput) -> f32;
+    /// Heat generated in the tyre (W), split between surface and core by
+    /// the `TireSimParameters` heat fractions.
+    fn heat_generation(&self, input: &WearStepInput) -> f32;
+}
+
+/// The built-in law: slip power `friction * load * sliding speed` heats the
+/// tyre and wears it at `wear_per_joule`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DefaultWearModel {
+    pub params: TireSimParameters,
+}
+
+impl WearModel for DefaultWearModel {
+    fn wear_rate(&self, input: &WearStepInput) -> f32 {
+        self.params.wear_per_joule.max(0.0) * self.heat_generation(input)
+    }
+
+    fn heat_generation(&self, input: &WearStepInput) -> f32 {
+        slip_power(&input.sample, &self.params)
+    }
+}
+
+/// `tire_wear_step` with the wear and heat rates taken from `model`; `params`
+/// supplies the thermal constants (`None` = defaults). Non-finite or negative
+/// rates read as 0, so a model cannot un-wear the tyre. With a
+/// `DefaultWearModel` holding the same parameters it matches `tire_wear_step`.
+pub fn tire_wear_step_with_model(
+    input: &WearStepInput,
+    model: &dyn WearModel,
+    params: Option<&TireSimParameters>,
+) -> WearStepOutput {
+    let state = TireState {
+        surface_temp_c: input.current_surface_temp_c,
+        core_temp_c: input.current_core_temp_c,
+        wear: input.current_tire_wear,
+    };
+    let (delta, _) = resolve_delta(input.delta, false);
+    let next = if delta <= 0.0 {
+        state
+    } else {
+        let defaults = TireSimParameters::default();
+        let params = params.unwrap_or(&defaults);
+        let rate = |v: f32| if v.is_finite() { v.max(0.0) } else { 0.0 };
+        let power = rate(model.heat_generation(input));
+        let wear_rate = rate(model.wear_rate(input));
+        integrate_heat_and_wear(
+            state,
+            input.sample.speed,
+            power,
+            0.0,
+            wear_rate,
+            params,
+            delta,
+        )
+        .0
+    };
+    WearStepOutput {
+        surface_temp_c: next.surface_temp_c,
+        core_temp_c: next.core_temp_c,
+        tire_wear: next.wear,
+    }
+}
+
+/// Physical friction power of a wheel: the tangential part of the aggregate
+/// force (normal component removed along `average_normal`, or the whole force
+/// without a normal) times the patch sliding speed.
+pub fn frictional_power_dissipated_w(
+    aggregate: &ContactAggregate,
+    slip_velocity_m_per_s: f32,
+) -> f32 {
+    let normal = aggregate.average_normal.normalized();
+    let tangential = aggregate.total_force - normal * aggregate.total_force.dot(normal);
+    let power = tangential.length() * slip_velocity_m_per_s.abs();
+    if power.is_finite() {
+        power
+    } else {
+        0.0
+    }
+}
+
+/// Ratio of the heat `tire_wear_step` put into the tyre between `step_input`
+/// and `step_output` to `frictional_power_w`: the slip power it modelled,
+/// weighted by `surface_heat_fraction + core_heat_fraction` (1 when the
+/// tyre absorbs all of the friction, the defaults giving 0.6). The slip power
+/// is recovered from the stored heat by inverting the step's exact convection
+/// solution, so it is exact for unclamped steps. 0 when there is no physical
+/// power, no heat path or no valid step.
+pub fn check_energy_balance(
+    step_input: &WearStepInput,
+    step_output: &WearStepOutput,
+    frictional_power_w: f32,
+    params: Option<&TireSimParameters>,
+) -> f32 {
+    let delta = step_input.delta;
+    if !delta.is_finite()
+        || delta <= 0.0
+        || !frictional_power_w.is_finite()
+        || frictional_power_w <= 0.0
+    {
+        return 0.0;
+    }
+    let defaults = TireSimParameters::default();
+    let params = params.unwrap_or(&defaults);
+    let cs = params.surface_heat_capacity.max(1.0e-3);
+    let cc = params.core_heat_capacity.max(1.0e-3);
+    let surface_fraction = params.surface_heat_fraction.clamp(0.0, 1.0);
+    let core_fraction = params.core_heat_fraction.clamp(0.0, 1.0);
+    let convection = (params.convection_base
+        + params.convection_per_speed * step_input.sample.speed.abs())
+    .max(0.0);
+
+    let stored = cs * (step_output.surface_temp_c - step_input.current_surface_temp_c)
+        + cc * (step_output.core_temp_c - step_input.current_core_temp_c);
+    // Surface node: T(delta) = eq + (T0 - eq) e, eq = ambient + P fs / h.
+    let (offset, per_watt) = if convection > 1.0e-6 {
+        let relaxed = 1.0 - (-convection / cs * delta).exp();
+        (
+            cs * (params.ambient_temp_c - step_input.current_surface_temp_c) * relaxed,
+            surface_fraction * cs / convection * relaxed,
+        )
+    } else {
+        (0.0, surface_fraction * delta)
+    };
+    let per_watt = per_watt + core_fraction * delta;
+    if per_watt <= 0.0 {
+        return 0.0;
+    }
+    let heat_input_w = (stored - offset) / per_watt * (surface_fraction + core_fraction);
+    let ratio = heat_input_w / frictional_power_w;
+    if ratio.is_finite() {
+        ratio
+    } else {
+        0.0
+    }
+}
+
+/// Floats written by `tire_wear_thermal_step_flat`.
+pub const WEAR_STEP_FLAT_LEN: usize = 3;
+
+/// `tire_wear_thermal_step` written as `[surface_temp_c, core_temp_c, wear]`
+/// into `out`. Returns `Ok(floats_written)`, or `Err(required_len)` without
+/// touching `out` when it is too short.
+pub fn tire_wear_thermal_step_flat(
+    state: TireState,
+    sample: &LapSample,
+    params: Option<&TireSimParameters>,
+    delta: f32,
+    out: &mut [f32],
+) -> Result<usize, usize> {
+    if out.len() < WEAR_STEP_FLAT_LEN {
+        return Err(WEAR_STEP_FLAT_LEN);
+    }
+    let next = tire_wear_thermal_step(state, sample, params, delta);
+    out[..WEAR_STEP_FLAT_LEN].copy_from_slice(&[next.surface_temp_c, next.core_temp_c, next.wear]);
+    Ok(WEAR_STEP_FLAT_LEN)
+}
+
+/// Longest internal step `tire_fast_forward` takes through one profile sample.
+pub const FAST_FORWARD_MAX_STEP_S: f32 = 2.0;
+
+/// Tyre state after `laps` repetitions of `lap_profile`, integrating each sample
+/// in steps of up to `FAST_FORWARD_MAX_STEP_S` instead of per tick. The thermal
+/// masses and wear rate come from `params`, exactly as in the per-tick
+/// `tire_wear_thermal_step`, so set those for the compound being simulated.
+pub fn tire_fast_forward(
+    initial_state: TireState,
+    lap_profile: &[LapSample],
+    laps: u32,
+    params: Option<&TireSimParameters>,
+) -> TireState {
+    let mut state = initial_state;
+    for _ in 0..laps {
+        for sample in lap_profile {
+            if !sample.duration_s.is_finite() || sample.duration_s <= 0.0 {
+                continue;
+            }
+            let steps = (sample.duration_s / FAST_FORWARD_MAX_STEP_S)
+                .ceil()
+                .max(1.0);
+            let h = sample.duration_s / steps;
+            for _ in 0..steps as u32 {
+                state = tire_wear_thermal_step(state, sample, params, h);
+            }
+        }
+    }
+    state
+}
+
+/// Tread depth of a new tyre, used to turn a measured depth into a wear fraction.
+pub const NEW_TREAD_DEPTH_MM: f32 = 8.0;
+/// Flat-spot depth at which the visual effect is at full strength.
+pub const FLAT_SPOT_FULL_DEPTH_MM: f32 = 2.0;
+
+/// Material blend factors for the tyre shader, all in `[0, 1]` except
+/// `flat_spot_angle` (wheel-local radians in `[0, 2*pi)`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VisualWear {
+    pub tread_blend: f32,
+    pub cord_blend: f32,
+    pub flat_spot_angle: f32,
+    pub flat_spot_strength: f32,
+}
+
+fn unit(v: f32) -> f32 {
+    if v.is_finite() {
+        v.clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Maps simulation wear state to shader blends so visuals never contradict the
+/// physics. `tread_blend` (new -> slick) is the larger of `tire_wear` and the
+/// wear implied by `tread_depth_mm`, so it never decreases as either wears.
+/// `cord_blend` follows `cord_exposure` and is forced to 1 once the tyre is
+/// fully worn.
+pub fn tire_visual_wear(
+    tire_wear: f32,
+    tread_depth_mm: f32,
+    cord_exposure: f32,
+    flat_spot: Option<FlatSpot>,
+) -> VisualWear {
+    let wear = unit(tire_wear);
+    let depth_wear = if tread_depth_mm.is_finite() {
+        unit(1.0 - tread_depth_mm / NEW_TREAD_DEPTH_MM)
+    } else {
+        0.0
+    };
+    let tread_blend = wear.max(depth_wear);
+    let cord_blend = unit(cord_exposure).max(if tread_blend >= 1.0 { 1.0 } else { 0.0 });
+
+    let (flat_spot_angle, flat_spot_strength) = match flat_spot {
+        Some(spot) if spot.angle_rad.is_finite() => (
+            spot.angle_rad.rem_euclid(std::f32::consts::TAU),
+            unit(spot.depth_mm / FLAT_SPOT_FULL_DEPTH_MM),
+        ),
+        _ => (0.0, 0.0),
+    };
+
+    VisualWear {
+        tread_blend,
+        cord_blend,
+        flat_spot_angle,
+        flat_spot_strength,
+    }
+}
+
+/// Per-segment `tread_blend` for a wear texture: `out[i]` is `segments[i]`
+/// clamped to `[0, 1]` (the cords show where it reaches 1). Returns
+/// `Ok(segments.len())`, or `Err(required_len)` without touching `out` when it
+/// is too short. Pass the worst segment as `tire_wear` to `tire_visual_wear`
+/// for the whole-tyre blends.
+pub fn tire_visual_wear_segments(segments: &[f32], out: &mut [f32]) -> Result<usize, usize> {
+    if out.len() < segments.len() {
+        return Err(segments.len());
+    }
+    for (o, &w) in out.iter_mut().zip(segments) {
+        *o = unit(w);
+    }
+    Ok(segments.len())
+}
+
+/// Frames of history after which a durability estimate is fully trusted.
+pub const DURABILITY_FULL_CONFIDENCE_FRAMES: u32 = 100;
+
+/// Running totals over a stint, fed once per physics frame.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StintAccumulator {
+    pub frames: u32,
+    pub distance_m: f32,
+    pub wear_accrued: f32,
+    pub elapsed_s: f32,
+}
+
+/// Adds one frame: `wear_increment` is this frame's wear gain (negative values
+/// are ignored), distance is `|speed| * delta`. Invalid `delta` is skipped.
+pub fn stint_accumulator_step(
+    accumulator: StintAccumulator,
+    wear_increment: f32,
+    speed_m_per_s: f32,
+    delta: f32,
+) -> StintAccumulator {
+    if !delta.is_finite() || delta <= 0.0 {
+        return accumulator;
+    }
+    let distance = if speed_m_per_s.is_finite() {
+        speed_m_per_s.abs() * delta
+    } else {
+        0.0
+    };
+    let wear = if wear_increment.is_finite() {
+        wear_increment.max(0.0)
+    } else {
+        0.0
+    };
+    StintAccumulator {
+        frames: accumulator.frames.saturating_add(1),
+        distance_m: accumulator.distance_m + distance,
+        wear_accrued: accumulator.wear_accrued + wear,
+        elapsed_s: accumulator.elapsed_s + delta,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DurabilityRating {
+    pub estimated_laps: f32,
+    pub confidence: f32,
+}
+
+/// Laps left until wear reaches 1 at the stint's average wear per metre.
+/// `confidence` ramps from 0 to 1 over the first
+/// `DURABILITY_FULL_CONFIDENCE_FRAMES` frames and is 0 with no wear or distance
+/// recorded (then `estimated_laps` is `f32::MAX`).
+pub fn estimate_durability(
+    accumulator: &StintAccumulator,
+    lap_distance_m: f32,
+    current_wear: f32,
+) -> DurabilityRating {
+    let per_lap = if accumulator.distance_m > 1.0e-3 && lap_distance_m.is_finite() {
+        accumulator.wear_accrued / accumulator.distance_m * lap_distance_m.max(0.0)
+    } else {
+        0.0
+    };
+    if per_lap <= 1.0e-12 || !per_lap.is_finite() {
+        return DurabilityRating {
+            estimated_laps: f32::MAX,
+            confidence: 0.0,
+        };
+    }
+    let remaining = 1.0 - current_wear.clamp(0.0, 1.0);
+    DurabilityRating {
+        estimated_laps: remaining / per_lap,
+        confidence: (accumulator.frames as f32 / DURABILITY_FULL_CONFIDENCE_FRAMES as f32).min(1.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tire_state::{
+        DEFAULT_CORE_HEAT_FRACTION, DEFAULT_ROAD_HEAT_FRACTION, DEFAULT_SURFACE_HEAT_FRACTION,
+    };
+
+    #[test]
+    fn wear_gradient_converts_rate_to_distance() {
+        // 1e-5 /s at 50 m/s is 2e-4 per km; half a tyre left lasts 2500 km.
+        let gradient = tire_wear_gradient(1.0e-5, 50.0, 0.5);
+        assert!((gradient.wear_per_km - 2.0e-4).abs() < 1.0e-9);
+        assert!((gradient.estimated_distance_remaining_km - 2500.0).abs() < 0.5);
+
+        let parked = tire_wear_gradient(1.0e-5, 0.0, 0.5);
+        assert_eq!(parked.wear_per_km, 0.0);
+        assert_eq!(parked.estimated_distance_remaining_km, f32::MAX);
+    }
+
+    fn test_lap() -> Vec<LapSample> {
+        // 80 s lap: straights, braking zones and corners, sampled every 0.5 s.
+        (0..160)
+            .map(|i| {
+                let phase = i as f32 / 160.0 * std::f32::consts::TAU * 4.0;
+                let cornering = phase.sin().max(0.0);
+                LapSample {
+                    load: 4000.0 + 2000.0 * cornering,
+                    slip_ratio: 0.02 + 0.06 * (-phase.cos()).max(0.0),
+                    slip_angle: 0.08 * cornering,
+                    speed: 60.0 - 25.0 * cornering,
+                    duration_s: 0.5,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fast_forward_matches_per_tick_simulation() {
+        let lap = test_lap();
+        let params = TireSimParameters::default();
+        let laps = 10;
+        let fast = tire_fast_forward(TireState::default(), &lap, laps, Some(&params));
+
+        let tick = 1.0 / 60.0;
+        let mut reference = TireState::default();
+        for _ in 0..laps {
+            for sample in &lap {
+                for _ in 0..(sample.duration_s / tick).round() as u32 {
+                    reference = tire_wear_thermal_step(reference, sample, Some(&params), tick);
+                }
+            }
+        }
+
+        let rel = |a: f32, b: f32| (a - b).abs() / b.abs().max(1.0e-6);
+        assert!(reference.surface_temp_c > 40.0);
+        assert!(rel(fast.surface_temp_c, reference.surface_temp_c) < 0.03);
+        assert!(rel(fast.core_temp_c, reference.core_temp_c) < 0.03);
+        assert!(rel(fast.wear, reference.wear) < 0.03);
+        assert!(fast.wear > 0.0 && fast.wear < 1.0);
+    }
+
+    #[test]
+    fn wear_never_decreases() {
+        let sample = LapSample {
+            load: 4000.0,
+            speed: 40.0,
+            ..LapSample::default()
+        };
+        let params = TireSimParameters {
+            wear_per_joule: -1.0,
+            ..TireSimParameters::default()
+        };
+        let state = TireState {
+            wear: 0.4,
+            ..TireState::default()
+        };
+        assert_eq!(
+            tire_wear_thermal_step(state, &sample, Some(&params), 1.0).wear,
+            0.4
+        );
+    }
+
+    #[test]
+    fn default_parameters_match_none_and_core_fraction_heats_core() {
+        let sample = LapSample {
+            load: 5000.0,
+            slip_ratio: 0.05,
+            slip_angle: 0.06,
+            speed: 40.0,
+            duration_s: 1.0,
+        };
+        let state = TireState::default();
+        let defaults = TireSimParameters::default();
+        let heat_split =
+            DEFAULT_SURFACE_HEAT_FRACTION + DEFAULT_CORE_HEAT_FRACTION + DEFAULT_ROAD_HEAT_FRACTION;
+        assert!((heat_split - 1.0).abs() < 1.0e-6);
+        assert_eq!(
+            tire_wear_thermal_step(state, &sample, None, 1.0),
+            tire_wear_thermal_step(state, &sample, Some(&defaults), 1.0)
+        );
+
+        let split = TireSimParameters {
+            surface_heat_fraction: 0.7,
+            core_heat_fraction: 0.3,
+            ..defaults
+        };
+        let stock = tire_wear_thermal_step(state, &sample, None, 1.0);
+        let tuned = tire_wear_thermal_step(state, &sample, Some(&split), 1.0);
+        assert!(tuned.core_temp_c > stock.core_temp_c);
+    }
+
+    #[test]
+    fn visual_wear_is_monotonic_and_places_flat_spot() {
+        let mut previous = 0.0;
+        for i in 0..=100 {
+            let wear = i as f32 / 100.0;
+            let visual = tire_visual_wear(wear, NEW_TREAD_DEPTH_MM, 0.0, None);
+            assert!(visual.tread_blend >= previous);
+            previous = visual.tread_blend;
+        }
+        assert_eq!(tire_visual_wear(1.0, 0.0, 0.0, None).cord_blend, 1.0);
+        assert_eq!(tire_visual_wear(0.1, 2.0, 0.0, None).tread_blend, 0.75);
+
+        let spot = FlatSpot {
+            depth_mm: 1.0,
+            angle_rad: -std::f32::consts::FRAC_PI_2,
+        };
+        let visual = tire_visual_wear(0.2, 6.0, 0.0, Some(spot));
+        assert!((visual.flat_spot_angle - 1.5 * std::f32::consts::PI).abs() < 1.0e-5);
+        assert_eq!(visual.flat_spot_strength, 0.5);
+    }
+
+    #[test]
+    fn durability_extrapolates_average_wear_per_lap() {
+        let mut acc = StintAccumulator::default();
+        for _ in 0..50 {
+            acc = stint_accumulator_step(acc, 1.0e-5, 50.0, 0.1);
+        }
+        // 250 m at 2e-6 per metre: a 5 km lap costs 0.01 wear.
+        let early = estimate_durability(&acc, 5000.0, 0.4);
+        assert!((early.estimated_laps - 60.0).abs() < 0.1);
+        assert!((early.confidence - 0.5).abs() < 1.0e-6);
+
+        for _ in 0..100 {
+            acc = stint_accumulator_step(acc, 1.0e-5, 50.0, 0.1);
+        }
+        assert_eq!(estimate_durability(&acc, 5000.0, 0.4).confidence, 1.0);
+        let fresh = estimate_durability(&StintAccumulator::default(), 5000.0, 0.0);
+        assert_eq!(fresh.confidence, 0.0);
+    }
+
+    #[test]
+    fn lap_sample_interpolation_keeps_duration() {
+        let a = LapSample {
+            load: 4000.0,
+            slip_ratio: 0.0,
+            slip_angle: 0.02,
+            speed: 20.0,
+            duration_s: 1.0 / 240.0,
+        };
+        let b = LapSample {
+            load: 6000.0,
+            slip_ratio: 0.1,
+            slip_angle: 0.06,
+            speed: 30.0,
+            duration_s: 1.0 / 60.0,
+        };
+        let mid = a.interpolate(b, 0.5);
+        assert_eq!(mid.load, 5000.0);
+        assert_eq!(mid.speed, 25.0);
+        assert_eq!(mid.duration_s, a.duration_s);
+        assert_eq!(a.interpolate(b, 2.0).load, b.load);
+        assert_eq!(a.interpolate(b, f32::NAN), a);
+    }
+
+    #[test]
+    fn wear_step_input_interpolation_blends_state_and_sample() {
+        let a = WearStepInput {
+            current_surface_temp_c: 60.0,
+            current_core_temp_c: 50.0,
+            current_tire_wear: 0.1,
+            sample: LapSample {
+                load: 4000.0,
+                speed: 20.0,
+                ..LapSample::default()
+            },
+            delta: 1.0 / 240.0,
+        };
+        let b = WearStepInput {
+            current_surface_temp_c: 80.0,
+            current_core_temp_c: 70.0,
+            current_tire_wear: 0.3,
+            sample: LapSample {
+                load: 6000.0,
+                speed: 30.0,
+                ..LapSample::default()
+            },
+            delta: 1.0 / 60.0,
+        };
+        assert_eq!(a.interpolate(b, 0.0), a);
+        let end = a.interpolate(b, 1.0);
+        assert_eq!(end.current_surface_temp_c, b.current_surface_temp_c);
+        assert_eq!(end.current_tire_wear, b.current_tire_wear);
+        assert_eq!(end.sample.load, b.sample.load);
+        assert_eq!(end.delta, a.delta);
+
+        let mid = a.interpolate(b, 0.5);
+        assert_eq!(mid.current_surface_temp_c, 70.0);
+        assert_eq!(mid.current_core_temp_c, 60.0);
+        assert!((mid.current_tire_wear - 0.2).abs() < 1.0e-6);
+        assert_eq!(mid.sample.load, 5000.0);
+        assert_eq!(mid.sample.speed, 25.0);
+        assert_eq!(mid.delta, a.delta);
+        assert_eq!(a.interpolate(b, f32::NAN), a);
+    }
+
+    #[test]
+    fn flat_wear_step_matches_struct() {
+        let sample = LapSample {
+            load: 4000.0,
+            slip_ratio: 0.08,
+            slip_angle: 0.05,
+            speed: 40.0,
+            duration_s: 1.0,
+        };
+        let next = tire_wear_thermal_step(TireState::default(), &sample, None, 0.5);
+        let mut out = [0.0; WEAR_STEP_FLAT_LEN];
+        assert_eq!(
+            tire_wear_thermal_step_flat(TireState::default(), &sample, None, 0.5, &mut out),
+            Ok(WEAR_STEP_FLAT_LEN)
+        );
+        assert_eq!(out, [next.surface_temp_c, next.core_temp_c, next.wear]);
+        assert_eq!(
+            tire_wear_thermal_step_flat(TireState::default(), &sample, None, 0.5, &mut out[..2]),
+            Err(WEAR_STEP_FLAT_LEN)
+        );
+    }
+
+    #[test]
+    fn to_next_input_threads_state_forward() {
+        let sample = LapSample {
+            load: 4000.0,
+            slip_ratio: 0.1,
+            slip_angle: 0.04,
+            speed: 50.0,
+            duration_s: 1.0,
+        };
+        let start = TireState::default();
+        let mut input = WearStepInput {
+            current_surface_temp_c: start.surface_temp_c,
+            current_core_temp_c: start.core_temp_c,
+            current_tire_wear: start.wear,
+            sample,
+            delta: 1.0 / 60.0,
+        };
+        let mut manual = start;
+        for _ in 0..120 {
+            input = tire_wear_step(&input, None).to_next_input(input);
+            manual = tire_wear_thermal_step(manual, &sample, None, 1.0 / 60.0);
+        }
+        assert_eq!(input.current_surface_temp_c, manual.surface_temp_c);
+        assert_eq!(input.current_core_temp_c, manual.core_temp_c);
+        assert_eq!(input.current_tire_wear, manual.wear);
+        assert_eq!(input.sample, sample);
+        assert!(manual.wear > 0.0);
+    }
+
+    #[test]
+    fn pathological_cooling_is_clamped_and_flagged() {
+        let sample = LapSample {
+            load: 4000.0,
+            slip_ratio: 0.1,
+            speed: 50.0,
+            duration_s: 1.0,
+            ..LapSample::default()
+        };
+        let params = TireSimParameters {
+            convection_base: 1000.0,
+            ambient_temp_c: -200.0,
+            ..TireSimParameters::default()
+        };
+        let mut state = TireState::default();
+        let mut flagged = false;
+        for _ in 0..100 {
+            let (next, status) = tire_wear_thermal_step_ex(state, &sample, Some(&params), 0.1);
+            flagged |= status & STEP_STATUS_TEMPERATURE_CLAMPED != 0;
+            state = next;
+            assert!(state.surface_temp_c >= params.temperature_min_c);
+            assert!(state.core_temp_c >= params.temperature_min_c);
+        }
+        assert!(flagged);
+
+        let (_, status) = tire_wear_thermal_step_ex(
+            TireState::default(),
+            &sample,
+            Some(&TireSimParameters::default()),
+            0.1,
+        );
+        assert_eq!(status, 0);
+
+        let hot = TireSimParameters {
+            friction: 1.0e6,
+            ..TireSimParameters::default()
+        };
+        let (runaway, status) = tire_wear_thermal_step_ex(state, &sample, Some(&hot), 0.1);
+        assert_eq!(runaway.surface_temp_c, hot.temperature_max_c);
+        assert!(status & STEP_STATUS_TEMPERATURE_CLAMPED != 0);
+    }
+
+    #[test]
+    fn averaging_identical_outputs_is_identity() {
+        let out = WearStepOutput {
+            surface_temp_c: 92.5,
+            core_temp_c: 88.0,
+            tire_wear: 0.125,
+        };
+        assert_eq!(WearStepOutput::average(&[out; 4]), out);
+        let sum = out + out;
+        assert_eq!(sum.tire_wear, 0.25);
+        assert_eq!(WearStepOutput::average(&[]), WearStepOutput::default());
+    }
+
+    #[test]
+    fn energy_balance_closes_for_matching_friction_power() {
+        use crate::Vec3;
+        let sample = LapSample {
+            load: 4000.0,
+            slip_ratio: 0.05,
+            slip_angle: 0.0,
+            speed: 30.0,
+            duration_s: 0.0,
+        };
+        let input = WearStepInput {
+            current_surface_temp_c: 60.0,
+            current_core_temp_c: 50.0,
+            current_tire_wear: 0.1,
+            sample,
+            delta: 0.1,
+        };
+        let output = tire_wear_step(&input, None);
+        // friction 1 * 4000 N sliding at 0.05 * 30 m/s.
+        let aggregate = ContactAggregate {
+            total_force: Vec3 {
+                x: 4000.0,
+                y: 0.0,
+                z: 4000.0,
+            },
+            average_normal: Vec3 {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0,
+            },
+            ..ContactAggregate::default()
+        };
+        let physical = frictional_power_dissipated_w(&aggregate, 1.5);
+        assert!((physical - 6000.0).abs() < 1.0e-2);
+        let ratio = check_energy_balance(&input, &output, physical, None);
+        assert!((ratio - 0.6).abs() < 0.01, "{ratio}");
+        assert!((check_energy_balance(&input, &output, 2.0 * physical, None) - 0.3).abs() < 0.01);
+        assert_eq!(check_energy_balance(&input, &output, 0.0, None), 0.0);
+
+        // Half the heat into the surface: half the heat input for the same slip.
+        let halved = TireSimParameters {
+            surface_heat_fraction: 0.3,
+            ..TireSimParameters::default()
+        };
+        let output = tire_wear_step(&input, Some(&halved));
+        let ratio = check_energy_balance(&input, &output, physical, Some(&halved));
+        assert!((ratio - 0.3).abs() < 0.01, "{ratio}");
+    }
+
+    #[test]
+    fn default_wear_model_matches_the_built_in_step() {
+        let params = TireSimParameters {
+            core_heat_fraction: 0.2,
+            ..TireSimParameters::default()
+        };
+        let input = WearStepInput {
+            current_surface_temp_c: 60.0,
+            current_core_temp_c: 50.0,
+            current_tire_wear: 0.1,
+            sample: LapSample {
+                load: 4500.0,
+                slip_ratio: 0.08,
+                slip_angle: 0.06,
+                speed: 35.0,
+                duration_s: 0.0,
+            },
+            delta: 1.0 / 60.0,
+        };
+        let model = DefaultWearModel { params };
+        assert_eq!(
+            tire_wear_step_with_model(&input, &model, Some(&params)),
+            tire_wear_step(&input, Some(&params))
+        );
+    }
+
+    /// Wear rate and heat looked up by slip ratio, as a CSV-backed model would.
+    struct TableWearModel {
+        slip: [f32; 3],
+        wear_per_s: [f32; 3],
+        heat_w: [f32; 3],
+    }
+
+    impl TableWearModel {
+        fn lookup(&self, table: &[f32; 3], input: &WearStepInput) -> f32 {
+            let s = input.sample.slip_ratio.abs();
+            let i = self.slip.iter().rposition(|&x| x <= s).unwrap_or(0).min(1);
+            let t = ((s - self.slip[i]) / (self.slip[i + 1] - self.slip[i])).clamp(0.0, 1.0);
+            table[i] + (table[i + 1] - table[i]) * t
+        }
+    }
+
+    impl WearModel for TableWearModel {
+        fn wear_rate(&self, input: &WearStepInput) -> f32 {
+            self.lookup(&self.wear_per_s, input)
+        }
+
+        fn heat_generation(&self, input: &WearStepInput) -> f32 {
+            self.lookup(&self.heat_w, input)
+        }
+    }
+
+    #[test]
+    fn custom_wear_model_drives_the_step() {
+        let model = TableWearModel {
+            slip: [0.0, 0.1, 0.3],
+            wear_per_s: [0.0, 1.0e-5, -1.0],
+            heat_w: [0.0, 4000.0, 12000.0],
+        };
+        let mut input = WearStepInput {
+            current_surface_temp_c: 25.0,
+            current_core_temp_c: 25.0,
+            current_tire_wear: 0.2,
+            sample: LapSample {
+                load: 4000.0,
+                slip_ratio: 0.05,
+                slip_angle: 0.0,
+                speed: 20.0,
+                duration_s: 0.0,
+            },
+            delta: 1.0,
+        };
+        let out = tire_wear_step_with_model(&input, &model, None);
+        assert!((out.tire_wear - (0.2 + 0.5e-5)).abs() < 1.0e-7);
+        assert!(out.surface_temp_c > 25.0);
+        // A negative table entry cannot reduce wear.
+        input.sample.slip_ratio = 0.3;
+        assert_eq!(
+            tire_wear_step_with_model(&input, &model, None).tire_wear,
+            0.2
+        );
+        input.delta = f32::NAN;
+        assert_eq!(
+            tire_wear_step_with_model(&input, &model, None).tire_wear,
+            0.2
+        );
+    }
+
+    #[test]
+    fn substeps_converge_on_the_single_step() {
+        let params = TireSimParameters {
+            core_heat_fraction: 0.3,
+            tread_conductivity_w_per_m_k: 2.5,
+            ..TireSimParameters::default()
+        };
+        let input = WearStepInput {
+            current_surface_temp_c: 40.0,
+            current_core_temp_c: 30.0,
+            current_tire_wear: 0.0,
+            sample: LapSample {
+                load: 4500.0,
+                slip_ratio: 0.1,
+                slip_angle: 0.08,
+                speed: 30.0,
+                duration_s: 0.0,
+            },
+            delta: 1.0 / 30.0,
+        };
+        let single = tire_wear_step(&input, Some(&params));
+        assert_eq!(tire_wear_step_substeps(&input, 1, Some(&params)), single);
+        assert_eq!(tire_wear_step_substeps(&input, 0, Some(&params)), single);
+
+        let eight = tire_wear_step_substeps(&input, 8, Some(&params));
+        let reference = tire_wear_step_substeps(&input, 512, Some(&params));
+        assert!((eight.surface_temp_c - single.surface_temp_c).abs() < 0.1);
+        assert!((eight.core_temp_c - single.core_temp_c).abs() < 0.1);
+        assert!((eight.tire_wear - single.tire_wear).abs() < 1.0e-6);
+        assert_ne!(eight, single);
+        let error = |o: WearStepOutput| {
+            (o.surface_temp_c - reference.surface_temp_c).abs()
+                + (o.core_temp_c - reference.core_temp_c).abs()
+        };
+        assert!(error(eight) < error(single));
+    }
+
+    #[test]
+    fn segmented_wear_concentrates_on_the_contact_segment() {
+        let input = WearStepInput {
+            current_surface_temp_c: 60.0,
+            current_core_temp_c: 50.0,
+            current_tire_wear: 0.0,
+            sample: LapSample {
+                load: 4500.0,
+                slip_ratio: -0.3,
+                slip_angle: 0.0,
+                speed: 20.0,
+                duration_s: 0.0,
+            },
+            delta: 0.5,
+        };
+        let plain = tire_wear_step(&input, None);
+        assert_eq!(tire_wear_step_segmented(&input, 1.0, &mut [], None), plain);
+
+        // A locked wheel keeps the same segment on the road.
+        let mut map = WearSegments::new(8);
+        let angle = 3.3;
+        let mut step = input;
+        for _ in 0..4 {
+            let out = tire_wear_step_segmented(&step, angle, &mut map.wear, None);
+            assert_eq!(
+                out.surface_temp_c,
+                tire_wear_step(&step, None).surface_temp_c
+            );
+            step = out.to_next_input(step);
+        }
+        let hit = contact_segment(angle, 8);
+        assert_eq!(hit, 4);
+        assert!((map.mean() - step.current_tire_wear).abs() < 1.0e-7);
+        assert!(map.worst() > 7.9 * map.mean());
+        assert_eq!(map.worst(), map.wear[hit]);
+        assert!(map
+            .wear
+            .iter()
+            .enumerate()
+            .all(|(i, &w)| i == hit || w == 0.0));
+
+        let mut texture = [0.0; 8];
+        assert_eq!(tire_visual_wear_segments(&map.wear, &mut texture), Ok(8));
+        assert_eq!(texture[hit], map.wear[hit]);
+        assert_eq!(tire_visual_wear_segments(&map.wear, &mut [0.0; 4]), Err(8));
+        assert_eq!(contact_segment(-0.1, 8), 7);
+        assert_eq!(contact_segment(f32::NAN, 8), 0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn wear_segments_serialize_with_the_map() {
+        let map = WearSegments {
+            wear: vec![0.1, 0.4, 0.0],
+        };
+        let payload = serde_json::to_string(&map).unwrap();
+        let restored: WearSegments = serde_json::from_str(&payload).unwrap();
+        assert_eq!(restored, map);
+    }
+
+    #[test]
+    fn core_exchange_follows_the_tread_heat_flux() {
+        let params = TireSimParameters {
+            convection_base: 0.0,
+            convection_per_speed: 0.0,
+            ..TireSimParameters::default()
+        };
+        let state = TireState {
+            surface_temp_c: 60.0,
+            core_temp_c: 90.0,
+            wear: 0.0,
+        };
+        let delta = 1.0e-2;
+        let next = tire_wear_thermal_step(state, &LapSample::default(), Some(&params), delta);
+        let flux = core_surface_heat_flux_w(
+            state.surface_temp_c,
+            state.core_temp_c,
+            params.tread_conductivity_w_per_m_k,
+            params.tread_thickness_m,
+        );
+        let expected_j = flux * params.tread_area_m2 * delta;
+        let core_loss_j = (state.core_temp_c - next.core_temp_c) * params.core_heat_capacity;
+        let surface_gain_j =
+            (next.surface_temp_c - state.surface_temp_c) * params.surface_heat_capacity;
+        assert!(
+            (core_loss_j - expected_j).abs() < 1.0e-2 * expected_j,
+            "{core_loss_j}"
+        );
+        assert!((surface_gain_j - expected_j).abs() < 1.0e-2 * expected_j);
+    }
+}
@@ -0,0 +1,121 @@
+//! End-to-end straight-line launch of each reference vehicle, in the intended
+//! call order: body attitude and loads, kinematics, slip, forces, aggregation,
+//! wear/thermal and hub wrenches (the last five inside `axle_step`), then the
+//! wheel-spin and body integrators the host engine would own.
+
+use tire_core::axle::{axle_step, AxleInput, AxleParams, AxleState, AXLE_LEFT, AXLE_RIGHT};
+use tire_core::presets::{vehicle_preset, PresetId, VehicleSetup};
+use tire_core::tire_state::TireState;
+use tire_core::vehicle::{
+    dynamic_load_transfer, vehicle_inertia_step, VehicleInertiaState, FRONT_LEFT, REAR_LEFT,
+};
+use tire_core::Vec2;
+
+const DT: f32 = 1.0 / 1000.0;
+const DURATION_S: f32 = 10.0;
+const INITIAL_SPEED_M_PER_S: f32 = 10.0;
+const THROTTLE: f32 = 0.5;
+
+struct RunResult {
+    final_speed: f32,
+    tires: [TireState; 4],
+}
+
+fn run(setup: &VehicleSetup) -> RunResult {
+    let mass = setup.config.mass.dry_mass_kg;
+    let axles: [(&AxleParams, usize, f32); 2] = [
+        (&setup.front_axle, FRONT_LEFT, setup.front_drive_share),
+        (&setup.rear_axle, REAR_LEFT, 1.0 - setup.front_drive_share),
+    ];
+    let mut states = [AxleState::default(); 2];
+    let mut inertia = VehicleInertiaState::default();
+    let mut speed = INITIAL_SPEED_M_PER_S;
+    let mut omega = [INITIAL_SPEED_M_PER_S / setup.front_axle.tire_radius_m; 4];
+    let mut long_accel = 0.0;
+
+    let steps = (DURATION_S / DT).round() as u32;
+    for _ in 0..steps {
+        // Loads: body pitch lags the acceleration; each wheel's share becomes
+        // a spring travel for the axle to turn back into a load. The body
+        // model already carries the damper share, so no travel rate is passed.
+        inertia = vehicle_inertia_step(inertia, &setup.config, long_accel, 0.0, DT);
+        let loads = dynamic_load_transfer(&setup.config, &inertia, long_accel, 0.0);
+
+        let mut force_x = 0.0;
+        for (axle, ((params, first, drive_share), state)) in
+            axles.iter().zip(states.iter_mut()).enumerate()
+        {
+            let travel = |wheel: usize| (loads[wheel] - params.static_load_n) / params.spring_rate;
+            let input = AxleInput {
+                travel_m: [travel(*first), travel(first + 1)],
+                hub_velocity: [Vec2 { x: speed, y: 0.0 }; 2],
+                wheel_omega: [omega[*first], omega[first + 1]],
+                drive_torque: THROTTLE * setup.max_drive_torque_nm * drive_share,
+                surface_mu: 1.0,
+                delta: DT,
+                ..AxleInput::default()
+            };
+            let out = axle_step(&input, params, state);
+
+            for side in [AXLE_LEFT, AXLE_RIGHT] {
+                let wheel = first + side;
+                let fx = out.wrenches[side].force.x;
+                assert!(
+                    out.wrenches[side].force.x.is_finite()
+                        && out.wrenches[side].torque_about_com.length().is_finite(),
+                    "axle {axle} side {side}: {:?}",
+                    out.wrenches[side]
+                );
+                omega[wheel] += (out.diff_torques[side] - fx * params.tire_radius_m)
+                    / setup.wheel_inertia_kg_m2
+                    * DT;
+                force_x += fx;
+            }
+        }
+
+        long_accel = force_x / mass;
+        speed += long_accel * DT;
+        assert!(speed.is_finite() && omega.iter().all(|w| w.is_finite()));
+    }
+
+    let [front, rear] = states;
+    RunResult {
+        final_speed: speed,
+        tires: [front.tires[0], front.tires[1], rear.tires[0], rear.tires[1]],
+    }
+}
+
+#[test]
+fn reference_vehicles_launch_heat_and_wear_their_driven_tyres() {
+    for id in [PresetId::Hatchback, PresetId::GtRace, PresetId::Rally] {
+        let setup = vehicle_preset(id);
+        let result = run(&setup);
+        assert!(
+            result.final_speed > INITIAL_SPEED_M_PER_S + 10.0,
+            "{id:?} reached {}",
+            result.final_speed
+        );
+
+        let initial = TireState::default();
+        for (wheel, tire) in result.tires.iter().enumerate() {
+            assert!(
+                tire.surface_temp_c.is_finite()
+                    && tire.core_temp_c.is_finite()
+                    && tire.wear.is_finite(),
+                "{id:?} wheel {wheel}: {tire:?}"
+            );
+            let share = if wheel < REAR_LEFT {
+                setup.front_drive_share
+            } else {
+                1.0 - setup.front_drive_share
+            };
+            if share > 0.0 {
+                assert!(
+                    tire.surface_temp_c > initial.surface_temp_c,
+                    "{id:?} wheel {wheel}: {tire:?}"
+                );
+                assert!(tire.wear > 0.0, "{id:?} wheel {wheel}: {tire:?}");
+            }
+        }
+    }
+}